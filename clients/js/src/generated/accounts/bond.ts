@@ -21,10 +21,14 @@ import {
     getI64Encoder,
     getStructDecoder,
     getStructEncoder,
+    getU128Decoder,
+    getU128Encoder,
+    getU16Decoder,
+    getU16Encoder,
+    getU32Decoder,
+    getU32Encoder,
     getU64Decoder,
     getU64Encoder,
-    getU8Decoder,
-    getU8Encoder,
     type Account,
     type Address,
     type EncodedAccount,
@@ -37,44 +41,110 @@ import {
     type MaybeEncodedAccount,
 } from '@solana/kit';
 import { BondSeeds, findBondPda } from '../pdas';
+import {
+    getGrantSourceDecoder,
+    getGrantSourceEncoder,
+    type GrantSource,
+    type GrantSourceArgs,
+} from '../types';
 
 export type Bond = {
     owner: Address;
+    creator: Address;
     bondIndex: number;
+    pageIndex: number;
     creationTimestamp: bigint;
     lastClaimTimestamp: bigint;
     totalClaimed: bigint;
     isActive: boolean;
+    mint: Address;
+    isListed: boolean;
+    weight: number;
+    penaltyPerWeightCheckpoint: bigint;
+    rewardRemainder: bigint;
+    rewardPerWeightCheckpoint: bigint;
+    lockUntil: bigint;
+    lockBoostBps: number;
+    maturityTimestamp: bigint;
+    principal: bigint;
+    maturityReward: bigint;
+    principalReturnBps: number;
+    grantSource: GrantSource;
 };
 
 export type BondArgs = {
     owner: Address;
+    creator: Address;
     bondIndex: number;
+    pageIndex: number;
     creationTimestamp: number | bigint;
     lastClaimTimestamp: number | bigint;
     totalClaimed: number | bigint;
     isActive: boolean;
+    mint: Address;
+    isListed: boolean;
+    weight: number;
+    penaltyPerWeightCheckpoint: number | bigint;
+    rewardRemainder: number | bigint;
+    rewardPerWeightCheckpoint: number | bigint;
+    lockUntil: number | bigint;
+    lockBoostBps: number;
+    maturityTimestamp: number | bigint;
+    principal: number | bigint;
+    maturityReward: number | bigint;
+    principalReturnBps: number;
+    grantSource: GrantSourceArgs;
 };
 
 export function getBondEncoder(): FixedSizeEncoder<BondArgs> {
     return getStructEncoder([
         ['owner', getAddressEncoder()],
-        ['bondIndex', getU8Encoder()],
+        ['creator', getAddressEncoder()],
+        ['bondIndex', getU16Encoder()],
+        ['pageIndex', getU16Encoder()],
         ['creationTimestamp', getI64Encoder()],
         ['lastClaimTimestamp', getI64Encoder()],
         ['totalClaimed', getU64Encoder()],
         ['isActive', getBooleanEncoder()],
+        ['mint', getAddressEncoder()],
+        ['isListed', getBooleanEncoder()],
+        ['weight', getU32Encoder()],
+        ['penaltyPerWeightCheckpoint', getU128Encoder()],
+        ['rewardRemainder', getU64Encoder()],
+        ['rewardPerWeightCheckpoint', getU128Encoder()],
+        ['lockUntil', getI64Encoder()],
+        ['lockBoostBps', getU16Encoder()],
+        ['maturityTimestamp', getI64Encoder()],
+        ['principal', getU64Encoder()],
+        ['maturityReward', getU64Encoder()],
+        ['principalReturnBps', getU16Encoder()],
+        ['grantSource', getGrantSourceEncoder()],
     ]);
 }
 
 export function getBondDecoder(): FixedSizeDecoder<Bond> {
     return getStructDecoder([
         ['owner', getAddressDecoder()],
-        ['bondIndex', getU8Decoder()],
+        ['creator', getAddressDecoder()],
+        ['bondIndex', getU16Decoder()],
+        ['pageIndex', getU16Decoder()],
         ['creationTimestamp', getI64Decoder()],
         ['lastClaimTimestamp', getI64Decoder()],
         ['totalClaimed', getU64Decoder()],
         ['isActive', getBooleanDecoder()],
+        ['mint', getAddressDecoder()],
+        ['isListed', getBooleanDecoder()],
+        ['weight', getU32Decoder()],
+        ['penaltyPerWeightCheckpoint', getU128Decoder()],
+        ['rewardRemainder', getU64Decoder()],
+        ['rewardPerWeightCheckpoint', getU128Decoder()],
+        ['lockUntil', getI64Decoder()],
+        ['lockBoostBps', getU16Decoder()],
+        ['maturityTimestamp', getI64Decoder()],
+        ['principal', getU64Decoder()],
+        ['maturityReward', getU64Decoder()],
+        ['principalReturnBps', getU16Decoder()],
+        ['grantSource', getGrantSourceDecoder()],
     ]);
 }
 
@@ -136,7 +206,7 @@ export async function fetchAllMaybeBond(
 }
 
 export function getBondSize(): number {
-    return 58;
+    return 207;
 }
 
 export async function fetchBondFromSeeds(
@@ -23,10 +23,10 @@ import {
     getStructEncoder,
     getU16Decoder,
     getU16Encoder,
+    getU32Decoder,
+    getU32Encoder,
     getU64Decoder,
     getU64Encoder,
-    getU8Decoder,
-    getU8Encoder,
     type Account,
     type Address,
     type EncodedAccount,
@@ -39,6 +39,48 @@ import {
     type MaybeEncodedAccount,
 } from '@solana/kit';
 import { findGlobalAdminPda } from '../pdas';
+import {
+    getAllowlistPhaseDecoder,
+    getAllowlistPhaseEncoder,
+    getEmissionCurveDecoder,
+    getEmissionCurveEncoder,
+    getEmissionModeDecoder,
+    getEmissionModeEncoder,
+    getFixedTermConfigDecoder,
+    getFixedTermConfigEncoder,
+    getLockTierDecoder,
+    getLockTierEncoder,
+    getLoyaltyTierDecoder,
+    getLoyaltyTierEncoder,
+    getPenaltyDestinationDecoder,
+    getPenaltyDestinationEncoder,
+    getPenaltyTierDecoder,
+    getPenaltyTierEncoder,
+    getRewardSourceDecoder,
+    getRewardSourceEncoder,
+    getVestingScheduleDecoder,
+    getVestingScheduleEncoder,
+    type AllowlistPhase,
+    type AllowlistPhaseArgs,
+    type EmissionCurve,
+    type EmissionCurveArgs,
+    type EmissionMode,
+    type EmissionModeArgs,
+    type FixedTermConfig,
+    type FixedTermConfigArgs,
+    type LockTier,
+    type LockTierArgs,
+    type LoyaltyTier,
+    type LoyaltyTierArgs,
+    type PenaltyDestination,
+    type PenaltyDestinationArgs,
+    type PenaltyTier,
+    type PenaltyTierArgs,
+    type RewardSource,
+    type RewardSourceArgs,
+    type VestingSchedule,
+    type VestingScheduleArgs,
+} from '../types';
 
 export type GlobalAdmin = {
     authority: Address;
@@ -49,9 +91,25 @@ export type GlobalAdmin = {
     dailyEmissionRate: bigint;
     maxEmissionPerBond: bigint;
     maxBondsPerWallet: number;
-    tokenDepositSplit: Array<number>;
-    claimPenalty: number;
+    claimPenaltySchedule: Array<PenaltyTier>;
     pauseBondOperations: boolean;
+    redemptionRate: number;
+    marketplaceFee: number;
+    penaltyDestination: PenaltyDestination;
+    emissionMode: EmissionMode;
+    globalEmissionRate: bigint;
+    emissionCurve: EmissionCurve;
+    loyaltySchedule: Array<LoyaltyTier>;
+    lockTiers: Array<LockTier>;
+    fixedTerm: FixedTermConfig;
+    distributionInterval: number;
+    teamVesting: VestingSchedule;
+    rewardSource: RewardSource;
+    emissionCeiling: bigint;
+    referralDepositBps: number;
+    referralClaimBps: number;
+    allowlist: AllowlistPhase;
+    grantAuthority: Address;
 };
 
 export type GlobalAdminArgs = {
@@ -63,9 +121,25 @@ export type GlobalAdminArgs = {
     dailyEmissionRate: number | bigint;
     maxEmissionPerBond: number | bigint;
     maxBondsPerWallet: number;
-    tokenDepositSplit: Array<number>;
-    claimPenalty: number;
+    claimPenaltySchedule: Array<PenaltyTierArgs>;
     pauseBondOperations: boolean;
+    redemptionRate: number;
+    marketplaceFee: number;
+    penaltyDestination: PenaltyDestinationArgs;
+    emissionMode: EmissionModeArgs;
+    globalEmissionRate: number | bigint;
+    emissionCurve: EmissionCurveArgs;
+    loyaltySchedule: Array<LoyaltyTierArgs>;
+    lockTiers: Array<LockTierArgs>;
+    fixedTerm: FixedTermConfigArgs;
+    distributionInterval: number;
+    teamVesting: VestingScheduleArgs;
+    rewardSource: RewardSourceArgs;
+    emissionCeiling: number | bigint;
+    referralDepositBps: number;
+    referralClaimBps: number;
+    allowlist: AllowlistPhaseArgs;
+    grantAuthority: Address;
 };
 
 export function getGlobalAdminEncoder(): FixedSizeEncoder<GlobalAdminArgs> {
@@ -77,10 +151,32 @@ export function getGlobalAdminEncoder(): FixedSizeEncoder<GlobalAdminArgs> {
         ['nativeTokenMint', getAddressEncoder()],
         ['dailyEmissionRate', getU64Encoder()],
         ['maxEmissionPerBond', getU64Encoder()],
-        ['maxBondsPerWallet', getU8Encoder()],
-        ['tokenDepositSplit', getArrayEncoder(getU16Encoder(), { size: 3 })],
-        ['claimPenalty', getU16Encoder()],
+        ['maxBondsPerWallet', getU16Encoder()],
+        [
+            'claimPenaltySchedule',
+            getArrayEncoder(getPenaltyTierEncoder(), { size: 4 }),
+        ],
         ['pauseBondOperations', getBooleanEncoder()],
+        ['redemptionRate', getU16Encoder()],
+        ['marketplaceFee', getU16Encoder()],
+        ['penaltyDestination', getPenaltyDestinationEncoder()],
+        ['emissionMode', getEmissionModeEncoder()],
+        ['globalEmissionRate', getU64Encoder()],
+        ['emissionCurve', getEmissionCurveEncoder()],
+        [
+            'loyaltySchedule',
+            getArrayEncoder(getLoyaltyTierEncoder(), { size: 4 }),
+        ],
+        ['lockTiers', getArrayEncoder(getLockTierEncoder(), { size: 4 })],
+        ['fixedTerm', getFixedTermConfigEncoder()],
+        ['distributionInterval', getU32Encoder()],
+        ['teamVesting', getVestingScheduleEncoder()],
+        ['rewardSource', getRewardSourceEncoder()],
+        ['emissionCeiling', getU64Encoder()],
+        ['referralDepositBps', getU16Encoder()],
+        ['referralClaimBps', getU16Encoder()],
+        ['allowlist', getAllowlistPhaseEncoder()],
+        ['grantAuthority', getAddressEncoder()],
     ]);
 }
 
@@ -93,10 +189,32 @@ export function getGlobalAdminDecoder(): FixedSizeDecoder<GlobalAdmin> {
         ['nativeTokenMint', getAddressDecoder()],
         ['dailyEmissionRate', getU64Decoder()],
         ['maxEmissionPerBond', getU64Decoder()],
-        ['maxBondsPerWallet', getU8Decoder()],
-        ['tokenDepositSplit', getArrayDecoder(getU16Decoder(), { size: 3 })],
-        ['claimPenalty', getU16Decoder()],
+        ['maxBondsPerWallet', getU16Decoder()],
+        [
+            'claimPenaltySchedule',
+            getArrayDecoder(getPenaltyTierDecoder(), { size: 4 }),
+        ],
         ['pauseBondOperations', getBooleanDecoder()],
+        ['redemptionRate', getU16Decoder()],
+        ['marketplaceFee', getU16Decoder()],
+        ['penaltyDestination', getPenaltyDestinationDecoder()],
+        ['emissionMode', getEmissionModeDecoder()],
+        ['globalEmissionRate', getU64Decoder()],
+        ['emissionCurve', getEmissionCurveDecoder()],
+        [
+            'loyaltySchedule',
+            getArrayDecoder(getLoyaltyTierDecoder(), { size: 4 }),
+        ],
+        ['lockTiers', getArrayDecoder(getLockTierDecoder(), { size: 4 })],
+        ['fixedTerm', getFixedTermConfigDecoder()],
+        ['distributionInterval', getU32Decoder()],
+        ['teamVesting', getVestingScheduleDecoder()],
+        ['rewardSource', getRewardSourceDecoder()],
+        ['emissionCeiling', getU64Decoder()],
+        ['referralDepositBps', getU16Decoder()],
+        ['referralClaimBps', getU16Decoder()],
+        ['allowlist', getAllowlistPhaseDecoder()],
+        ['grantAuthority', getAddressDecoder()],
     ]);
 }
 
@@ -165,7 +283,7 @@ export async function fetchAllMaybeGlobalAdmin(
 }
 
 export function getGlobalAdminSize(): number {
-    return 186;
+    return 406;
 }
 
 export async function fetchGlobalAdminFromSeeds(
@@ -8,4 +8,12 @@
 
 export * from './bond';
 export * from './globalAdmin';
+export * from './globalState';
+export * from './listing';
+export * from './referrerStats';
+export * from './rewardStream';
+export * from './rewardStreamList';
+export * from './splitConfig';
+export * from './streamSettlement';
+export * from './userBondPage';
 export * from './userPda';
@@ -19,20 +19,20 @@ import {
     getArrayEncoder,
     getStructDecoder,
     getStructEncoder,
-    getTupleDecoder,
-    getTupleEncoder,
+    getU16Decoder,
+    getU16Encoder,
     getU64Decoder,
     getU64Encoder,
     getU8Decoder,
     getU8Encoder,
     type Account,
     type Address,
-    type Codec,
-    type Decoder,
     type EncodedAccount,
-    type Encoder,
     type FetchAccountConfig,
     type FetchAccountsConfig,
+    type FixedSizeCodec,
+    type FixedSizeDecoder,
+    type FixedSizeEncoder,
     type MaybeAccount,
     type MaybeEncodedAccount,
 } from '@solana/kit';
@@ -43,7 +43,11 @@ export type UserPda = {
     bondCount: number;
     totalAccruedRewards: bigint;
     bondIndex: number;
-    activeBonds: Array<readonly [number, Address]>;
+    pageCount: number;
+    totalPenalties: bigint;
+    referrer: Address;
+    allowlistRoot: Array<number>;
+    allowlistUsed: number;
 };
 
 export type UserPdaArgs = {
@@ -51,40 +55,42 @@ export type UserPdaArgs = {
     bondCount: number;
     totalAccruedRewards: number | bigint;
     bondIndex: number;
-    activeBonds: Array<readonly [number, Address]>;
+    pageCount: number;
+    totalPenalties: number | bigint;
+    referrer: Address;
+    allowlistRoot: Array<number>;
+    allowlistUsed: number;
 };
 
-export function getUserPdaEncoder(): Encoder<UserPdaArgs> {
+export function getUserPdaEncoder(): FixedSizeEncoder<UserPdaArgs> {
     return getStructEncoder([
         ['user', getAddressEncoder()],
-        ['bondCount', getU8Encoder()],
+        ['bondCount', getU16Encoder()],
         ['totalAccruedRewards', getU64Encoder()],
-        ['bondIndex', getU8Encoder()],
-        [
-            'activeBonds',
-            getArrayEncoder(
-                getTupleEncoder([getU8Encoder(), getAddressEncoder()])
-            ),
-        ],
+        ['bondIndex', getU16Encoder()],
+        ['pageCount', getU16Encoder()],
+        ['totalPenalties', getU64Encoder()],
+        ['referrer', getAddressEncoder()],
+        ['allowlistRoot', getArrayEncoder(getU8Encoder(), { size: 32 })],
+        ['allowlistUsed', getU16Encoder()],
     ]);
 }
 
-export function getUserPdaDecoder(): Decoder<UserPda> {
+export function getUserPdaDecoder(): FixedSizeDecoder<UserPda> {
     return getStructDecoder([
         ['user', getAddressDecoder()],
-        ['bondCount', getU8Decoder()],
+        ['bondCount', getU16Decoder()],
         ['totalAccruedRewards', getU64Decoder()],
-        ['bondIndex', getU8Decoder()],
-        [
-            'activeBonds',
-            getArrayDecoder(
-                getTupleDecoder([getU8Decoder(), getAddressDecoder()])
-            ),
-        ],
+        ['bondIndex', getU16Decoder()],
+        ['pageCount', getU16Decoder()],
+        ['totalPenalties', getU64Decoder()],
+        ['referrer', getAddressDecoder()],
+        ['allowlistRoot', getArrayDecoder(getU8Decoder(), { size: 32 })],
+        ['allowlistUsed', getU16Decoder()],
     ]);
 }
 
-export function getUserPdaCodec(): Codec<UserPdaArgs, UserPda> {
+export function getUserPdaCodec(): FixedSizeCodec<UserPdaArgs, UserPda> {
     return combineCodec(getUserPdaEncoder(), getUserPdaDecoder());
 }
 
@@ -141,6 +147,10 @@ export async function fetchAllMaybeUserPda(
     return maybeAccounts.map((maybeAccount) => decodeUserPda(maybeAccount));
 }
 
+export function getUserPdaSize(): number {
+    return 120;
+}
+
 export async function fetchUserPdaFromSeeds(
     rpc: Parameters<typeof fetchEncodedAccount>[0],
     seeds: UserPdaSeeds,
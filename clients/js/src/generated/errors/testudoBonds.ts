@@ -54,13 +54,80 @@ export const TESTUDO_BONDS_ERROR__MAX_BONDS_REACHED = 0x11; // 17
 export const TESTUDO_BONDS_ERROR__BOND_OPERATIONS_PAUSED = 0x12; // 18
 /** BondIsActive: Bond is active */
 export const TESTUDO_BONDS_ERROR__BOND_IS_ACTIVE = 0x13; // 19
+/** BondPageFull: Bond page is full */
+export const TESTUDO_BONDS_ERROR__BOND_PAGE_FULL = 0x14; // 20
+/** BondIsTokenized: Bond is tokenized */
+export const TESTUDO_BONDS_ERROR__BOND_IS_TOKENIZED = 0x15; // 21
+/** NotBondHolder: Signer does not hold the bond token */
+export const TESTUDO_BONDS_ERROR__NOT_BOND_HOLDER = 0x16; // 22
+/** BondIsListed: Bond is listed for sale */
+export const TESTUDO_BONDS_ERROR__BOND_IS_LISTED = 0x17; // 23
+/** InvalidListingPrice: Invalid listing price */
+export const TESTUDO_BONDS_ERROR__INVALID_LISTING_PRICE = 0x18; // 24
+/** InvalidBondWeight: Invalid bond weight */
+export const TESTUDO_BONDS_ERROR__INVALID_BOND_WEIGHT = 0x19; // 25
+/** InvalidPenaltySchedule: Invalid penalty schedule */
+export const TESTUDO_BONDS_ERROR__INVALID_PENALTY_SCHEDULE = 0x1a; // 26
+/** InvalidTimestamp: Current timestamp is before the last claim */
+export const TESTUDO_BONDS_ERROR__INVALID_TIMESTAMP = 0x1b; // 27
+/** NumericalUnderflow: Numerical underflow */
+export const TESTUDO_BONDS_ERROR__NUMERICAL_UNDERFLOW = 0x1c; // 28
+/** InvalidEmissionCurve: Invalid emission curve */
+export const TESTUDO_BONDS_ERROR__INVALID_EMISSION_CURVE = 0x1d; // 29
+/** InvalidLoyaltySchedule: Invalid loyalty schedule */
+export const TESTUDO_BONDS_ERROR__INVALID_LOYALTY_SCHEDULE = 0x1e; // 30
+/** BondLocked: Bond is locked */
+export const TESTUDO_BONDS_ERROR__BOND_LOCKED = 0x1f; // 31
+/** InvalidLockTier: Invalid lock tier */
+export const TESTUDO_BONDS_ERROR__INVALID_LOCK_TIER = 0x20; // 32
+/** LockBoostMismatch: Bonds with different lock boosts cannot be merged */
+export const TESTUDO_BONDS_ERROR__LOCK_BOOST_MISMATCH = 0x21; // 33
+/** InvalidFixedTerm: Fixed-term bonds are unavailable or misconfigured */
+export const TESTUDO_BONDS_ERROR__INVALID_FIXED_TERM = 0x22; // 34
+/** BondIsFixedTerm: Bond is a fixed-term bond */
+export const TESTUDO_BONDS_ERROR__BOND_IS_FIXED_TERM = 0x23; // 35
+/** BondNotMature: Bond is not a matured fixed-term bond */
+export const TESTUDO_BONDS_ERROR__BOND_NOT_MATURE = 0x24; // 36
+/** DistributionNotDue: Vault distribution is not due yet */
+export const TESTUDO_BONDS_ERROR__DISTRIBUTION_NOT_DUE = 0x25; // 37
+/** InvalidVestingSchedule: Invalid vesting schedule */
+export const TESTUDO_BONDS_ERROR__INVALID_VESTING_SCHEDULE = 0x26; // 38
+/** NothingToRelease: No vested tokens to release */
+export const TESTUDO_BONDS_ERROR__NOTHING_TO_RELEASE = 0x27; // 39
+/** InvalidSplitConfig: Invalid deposit split config */
+export const TESTUDO_BONDS_ERROR__INVALID_SPLIT_CONFIG = 0x28; // 40
+/** EmissionCeilingReached: Minting the rewards would exceed the emission ceiling */
+export const TESTUDO_BONDS_ERROR__EMISSION_CEILING_REACHED = 0x29; // 41
+/** InvalidRewardStream: Invalid reward stream */
+export const TESTUDO_BONDS_ERROR__INVALID_REWARD_STREAM = 0x2a; // 42
+/** InvalidReferral: Invalid referrer or referral rate */
+export const TESTUDO_BONDS_ERROR__INVALID_REFERRAL = 0x2b; // 43
+/** InvalidAllowlistPhase: Invalid allowlist phase */
+export const TESTUDO_BONDS_ERROR__INVALID_ALLOWLIST_PHASE = 0x2c; // 44
+/** NotAllowlisted: Wallet is not allowlisted or has used its allocation */
+export const TESTUDO_BONDS_ERROR__NOT_ALLOWLISTED = 0x2d; // 45
+/** BondGrantMismatch: Granted and bought bonds cannot be merged */
+export const TESTUDO_BONDS_ERROR__BOND_GRANT_MISMATCH = 0x2e; // 46
+/** PriceAboveMaximum: Listing price is above the buyer's maximum price */
+export const TESTUDO_BONDS_ERROR__PRICE_ABOVE_MAXIMUM = 0x2f; // 47
+/** InvalidGrantSource: A granted bond needs a grant source */
+export const TESTUDO_BONDS_ERROR__INVALID_GRANT_SOURCE = 0x30; // 48
 
 export type TestudoBondsError =
     | typeof TESTUDO_BONDS_ERROR__ACCOUNT_MISMATCH
+    | typeof TESTUDO_BONDS_ERROR__BOND_GRANT_MISMATCH
     | typeof TESTUDO_BONDS_ERROR__BOND_IS_ACTIVE
+    | typeof TESTUDO_BONDS_ERROR__BOND_IS_FIXED_TERM
+    | typeof TESTUDO_BONDS_ERROR__BOND_IS_LISTED
+    | typeof TESTUDO_BONDS_ERROR__BOND_IS_TOKENIZED
+    | typeof TESTUDO_BONDS_ERROR__BOND_LOCKED
     | typeof TESTUDO_BONDS_ERROR__BOND_NOT_ACTIVE
+    | typeof TESTUDO_BONDS_ERROR__BOND_NOT_MATURE
     | typeof TESTUDO_BONDS_ERROR__BOND_OPERATIONS_PAUSED
+    | typeof TESTUDO_BONDS_ERROR__BOND_PAGE_FULL
     | typeof TESTUDO_BONDS_ERROR__DESERIALIZATION_ERROR
+    | typeof TESTUDO_BONDS_ERROR__DISTRIBUTION_NOT_DUE
+    | typeof TESTUDO_BONDS_ERROR__EMISSION_CEILING_REACHED
     | typeof TESTUDO_BONDS_ERROR__EXPECTED_EMPTY_ACCOUNT
     | typeof TESTUDO_BONDS_ERROR__EXPECTED_NON_EMPTY_ACCOUNT
     | typeof TESTUDO_BONDS_ERROR__EXPECTED_SIGNER_ACCOUNT
@@ -68,23 +135,52 @@ export type TestudoBondsError =
     | typeof TESTUDO_BONDS_ERROR__INSUFFICIENT_REWARDS
     | typeof TESTUDO_BONDS_ERROR__INSUFFICIENT_TOKENS
     | typeof TESTUDO_BONDS_ERROR__INVALID_ACCOUNT_KEY
+    | typeof TESTUDO_BONDS_ERROR__INVALID_ALLOWLIST_PHASE
     | typeof TESTUDO_BONDS_ERROR__INVALID_BOND_INDEX
+    | typeof TESTUDO_BONDS_ERROR__INVALID_BOND_WEIGHT
+    | typeof TESTUDO_BONDS_ERROR__INVALID_EMISSION_CURVE
+    | typeof TESTUDO_BONDS_ERROR__INVALID_FIXED_TERM
+    | typeof TESTUDO_BONDS_ERROR__INVALID_GRANT_SOURCE
+    | typeof TESTUDO_BONDS_ERROR__INVALID_LISTING_PRICE
+    | typeof TESTUDO_BONDS_ERROR__INVALID_LOCK_TIER
+    | typeof TESTUDO_BONDS_ERROR__INVALID_LOYALTY_SCHEDULE
     | typeof TESTUDO_BONDS_ERROR__INVALID_PDA
+    | typeof TESTUDO_BONDS_ERROR__INVALID_PENALTY_SCHEDULE
     | typeof TESTUDO_BONDS_ERROR__INVALID_PROGRAM_OWNER
+    | typeof TESTUDO_BONDS_ERROR__INVALID_REFERRAL
+    | typeof TESTUDO_BONDS_ERROR__INVALID_REWARD_STREAM
+    | typeof TESTUDO_BONDS_ERROR__INVALID_SPLIT_CONFIG
+    | typeof TESTUDO_BONDS_ERROR__INVALID_TIMESTAMP
     | typeof TESTUDO_BONDS_ERROR__INVALID_TOKEN_ACCOUNTS
+    | typeof TESTUDO_BONDS_ERROR__INVALID_VESTING_SCHEDULE
+    | typeof TESTUDO_BONDS_ERROR__LOCK_BOOST_MISMATCH
     | typeof TESTUDO_BONDS_ERROR__MAX_BONDS_REACHED
+    | typeof TESTUDO_BONDS_ERROR__NOTHING_TO_RELEASE
+    | typeof TESTUDO_BONDS_ERROR__NOT_ALLOWLISTED
+    | typeof TESTUDO_BONDS_ERROR__NOT_BOND_HOLDER
     | typeof TESTUDO_BONDS_ERROR__NO_REWARDS_TO_CLAIM
     | typeof TESTUDO_BONDS_ERROR__NUMERICAL_OVERFLOW
+    | typeof TESTUDO_BONDS_ERROR__NUMERICAL_UNDERFLOW
+    | typeof TESTUDO_BONDS_ERROR__PRICE_ABOVE_MAXIMUM
     | typeof TESTUDO_BONDS_ERROR__SERIALIZATION_ERROR;
 
 let testudoBondsErrorMessages: Record<TestudoBondsError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
     testudoBondsErrorMessages = {
         [TESTUDO_BONDS_ERROR__ACCOUNT_MISMATCH]: `Account mismatch`,
+        [TESTUDO_BONDS_ERROR__BOND_GRANT_MISMATCH]: `Granted and bought bonds cannot be merged`,
         [TESTUDO_BONDS_ERROR__BOND_IS_ACTIVE]: `Bond is active`,
+        [TESTUDO_BONDS_ERROR__BOND_IS_FIXED_TERM]: `Bond is a fixed-term bond`,
+        [TESTUDO_BONDS_ERROR__BOND_IS_LISTED]: `Bond is listed for sale`,
+        [TESTUDO_BONDS_ERROR__BOND_IS_TOKENIZED]: `Bond is tokenized`,
+        [TESTUDO_BONDS_ERROR__BOND_LOCKED]: `Bond is locked`,
         [TESTUDO_BONDS_ERROR__BOND_NOT_ACTIVE]: `Bond not active`,
+        [TESTUDO_BONDS_ERROR__BOND_NOT_MATURE]: `Bond is not a matured fixed-term bond`,
         [TESTUDO_BONDS_ERROR__BOND_OPERATIONS_PAUSED]: `Bond operations paused`,
+        [TESTUDO_BONDS_ERROR__BOND_PAGE_FULL]: `Bond page is full`,
         [TESTUDO_BONDS_ERROR__DESERIALIZATION_ERROR]: `Error deserializing an account`,
+        [TESTUDO_BONDS_ERROR__DISTRIBUTION_NOT_DUE]: `Vault distribution is not due yet`,
+        [TESTUDO_BONDS_ERROR__EMISSION_CEILING_REACHED]: `Minting the rewards would exceed the emission ceiling`,
         [TESTUDO_BONDS_ERROR__EXPECTED_EMPTY_ACCOUNT]: `Expected empty account`,
         [TESTUDO_BONDS_ERROR__EXPECTED_NON_EMPTY_ACCOUNT]: `Expected non empty account`,
         [TESTUDO_BONDS_ERROR__EXPECTED_SIGNER_ACCOUNT]: `Expected signer account`,
@@ -92,13 +188,33 @@ if (process.env.NODE_ENV !== 'production') {
         [TESTUDO_BONDS_ERROR__INSUFFICIENT_REWARDS]: `Insufficient rewards`,
         [TESTUDO_BONDS_ERROR__INSUFFICIENT_TOKENS]: `Insufficient native tokens`,
         [TESTUDO_BONDS_ERROR__INVALID_ACCOUNT_KEY]: `Invalid account key`,
+        [TESTUDO_BONDS_ERROR__INVALID_ALLOWLIST_PHASE]: `Invalid allowlist phase`,
         [TESTUDO_BONDS_ERROR__INVALID_BOND_INDEX]: `Invalid bond index`,
+        [TESTUDO_BONDS_ERROR__INVALID_BOND_WEIGHT]: `Invalid bond weight`,
+        [TESTUDO_BONDS_ERROR__INVALID_EMISSION_CURVE]: `Invalid emission curve`,
+        [TESTUDO_BONDS_ERROR__INVALID_FIXED_TERM]: `Fixed-term bonds are unavailable or misconfigured`,
+        [TESTUDO_BONDS_ERROR__INVALID_GRANT_SOURCE]: `A granted bond needs a grant source`,
+        [TESTUDO_BONDS_ERROR__INVALID_LISTING_PRICE]: `Invalid listing price`,
+        [TESTUDO_BONDS_ERROR__INVALID_LOCK_TIER]: `Invalid lock tier`,
+        [TESTUDO_BONDS_ERROR__INVALID_LOYALTY_SCHEDULE]: `Invalid loyalty schedule`,
         [TESTUDO_BONDS_ERROR__INVALID_PDA]: `Invalid PDA derivation`,
+        [TESTUDO_BONDS_ERROR__INVALID_PENALTY_SCHEDULE]: `Invalid penalty schedule`,
         [TESTUDO_BONDS_ERROR__INVALID_PROGRAM_OWNER]: `Invalid program owner. This likely mean the provided account does not exist`,
+        [TESTUDO_BONDS_ERROR__INVALID_REFERRAL]: `Invalid referrer or referral rate`,
+        [TESTUDO_BONDS_ERROR__INVALID_REWARD_STREAM]: `Invalid reward stream`,
+        [TESTUDO_BONDS_ERROR__INVALID_SPLIT_CONFIG]: `Invalid deposit split config`,
+        [TESTUDO_BONDS_ERROR__INVALID_TIMESTAMP]: `Current timestamp is before the last claim`,
         [TESTUDO_BONDS_ERROR__INVALID_TOKEN_ACCOUNTS]: `Invalid token accounts`,
+        [TESTUDO_BONDS_ERROR__INVALID_VESTING_SCHEDULE]: `Invalid vesting schedule`,
+        [TESTUDO_BONDS_ERROR__LOCK_BOOST_MISMATCH]: `Bonds with different lock boosts cannot be merged`,
         [TESTUDO_BONDS_ERROR__MAX_BONDS_REACHED]: `Max bonds reached`,
+        [TESTUDO_BONDS_ERROR__NOTHING_TO_RELEASE]: `No vested tokens to release`,
+        [TESTUDO_BONDS_ERROR__NOT_ALLOWLISTED]: `Wallet is not allowlisted or has used its allocation`,
+        [TESTUDO_BONDS_ERROR__NOT_BOND_HOLDER]: `Signer does not hold the bond token`,
         [TESTUDO_BONDS_ERROR__NO_REWARDS_TO_CLAIM]: `No rewards to claim`,
         [TESTUDO_BONDS_ERROR__NUMERICAL_OVERFLOW]: `Numerical overflow`,
+        [TESTUDO_BONDS_ERROR__NUMERICAL_UNDERFLOW]: `Numerical underflow`,
+        [TESTUDO_BONDS_ERROR__PRICE_ABOVE_MAXIMUM]: `Listing price is above the buyer's maximum price`,
         [TESTUDO_BONDS_ERROR__SERIALIZATION_ERROR]: `Error serializing an account`,
     };
 }
@@ -6,8 +6,22 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './attachRewardStream';
+export * from './buyBond';
+export * from './cancelListing';
 export * from './createUser';
+export * from './detachRewardStream';
+export * from './distributeVault';
+export * from './grantBond';
 export * from './initializeAdmin';
 export * from './initializeBond';
+export * from './listBond';
+export * from './matureBond';
+export * from './mergeBonds';
 export * from './processClaim';
+export * from './redeemBond';
+export * from './releaseVested';
+export * from './splitBond';
+export * from './transferBond';
 export * from './updateAdmin';
+export * from './updateSplitConfig';
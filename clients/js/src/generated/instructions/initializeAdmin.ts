@@ -63,6 +63,12 @@ export type InitializeAdminInstruction<
     TAccountAssociatedTokenProgram extends
         | string
         | AccountMeta<string> = string,
+    TAccountGlobalState extends string | AccountMeta<string> = string,
+    TAccountPrincipalVault extends string | AccountMeta<string> = string,
+    TAccountPrincipalVaultAta extends string | AccountMeta<string> = string,
+    TAccountTeamVesting extends string | AccountMeta<string> = string,
+    TAccountTeamVestingAta extends string | AccountMeta<string> = string,
+    TAccountSplitConfig extends string | AccountMeta<string> = string,
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -102,6 +108,24 @@ export type InitializeAdminInstruction<
             TAccountAssociatedTokenProgram extends string
                 ? ReadonlyAccount<TAccountAssociatedTokenProgram>
                 : TAccountAssociatedTokenProgram,
+            TAccountGlobalState extends string
+                ? WritableAccount<TAccountGlobalState>
+                : TAccountGlobalState,
+            TAccountPrincipalVault extends string
+                ? ReadonlyAccount<TAccountPrincipalVault>
+                : TAccountPrincipalVault,
+            TAccountPrincipalVaultAta extends string
+                ? WritableAccount<TAccountPrincipalVaultAta>
+                : TAccountPrincipalVaultAta,
+            TAccountTeamVesting extends string
+                ? ReadonlyAccount<TAccountTeamVesting>
+                : TAccountTeamVesting,
+            TAccountTeamVestingAta extends string
+                ? WritableAccount<TAccountTeamVestingAta>
+                : TAccountTeamVestingAta,
+            TAccountSplitConfig extends string
+                ? WritableAccount<TAccountSplitConfig>
+                : TAccountSplitConfig,
             ...TRemainingAccounts,
         ]
     >;
@@ -143,6 +167,12 @@ export type InitializeAdminAsyncInput<
     TAccountSystemProgram extends string = string,
     TAccountTokenProgram extends string = string,
     TAccountAssociatedTokenProgram extends string = string,
+    TAccountGlobalState extends string = string,
+    TAccountPrincipalVault extends string = string,
+    TAccountPrincipalVaultAta extends string = string,
+    TAccountTeamVesting extends string = string,
+    TAccountTeamVestingAta extends string = string,
+    TAccountSplitConfig extends string = string,
 > = {
     /** The program derived address of the global admin account to create (seeds: ['global_admin']) */
     globalAdmin?: Address<TAccountGlobalAdmin>;
@@ -166,6 +196,18 @@ export type InitializeAdminAsyncInput<
     tokenProgram?: Address<TAccountTokenProgram>;
     /** The associated token program */
     associatedTokenProgram: Address<TAccountAssociatedTokenProgram>;
+    /** The program derived address of the global state account to create (seeds: ['global_state']) */
+    globalState: Address<TAccountGlobalState>;
+    /** The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault']) */
+    principalVault: Address<TAccountPrincipalVault>;
+    /** The token account of the principal vault */
+    principalVaultAta: Address<TAccountPrincipalVaultAta>;
+    /** The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting']) */
+    teamVesting: Address<TAccountTeamVesting>;
+    /** The token account of the team vesting escrow */
+    teamVestingAta: Address<TAccountTeamVestingAta>;
+    /** The program derived address of the deposit split config to create (seeds: ['split_config']) */
+    splitConfig: Address<TAccountSplitConfig>;
 };
 
 export async function getInitializeAdminInstructionAsync<
@@ -180,6 +222,12 @@ export async function getInitializeAdminInstructionAsync<
     TAccountSystemProgram extends string,
     TAccountTokenProgram extends string,
     TAccountAssociatedTokenProgram extends string,
+    TAccountGlobalState extends string,
+    TAccountPrincipalVault extends string,
+    TAccountPrincipalVaultAta extends string,
+    TAccountTeamVesting extends string,
+    TAccountTeamVestingAta extends string,
+    TAccountSplitConfig extends string,
     TProgramAddress extends Address = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
 >(
     input: InitializeAdminAsyncInput<
@@ -193,7 +241,13 @@ export async function getInitializeAdminInstructionAsync<
         TAccountNativeTokenMint,
         TAccountSystemProgram,
         TAccountTokenProgram,
-        TAccountAssociatedTokenProgram
+        TAccountAssociatedTokenProgram,
+        TAccountGlobalState,
+        TAccountPrincipalVault,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVesting,
+        TAccountTeamVestingAta,
+        TAccountSplitConfig
     >,
     config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -209,7 +263,13 @@ export async function getInitializeAdminInstructionAsync<
         TAccountNativeTokenMint,
         TAccountSystemProgram,
         TAccountTokenProgram,
-        TAccountAssociatedTokenProgram
+        TAccountAssociatedTokenProgram,
+        TAccountGlobalState,
+        TAccountPrincipalVault,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVesting,
+        TAccountTeamVestingAta,
+        TAccountSplitConfig
     > &
         InstructionWithByteDelta
 > {
@@ -242,6 +302,21 @@ export async function getInitializeAdminInstructionAsync<
             value: input.associatedTokenProgram ?? null,
             isWritable: false,
         },
+        globalState: { value: input.globalState ?? null, isWritable: true },
+        principalVault: {
+            value: input.principalVault ?? null,
+            isWritable: false,
+        },
+        principalVaultAta: {
+            value: input.principalVaultAta ?? null,
+            isWritable: true,
+        },
+        teamVesting: { value: input.teamVesting ?? null, isWritable: false },
+        teamVestingAta: {
+            value: input.teamVestingAta ?? null,
+            isWritable: true,
+        },
+        splitConfig: { value: input.splitConfig ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<
         keyof typeof originalAccounts,
@@ -281,6 +356,12 @@ export async function getInitializeAdminInstructionAsync<
             getAccountMeta(accounts.systemProgram),
             getAccountMeta(accounts.tokenProgram),
             getAccountMeta(accounts.associatedTokenProgram),
+            getAccountMeta(accounts.globalState),
+            getAccountMeta(accounts.principalVault),
+            getAccountMeta(accounts.principalVaultAta),
+            getAccountMeta(accounts.teamVesting),
+            getAccountMeta(accounts.teamVestingAta),
+            getAccountMeta(accounts.splitConfig),
         ],
         programAddress,
         data: getInitializeAdminInstructionDataEncoder().encode({}),
@@ -296,7 +377,13 @@ export async function getInitializeAdminInstructionAsync<
         TAccountNativeTokenMint,
         TAccountSystemProgram,
         TAccountTokenProgram,
-        TAccountAssociatedTokenProgram
+        TAccountAssociatedTokenProgram,
+        TAccountGlobalState,
+        TAccountPrincipalVault,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVesting,
+        TAccountTeamVestingAta,
+        TAccountSplitConfig
     >;
 
     return Object.freeze({ ...instruction, byteDelta });
@@ -314,6 +401,12 @@ export type InitializeAdminInput<
     TAccountSystemProgram extends string = string,
     TAccountTokenProgram extends string = string,
     TAccountAssociatedTokenProgram extends string = string,
+    TAccountGlobalState extends string = string,
+    TAccountPrincipalVault extends string = string,
+    TAccountPrincipalVaultAta extends string = string,
+    TAccountTeamVesting extends string = string,
+    TAccountTeamVestingAta extends string = string,
+    TAccountSplitConfig extends string = string,
 > = {
     /** The program derived address of the global admin account to create (seeds: ['global_admin']) */
     globalAdmin: Address<TAccountGlobalAdmin>;
@@ -337,6 +430,18 @@ export type InitializeAdminInput<
     tokenProgram?: Address<TAccountTokenProgram>;
     /** The associated token program */
     associatedTokenProgram: Address<TAccountAssociatedTokenProgram>;
+    /** The program derived address of the global state account to create (seeds: ['global_state']) */
+    globalState: Address<TAccountGlobalState>;
+    /** The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault']) */
+    principalVault: Address<TAccountPrincipalVault>;
+    /** The token account of the principal vault */
+    principalVaultAta: Address<TAccountPrincipalVaultAta>;
+    /** The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting']) */
+    teamVesting: Address<TAccountTeamVesting>;
+    /** The token account of the team vesting escrow */
+    teamVestingAta: Address<TAccountTeamVestingAta>;
+    /** The program derived address of the deposit split config to create (seeds: ['split_config']) */
+    splitConfig: Address<TAccountSplitConfig>;
 };
 
 export function getInitializeAdminInstruction<
@@ -351,6 +456,12 @@ export function getInitializeAdminInstruction<
     TAccountSystemProgram extends string,
     TAccountTokenProgram extends string,
     TAccountAssociatedTokenProgram extends string,
+    TAccountGlobalState extends string,
+    TAccountPrincipalVault extends string,
+    TAccountPrincipalVaultAta extends string,
+    TAccountTeamVesting extends string,
+    TAccountTeamVestingAta extends string,
+    TAccountSplitConfig extends string,
     TProgramAddress extends Address = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
 >(
     input: InitializeAdminInput<
@@ -364,7 +475,13 @@ export function getInitializeAdminInstruction<
         TAccountNativeTokenMint,
         TAccountSystemProgram,
         TAccountTokenProgram,
-        TAccountAssociatedTokenProgram
+        TAccountAssociatedTokenProgram,
+        TAccountGlobalState,
+        TAccountPrincipalVault,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVesting,
+        TAccountTeamVestingAta,
+        TAccountSplitConfig
     >,
     config?: { programAddress?: TProgramAddress }
 ): InitializeAdminInstruction<
@@ -379,7 +496,13 @@ export function getInitializeAdminInstruction<
     TAccountNativeTokenMint,
     TAccountSystemProgram,
     TAccountTokenProgram,
-    TAccountAssociatedTokenProgram
+    TAccountAssociatedTokenProgram,
+    TAccountGlobalState,
+    TAccountPrincipalVault,
+    TAccountPrincipalVaultAta,
+    TAccountTeamVesting,
+    TAccountTeamVestingAta,
+    TAccountSplitConfig
 > &
     InstructionWithByteDelta {
     // Program address.
@@ -411,6 +534,21 @@ export function getInitializeAdminInstruction<
             value: input.associatedTokenProgram ?? null,
             isWritable: false,
         },
+        globalState: { value: input.globalState ?? null, isWritable: true },
+        principalVault: {
+            value: input.principalVault ?? null,
+            isWritable: false,
+        },
+        principalVaultAta: {
+            value: input.principalVaultAta ?? null,
+            isWritable: true,
+        },
+        teamVesting: { value: input.teamVesting ?? null, isWritable: false },
+        teamVestingAta: {
+            value: input.teamVestingAta ?? null,
+            isWritable: true,
+        },
+        splitConfig: { value: input.splitConfig ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<
         keyof typeof originalAccounts,
@@ -447,6 +585,12 @@ export function getInitializeAdminInstruction<
             getAccountMeta(accounts.systemProgram),
             getAccountMeta(accounts.tokenProgram),
             getAccountMeta(accounts.associatedTokenProgram),
+            getAccountMeta(accounts.globalState),
+            getAccountMeta(accounts.principalVault),
+            getAccountMeta(accounts.principalVaultAta),
+            getAccountMeta(accounts.teamVesting),
+            getAccountMeta(accounts.teamVestingAta),
+            getAccountMeta(accounts.splitConfig),
         ],
         programAddress,
         data: getInitializeAdminInstructionDataEncoder().encode({}),
@@ -462,7 +606,13 @@ export function getInitializeAdminInstruction<
         TAccountNativeTokenMint,
         TAccountSystemProgram,
         TAccountTokenProgram,
-        TAccountAssociatedTokenProgram
+        TAccountAssociatedTokenProgram,
+        TAccountGlobalState,
+        TAccountPrincipalVault,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVesting,
+        TAccountTeamVestingAta,
+        TAccountSplitConfig
     >;
 
     return Object.freeze({ ...instruction, byteDelta });
@@ -496,6 +646,18 @@ export type ParsedInitializeAdminInstruction<
         tokenProgram: TAccountMetas[9];
         /** The associated token program */
         associatedTokenProgram: TAccountMetas[10];
+        /** The program derived address of the global state account to create (seeds: ['global_state']) */
+        globalState: TAccountMetas[11];
+        /** The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault']) */
+        principalVault: TAccountMetas[12];
+        /** The token account of the principal vault */
+        principalVaultAta: TAccountMetas[13];
+        /** The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting']) */
+        teamVesting: TAccountMetas[14];
+        /** The token account of the team vesting escrow */
+        teamVestingAta: TAccountMetas[15];
+        /** The program derived address of the deposit split config to create (seeds: ['split_config']) */
+        splitConfig: TAccountMetas[16];
     };
     data: InitializeAdminInstructionData;
 };
@@ -508,7 +670,7 @@ export function parseInitializeAdminInstruction<
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeAdminInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 11) {
+    if (instruction.accounts.length < 17) {
         // TODO: Coded error.
         throw new Error('Not enough accounts');
     }
@@ -532,6 +694,12 @@ export function parseInitializeAdminInstruction<
             systemProgram: getNextAccount(),
             tokenProgram: getNextAccount(),
             associatedTokenProgram: getNextAccount(),
+            globalState: getNextAccount(),
+            principalVault: getNextAccount(),
+            principalVaultAta: getNextAccount(),
+            teamVesting: getNextAccount(),
+            teamVestingAta: getNextAccount(),
+            splitConfig: getNextAccount(),
         },
         data: getInitializeAdminInstructionDataDecoder().decode(
             instruction.data
@@ -9,6 +9,10 @@
 import {
     BASE_ACCOUNT_SIZE,
     combineCodec,
+    getBooleanDecoder,
+    getBooleanEncoder,
+    getOptionDecoder,
+    getOptionEncoder,
     getStructDecoder,
     getStructEncoder,
     getU8Decoder,
@@ -17,12 +21,14 @@ import {
     type AccountMeta,
     type AccountSignerMeta,
     type Address,
-    type FixedSizeCodec,
-    type FixedSizeDecoder,
-    type FixedSizeEncoder,
+    type Codec,
+    type Decoder,
+    type Encoder,
     type Instruction,
     type InstructionWithAccounts,
     type InstructionWithData,
+    type Option,
+    type OptionOrNullable,
     type ReadonlyAccount,
     type ReadonlySignerAccount,
     type ReadonlyUint8Array,
@@ -38,6 +44,12 @@ import {
     type InstructionWithByteDelta,
     type ResolvedAccount,
 } from '../shared';
+import {
+    getAllowlistProofDecoder,
+    getAllowlistProofEncoder,
+    type AllowlistProof,
+    type AllowlistProofArgs,
+} from '../types';
 
 export const INITIALIZE_BOND_DISCRIMINATOR = 2;
 
@@ -50,11 +62,12 @@ export type InitializeBondInstruction<
     TAccountBond extends string | AccountMeta<string> = string,
     TAccountUserWallet extends string | AccountMeta<string> = string,
     TAccountUserPda extends string | AccountMeta<string> = string,
+    TAccountUserBondPage extends string | AccountMeta<string> = string,
     TAccountGlobalAdmin extends string | AccountMeta<string> = string,
     TAccountUserWalletAta extends string | AccountMeta<string> = string,
     TAccountRewardsPoolAta extends string | AccountMeta<string> = string,
-    TAccountTreasuryAta extends string | AccountMeta<string> = string,
-    TAccountTeamAta extends string | AccountMeta<string> = string,
+    TAccountPrincipalVaultAta extends string | AccountMeta<string> = string,
+    TAccountTeamVestingAta extends string | AccountMeta<string> = string,
     TAccountNativeTokenMint extends string | AccountMeta<string> = string,
     TAccountSystemProgram extends
         | string
@@ -62,6 +75,17 @@ export type InitializeBondInstruction<
     TAccountTokenProgram extends
         | string
         | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+    TAccountGlobalState extends string | AccountMeta<string> = string,
+    TAccountSplitConfig extends string | AccountMeta<string> = string,
+    TAccountBondMint extends string | AccountMeta<string> = string,
+    TAccountBondMintAuthority extends string | AccountMeta<string> = string,
+    TAccountUserBondTokenAccount extends string | AccountMeta<string> = string,
+    TAccountAssociatedTokenProgram extends
+        | string
+        | AccountMeta<string> = string,
+    TAccountReferrerPda extends string | AccountMeta<string> = string,
+    TAccountReferrerAta extends string | AccountMeta<string> = string,
+    TAccountReferrerStats extends string | AccountMeta<string> = string,
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -77,6 +101,9 @@ export type InitializeBondInstruction<
             TAccountUserPda extends string
                 ? WritableAccount<TAccountUserPda>
                 : TAccountUserPda,
+            TAccountUserBondPage extends string
+                ? WritableAccount<TAccountUserBondPage>
+                : TAccountUserBondPage,
             TAccountGlobalAdmin extends string
                 ? ReadonlyAccount<TAccountGlobalAdmin>
                 : TAccountGlobalAdmin,
@@ -86,14 +113,14 @@ export type InitializeBondInstruction<
             TAccountRewardsPoolAta extends string
                 ? WritableAccount<TAccountRewardsPoolAta>
                 : TAccountRewardsPoolAta,
-            TAccountTreasuryAta extends string
-                ? WritableAccount<TAccountTreasuryAta>
-                : TAccountTreasuryAta,
-            TAccountTeamAta extends string
-                ? WritableAccount<TAccountTeamAta>
-                : TAccountTeamAta,
+            TAccountPrincipalVaultAta extends string
+                ? WritableAccount<TAccountPrincipalVaultAta>
+                : TAccountPrincipalVaultAta,
+            TAccountTeamVestingAta extends string
+                ? WritableAccount<TAccountTeamVestingAta>
+                : TAccountTeamVestingAta,
             TAccountNativeTokenMint extends string
-                ? ReadonlyAccount<TAccountNativeTokenMint>
+                ? WritableAccount<TAccountNativeTokenMint>
                 : TAccountNativeTokenMint,
             TAccountSystemProgram extends string
                 ? ReadonlyAccount<TAccountSystemProgram>
@@ -101,26 +128,76 @@ export type InitializeBondInstruction<
             TAccountTokenProgram extends string
                 ? ReadonlyAccount<TAccountTokenProgram>
                 : TAccountTokenProgram,
+            TAccountGlobalState extends string
+                ? WritableAccount<TAccountGlobalState>
+                : TAccountGlobalState,
+            TAccountSplitConfig extends string
+                ? ReadonlyAccount<TAccountSplitConfig>
+                : TAccountSplitConfig,
+            TAccountBondMint extends string
+                ? WritableAccount<TAccountBondMint>
+                : TAccountBondMint,
+            TAccountBondMintAuthority extends string
+                ? ReadonlyAccount<TAccountBondMintAuthority>
+                : TAccountBondMintAuthority,
+            TAccountUserBondTokenAccount extends string
+                ? WritableAccount<TAccountUserBondTokenAccount>
+                : TAccountUserBondTokenAccount,
+            TAccountAssociatedTokenProgram extends string
+                ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+                : TAccountAssociatedTokenProgram,
+            TAccountReferrerPda extends string
+                ? ReadonlyAccount<TAccountReferrerPda>
+                : TAccountReferrerPda,
+            TAccountReferrerAta extends string
+                ? WritableAccount<TAccountReferrerAta>
+                : TAccountReferrerAta,
+            TAccountReferrerStats extends string
+                ? WritableAccount<TAccountReferrerStats>
+                : TAccountReferrerStats,
             ...TRemainingAccounts,
         ]
     >;
 
-export type InitializeBondInstructionData = { discriminator: number };
+export type InitializeBondInstructionData = {
+    discriminator: number;
+    tokenize: boolean;
+    lockTier: Option<number>;
+    fixedTerm: boolean;
+    allowlistProof: Option<AllowlistProof>;
+};
 
-export type InitializeBondInstructionDataArgs = {};
+export type InitializeBondInstructionDataArgs = {
+    tokenize: boolean;
+    lockTier: OptionOrNullable<number>;
+    fixedTerm: boolean;
+    allowlistProof: OptionOrNullable<AllowlistProofArgs>;
+};
 
-export function getInitializeBondInstructionDataEncoder(): FixedSizeEncoder<InitializeBondInstructionDataArgs> {
+export function getInitializeBondInstructionDataEncoder(): Encoder<InitializeBondInstructionDataArgs> {
     return transformEncoder(
-        getStructEncoder([['discriminator', getU8Encoder()]]),
+        getStructEncoder([
+            ['discriminator', getU8Encoder()],
+            ['tokenize', getBooleanEncoder()],
+            ['lockTier', getOptionEncoder(getU8Encoder())],
+            ['fixedTerm', getBooleanEncoder()],
+            ['allowlistProof', getOptionEncoder(getAllowlistProofEncoder())],
+        ]),
         (value) => ({ ...value, discriminator: INITIALIZE_BOND_DISCRIMINATOR })
     );
 }
 
-export function getInitializeBondInstructionDataDecoder(): FixedSizeDecoder<InitializeBondInstructionData> {
-    return getStructDecoder([['discriminator', getU8Decoder()]]);
+export function getInitializeBondInstructionDataDecoder(): Decoder<InitializeBondInstructionData> {
+    return getStructDecoder([
+        ['discriminator', getU8Decoder()],
+        ['tokenize', getBooleanDecoder()],
+        ['lockTier', getOptionDecoder(getU8Decoder())],
+        ['fixedTerm', getBooleanDecoder()],
+        ['allowlistProof', getOptionDecoder(getAllowlistProofDecoder())],
+    ]);
 }
 
-export function getInitializeBondInstructionDataCodec(): FixedSizeCodec<
+export function getInitializeBondInstructionDataCodec(): Codec<
     InitializeBondInstructionDataArgs,
     InitializeBondInstructionData
 > {
@@ -134,14 +211,24 @@ export type InitializeBondAsyncInput<
     TAccountBond extends string = string,
     TAccountUserWallet extends string = string,
     TAccountUserPda extends string = string,
+    TAccountUserBondPage extends string = string,
     TAccountGlobalAdmin extends string = string,
     TAccountUserWalletAta extends string = string,
     TAccountRewardsPoolAta extends string = string,
-    TAccountTreasuryAta extends string = string,
-    TAccountTeamAta extends string = string,
+    TAccountPrincipalVaultAta extends string = string,
+    TAccountTeamVestingAta extends string = string,
     TAccountNativeTokenMint extends string = string,
     TAccountSystemProgram extends string = string,
     TAccountTokenProgram extends string = string,
+    TAccountGlobalState extends string = string,
+    TAccountSplitConfig extends string = string,
+    TAccountBondMint extends string = string,
+    TAccountBondMintAuthority extends string = string,
+    TAccountUserBondTokenAccount extends string = string,
+    TAccountAssociatedTokenProgram extends string = string,
+    TAccountReferrerPda extends string = string,
+    TAccountReferrerAta extends string = string,
+    TAccountReferrerStats extends string = string,
 > = {
     /** The program derived address of the bond account to create (seeds: ['bond', user_pda, bond_index]) */
     bond?: Address<TAccountBond>;
@@ -149,50 +236,94 @@ export type InitializeBondAsyncInput<
     userWallet: TransactionSigner<TAccountUserWallet>;
     /** The user's pda */
     userPda?: Address<TAccountUserPda>;
+    /** The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index]) */
+    userBondPage: Address<TAccountUserBondPage>;
     /** The global admin account */
     globalAdmin?: Address<TAccountGlobalAdmin>;
     /** The user's wallet token account */
     userWalletAta: Address<TAccountUserWalletAta>;
     /** The rewards pool (token account) of the global admin */
     rewardsPoolAta: Address<TAccountRewardsPoolAta>;
-    /** The token account of the treasury */
-    treasuryAta: Address<TAccountTreasuryAta>;
-    /** The token account of the team */
-    teamAta: Address<TAccountTeamAta>;
-    /** The native token mint */
+    /** The token account of the principal vault, holding the treasury share until distributed */
+    principalVaultAta: Address<TAccountPrincipalVaultAta>;
+    /** The token account of the team vesting escrow, receiving the team share */
+    teamVestingAta: Address<TAccountTeamVestingAta>;
+    /** The native token mint, written when a deposit share is burned */
     nativeTokenMint: Address<TAccountNativeTokenMint>;
     /** The system program */
     systemProgram?: Address<TAccountSystemProgram>;
     /** The token program */
     tokenProgram?: Address<TAccountTokenProgram>;
+    /** The global state account (seeds: ['global_state']) */
+    globalState: Address<TAccountGlobalState>;
+    /** The deposit split config (seeds: ['split_config']) */
+    splitConfig: Address<TAccountSplitConfig>;
+    /** The bond token mint to create when tokenizing (seeds: ['bond_mint', bond]) */
+    bondMint?: Address<TAccountBondMint>;
+    /** The mint authority of bond tokens (seeds: ['bond_mint_authority']) */
+    bondMintAuthority?: Address<TAccountBondMintAuthority>;
+    /** The user's associated token account for the bond token */
+    userBondTokenAccount?: Address<TAccountUserBondTokenAccount>;
+    /** The associated token program */
+    associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+    /** The user PDA of the referrer to set if the user has none yet, or the program ID */
+    referrerPda?: Address<TAccountReferrerPda>;
+    /** The referrer's wallet token account, required once the user has a referrer */
+    referrerAta?: Address<TAccountReferrerAta>;
+    /** The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral */
+    referrerStats?: Address<TAccountReferrerStats>;
+    tokenize: InitializeBondInstructionDataArgs['tokenize'];
+    lockTier: InitializeBondInstructionDataArgs['lockTier'];
+    fixedTerm: InitializeBondInstructionDataArgs['fixedTerm'];
+    allowlistProof: InitializeBondInstructionDataArgs['allowlistProof'];
 };
 
 export async function getInitializeBondInstructionAsync<
     TAccountBond extends string,
     TAccountUserWallet extends string,
     TAccountUserPda extends string,
+    TAccountUserBondPage extends string,
     TAccountGlobalAdmin extends string,
     TAccountUserWalletAta extends string,
     TAccountRewardsPoolAta extends string,
-    TAccountTreasuryAta extends string,
-    TAccountTeamAta extends string,
+    TAccountPrincipalVaultAta extends string,
+    TAccountTeamVestingAta extends string,
     TAccountNativeTokenMint extends string,
     TAccountSystemProgram extends string,
     TAccountTokenProgram extends string,
+    TAccountGlobalState extends string,
+    TAccountSplitConfig extends string,
+    TAccountBondMint extends string,
+    TAccountBondMintAuthority extends string,
+    TAccountUserBondTokenAccount extends string,
+    TAccountAssociatedTokenProgram extends string,
+    TAccountReferrerPda extends string,
+    TAccountReferrerAta extends string,
+    TAccountReferrerStats extends string,
     TProgramAddress extends Address = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
 >(
     input: InitializeBondAsyncInput<
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountGlobalAdmin,
         TAccountUserWalletAta,
         TAccountRewardsPoolAta,
-        TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNativeTokenMint,
         TAccountSystemProgram,
-        TAccountTokenProgram
+        TAccountTokenProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountBondMint,
+        TAccountBondMintAuthority,
+        TAccountUserBondTokenAccount,
+        TAccountAssociatedTokenProgram,
+        TAccountReferrerPda,
+        TAccountReferrerAta,
+        TAccountReferrerStats
     >,
     config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -201,14 +332,24 @@ export async function getInitializeBondInstructionAsync<
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountGlobalAdmin,
         TAccountUserWalletAta,
         TAccountRewardsPoolAta,
-        TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNativeTokenMint,
         TAccountSystemProgram,
-        TAccountTokenProgram
+        TAccountTokenProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountBondMint,
+        TAccountBondMintAuthority,
+        TAccountUserBondTokenAccount,
+        TAccountAssociatedTokenProgram,
+        TAccountReferrerPda,
+        TAccountReferrerAta,
+        TAccountReferrerStats
     > &
         InstructionWithByteDelta
 > {
@@ -221,29 +362,57 @@ export async function getInitializeBondInstructionAsync<
         bond: { value: input.bond ?? null, isWritable: true },
         userWallet: { value: input.userWallet ?? null, isWritable: false },
         userPda: { value: input.userPda ?? null, isWritable: true },
+        userBondPage: { value: input.userBondPage ?? null, isWritable: true },
         globalAdmin: { value: input.globalAdmin ?? null, isWritable: false },
         userWalletAta: { value: input.userWalletAta ?? null, isWritable: true },
         rewardsPoolAta: {
             value: input.rewardsPoolAta ?? null,
             isWritable: true,
         },
-        treasuryAta: { value: input.treasuryAta ?? null, isWritable: true },
-        teamAta: { value: input.teamAta ?? null, isWritable: true },
+        principalVaultAta: {
+            value: input.principalVaultAta ?? null,
+            isWritable: true,
+        },
+        teamVestingAta: {
+            value: input.teamVestingAta ?? null,
+            isWritable: true,
+        },
         nativeTokenMint: {
             value: input.nativeTokenMint ?? null,
-            isWritable: false,
+            isWritable: true,
         },
         systemProgram: {
             value: input.systemProgram ?? null,
             isWritable: false,
         },
         tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+        globalState: { value: input.globalState ?? null, isWritable: true },
+        splitConfig: { value: input.splitConfig ?? null, isWritable: false },
+        bondMint: { value: input.bondMint ?? null, isWritable: true },
+        bondMintAuthority: {
+            value: input.bondMintAuthority ?? null,
+            isWritable: false,
+        },
+        userBondTokenAccount: {
+            value: input.userBondTokenAccount ?? null,
+            isWritable: true,
+        },
+        associatedTokenProgram: {
+            value: input.associatedTokenProgram ?? null,
+            isWritable: false,
+        },
+        referrerPda: { value: input.referrerPda ?? null, isWritable: false },
+        referrerAta: { value: input.referrerAta ?? null, isWritable: true },
+        referrerStats: { value: input.referrerStats ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<
         keyof typeof originalAccounts,
         ResolvedAccount
     >;
 
+    // Original args.
+    const args = { ...input };
+
     // Resolve default values.
     if (!accounts.userPda.value) {
         accounts.userPda.value = await findUserPdaPda({
@@ -279,30 +448,52 @@ export async function getInitializeBondInstructionAsync<
             getAccountMeta(accounts.bond),
             getAccountMeta(accounts.userWallet),
             getAccountMeta(accounts.userPda),
+            getAccountMeta(accounts.userBondPage),
             getAccountMeta(accounts.globalAdmin),
             getAccountMeta(accounts.userWalletAta),
             getAccountMeta(accounts.rewardsPoolAta),
-            getAccountMeta(accounts.treasuryAta),
-            getAccountMeta(accounts.teamAta),
+            getAccountMeta(accounts.principalVaultAta),
+            getAccountMeta(accounts.teamVestingAta),
             getAccountMeta(accounts.nativeTokenMint),
             getAccountMeta(accounts.systemProgram),
             getAccountMeta(accounts.tokenProgram),
+            getAccountMeta(accounts.globalState),
+            getAccountMeta(accounts.splitConfig),
+            getAccountMeta(accounts.bondMint),
+            getAccountMeta(accounts.bondMintAuthority),
+            getAccountMeta(accounts.userBondTokenAccount),
+            getAccountMeta(accounts.associatedTokenProgram),
+            getAccountMeta(accounts.referrerPda),
+            getAccountMeta(accounts.referrerAta),
+            getAccountMeta(accounts.referrerStats),
         ],
         programAddress,
-        data: getInitializeBondInstructionDataEncoder().encode({}),
+        data: getInitializeBondInstructionDataEncoder().encode(
+            args as InitializeBondInstructionDataArgs
+        ),
     } as InitializeBondInstruction<
         TProgramAddress,
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountGlobalAdmin,
         TAccountUserWalletAta,
         TAccountRewardsPoolAta,
-        TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNativeTokenMint,
         TAccountSystemProgram,
-        TAccountTokenProgram
+        TAccountTokenProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountBondMint,
+        TAccountBondMintAuthority,
+        TAccountUserBondTokenAccount,
+        TAccountAssociatedTokenProgram,
+        TAccountReferrerPda,
+        TAccountReferrerAta,
+        TAccountReferrerStats
     >;
 
     return Object.freeze({ ...instruction, byteDelta });
@@ -312,14 +503,24 @@ export type InitializeBondInput<
     TAccountBond extends string = string,
     TAccountUserWallet extends string = string,
     TAccountUserPda extends string = string,
+    TAccountUserBondPage extends string = string,
     TAccountGlobalAdmin extends string = string,
     TAccountUserWalletAta extends string = string,
     TAccountRewardsPoolAta extends string = string,
-    TAccountTreasuryAta extends string = string,
-    TAccountTeamAta extends string = string,
+    TAccountPrincipalVaultAta extends string = string,
+    TAccountTeamVestingAta extends string = string,
     TAccountNativeTokenMint extends string = string,
     TAccountSystemProgram extends string = string,
     TAccountTokenProgram extends string = string,
+    TAccountGlobalState extends string = string,
+    TAccountSplitConfig extends string = string,
+    TAccountBondMint extends string = string,
+    TAccountBondMintAuthority extends string = string,
+    TAccountUserBondTokenAccount extends string = string,
+    TAccountAssociatedTokenProgram extends string = string,
+    TAccountReferrerPda extends string = string,
+    TAccountReferrerAta extends string = string,
+    TAccountReferrerStats extends string = string,
 > = {
     /** The program derived address of the bond account to create (seeds: ['bond', user_pda, bond_index]) */
     bond: Address<TAccountBond>;
@@ -327,50 +528,94 @@ export type InitializeBondInput<
     userWallet: TransactionSigner<TAccountUserWallet>;
     /** The user's pda */
     userPda: Address<TAccountUserPda>;
+    /** The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index]) */
+    userBondPage: Address<TAccountUserBondPage>;
     /** The global admin account */
     globalAdmin: Address<TAccountGlobalAdmin>;
     /** The user's wallet token account */
     userWalletAta: Address<TAccountUserWalletAta>;
     /** The rewards pool (token account) of the global admin */
     rewardsPoolAta: Address<TAccountRewardsPoolAta>;
-    /** The token account of the treasury */
-    treasuryAta: Address<TAccountTreasuryAta>;
-    /** The token account of the team */
-    teamAta: Address<TAccountTeamAta>;
-    /** The native token mint */
+    /** The token account of the principal vault, holding the treasury share until distributed */
+    principalVaultAta: Address<TAccountPrincipalVaultAta>;
+    /** The token account of the team vesting escrow, receiving the team share */
+    teamVestingAta: Address<TAccountTeamVestingAta>;
+    /** The native token mint, written when a deposit share is burned */
     nativeTokenMint: Address<TAccountNativeTokenMint>;
     /** The system program */
     systemProgram?: Address<TAccountSystemProgram>;
     /** The token program */
     tokenProgram?: Address<TAccountTokenProgram>;
+    /** The global state account (seeds: ['global_state']) */
+    globalState: Address<TAccountGlobalState>;
+    /** The deposit split config (seeds: ['split_config']) */
+    splitConfig: Address<TAccountSplitConfig>;
+    /** The bond token mint to create when tokenizing (seeds: ['bond_mint', bond]) */
+    bondMint?: Address<TAccountBondMint>;
+    /** The mint authority of bond tokens (seeds: ['bond_mint_authority']) */
+    bondMintAuthority?: Address<TAccountBondMintAuthority>;
+    /** The user's associated token account for the bond token */
+    userBondTokenAccount?: Address<TAccountUserBondTokenAccount>;
+    /** The associated token program */
+    associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+    /** The user PDA of the referrer to set if the user has none yet, or the program ID */
+    referrerPda?: Address<TAccountReferrerPda>;
+    /** The referrer's wallet token account, required once the user has a referrer */
+    referrerAta?: Address<TAccountReferrerAta>;
+    /** The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral */
+    referrerStats?: Address<TAccountReferrerStats>;
+    tokenize: InitializeBondInstructionDataArgs['tokenize'];
+    lockTier: InitializeBondInstructionDataArgs['lockTier'];
+    fixedTerm: InitializeBondInstructionDataArgs['fixedTerm'];
+    allowlistProof: InitializeBondInstructionDataArgs['allowlistProof'];
 };
 
 export function getInitializeBondInstruction<
     TAccountBond extends string,
     TAccountUserWallet extends string,
     TAccountUserPda extends string,
+    TAccountUserBondPage extends string,
     TAccountGlobalAdmin extends string,
     TAccountUserWalletAta extends string,
     TAccountRewardsPoolAta extends string,
-    TAccountTreasuryAta extends string,
-    TAccountTeamAta extends string,
+    TAccountPrincipalVaultAta extends string,
+    TAccountTeamVestingAta extends string,
     TAccountNativeTokenMint extends string,
     TAccountSystemProgram extends string,
     TAccountTokenProgram extends string,
+    TAccountGlobalState extends string,
+    TAccountSplitConfig extends string,
+    TAccountBondMint extends string,
+    TAccountBondMintAuthority extends string,
+    TAccountUserBondTokenAccount extends string,
+    TAccountAssociatedTokenProgram extends string,
+    TAccountReferrerPda extends string,
+    TAccountReferrerAta extends string,
+    TAccountReferrerStats extends string,
     TProgramAddress extends Address = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
 >(
     input: InitializeBondInput<
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountGlobalAdmin,
         TAccountUserWalletAta,
         TAccountRewardsPoolAta,
-        TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNativeTokenMint,
         TAccountSystemProgram,
-        TAccountTokenProgram
+        TAccountTokenProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountBondMint,
+        TAccountBondMintAuthority,
+        TAccountUserBondTokenAccount,
+        TAccountAssociatedTokenProgram,
+        TAccountReferrerPda,
+        TAccountReferrerAta,
+        TAccountReferrerStats
     >,
     config?: { programAddress?: TProgramAddress }
 ): InitializeBondInstruction<
@@ -378,14 +623,24 @@ export function getInitializeBondInstruction<
     TAccountBond,
     TAccountUserWallet,
     TAccountUserPda,
+    TAccountUserBondPage,
     TAccountGlobalAdmin,
     TAccountUserWalletAta,
     TAccountRewardsPoolAta,
-    TAccountTreasuryAta,
-    TAccountTeamAta,
+    TAccountPrincipalVaultAta,
+    TAccountTeamVestingAta,
     TAccountNativeTokenMint,
     TAccountSystemProgram,
-    TAccountTokenProgram
+    TAccountTokenProgram,
+    TAccountGlobalState,
+    TAccountSplitConfig,
+    TAccountBondMint,
+    TAccountBondMintAuthority,
+    TAccountUserBondTokenAccount,
+    TAccountAssociatedTokenProgram,
+    TAccountReferrerPda,
+    TAccountReferrerAta,
+    TAccountReferrerStats
 > &
     InstructionWithByteDelta {
     // Program address.
@@ -397,29 +652,57 @@ export function getInitializeBondInstruction<
         bond: { value: input.bond ?? null, isWritable: true },
         userWallet: { value: input.userWallet ?? null, isWritable: false },
         userPda: { value: input.userPda ?? null, isWritable: true },
+        userBondPage: { value: input.userBondPage ?? null, isWritable: true },
         globalAdmin: { value: input.globalAdmin ?? null, isWritable: false },
         userWalletAta: { value: input.userWalletAta ?? null, isWritable: true },
         rewardsPoolAta: {
             value: input.rewardsPoolAta ?? null,
             isWritable: true,
         },
-        treasuryAta: { value: input.treasuryAta ?? null, isWritable: true },
-        teamAta: { value: input.teamAta ?? null, isWritable: true },
+        principalVaultAta: {
+            value: input.principalVaultAta ?? null,
+            isWritable: true,
+        },
+        teamVestingAta: {
+            value: input.teamVestingAta ?? null,
+            isWritable: true,
+        },
         nativeTokenMint: {
             value: input.nativeTokenMint ?? null,
-            isWritable: false,
+            isWritable: true,
         },
         systemProgram: {
             value: input.systemProgram ?? null,
             isWritable: false,
         },
         tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+        globalState: { value: input.globalState ?? null, isWritable: true },
+        splitConfig: { value: input.splitConfig ?? null, isWritable: false },
+        bondMint: { value: input.bondMint ?? null, isWritable: true },
+        bondMintAuthority: {
+            value: input.bondMintAuthority ?? null,
+            isWritable: false,
+        },
+        userBondTokenAccount: {
+            value: input.userBondTokenAccount ?? null,
+            isWritable: true,
+        },
+        associatedTokenProgram: {
+            value: input.associatedTokenProgram ?? null,
+            isWritable: false,
+        },
+        referrerPda: { value: input.referrerPda ?? null, isWritable: false },
+        referrerAta: { value: input.referrerAta ?? null, isWritable: true },
+        referrerStats: { value: input.referrerStats ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<
         keyof typeof originalAccounts,
         ResolvedAccount
     >;
 
+    // Original args.
+    const args = { ...input };
+
     // Resolve default values.
     if (!accounts.systemProgram.value) {
         accounts.systemProgram.value =
@@ -442,30 +725,52 @@ export function getInitializeBondInstruction<
             getAccountMeta(accounts.bond),
             getAccountMeta(accounts.userWallet),
             getAccountMeta(accounts.userPda),
+            getAccountMeta(accounts.userBondPage),
             getAccountMeta(accounts.globalAdmin),
             getAccountMeta(accounts.userWalletAta),
             getAccountMeta(accounts.rewardsPoolAta),
-            getAccountMeta(accounts.treasuryAta),
-            getAccountMeta(accounts.teamAta),
+            getAccountMeta(accounts.principalVaultAta),
+            getAccountMeta(accounts.teamVestingAta),
             getAccountMeta(accounts.nativeTokenMint),
             getAccountMeta(accounts.systemProgram),
             getAccountMeta(accounts.tokenProgram),
+            getAccountMeta(accounts.globalState),
+            getAccountMeta(accounts.splitConfig),
+            getAccountMeta(accounts.bondMint),
+            getAccountMeta(accounts.bondMintAuthority),
+            getAccountMeta(accounts.userBondTokenAccount),
+            getAccountMeta(accounts.associatedTokenProgram),
+            getAccountMeta(accounts.referrerPda),
+            getAccountMeta(accounts.referrerAta),
+            getAccountMeta(accounts.referrerStats),
         ],
         programAddress,
-        data: getInitializeBondInstructionDataEncoder().encode({}),
+        data: getInitializeBondInstructionDataEncoder().encode(
+            args as InitializeBondInstructionDataArgs
+        ),
     } as InitializeBondInstruction<
         TProgramAddress,
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountGlobalAdmin,
         TAccountUserWalletAta,
         TAccountRewardsPoolAta,
-        TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNativeTokenMint,
         TAccountSystemProgram,
-        TAccountTokenProgram
+        TAccountTokenProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountBondMint,
+        TAccountBondMintAuthority,
+        TAccountUserBondTokenAccount,
+        TAccountAssociatedTokenProgram,
+        TAccountReferrerPda,
+        TAccountReferrerAta,
+        TAccountReferrerStats
     >;
 
     return Object.freeze({ ...instruction, byteDelta });
@@ -483,22 +788,42 @@ export type ParsedInitializeBondInstruction<
         userWallet: TAccountMetas[1];
         /** The user's pda */
         userPda: TAccountMetas[2];
+        /** The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index]) */
+        userBondPage: TAccountMetas[3];
         /** The global admin account */
-        globalAdmin: TAccountMetas[3];
+        globalAdmin: TAccountMetas[4];
         /** The user's wallet token account */
-        userWalletAta: TAccountMetas[4];
+        userWalletAta: TAccountMetas[5];
         /** The rewards pool (token account) of the global admin */
-        rewardsPoolAta: TAccountMetas[5];
-        /** The token account of the treasury */
-        treasuryAta: TAccountMetas[6];
-        /** The token account of the team */
-        teamAta: TAccountMetas[7];
-        /** The native token mint */
-        nativeTokenMint: TAccountMetas[8];
+        rewardsPoolAta: TAccountMetas[6];
+        /** The token account of the principal vault, holding the treasury share until distributed */
+        principalVaultAta: TAccountMetas[7];
+        /** The token account of the team vesting escrow, receiving the team share */
+        teamVestingAta: TAccountMetas[8];
+        /** The native token mint, written when a deposit share is burned */
+        nativeTokenMint: TAccountMetas[9];
         /** The system program */
-        systemProgram: TAccountMetas[9];
+        systemProgram: TAccountMetas[10];
         /** The token program */
-        tokenProgram: TAccountMetas[10];
+        tokenProgram: TAccountMetas[11];
+        /** The global state account (seeds: ['global_state']) */
+        globalState: TAccountMetas[12];
+        /** The deposit split config (seeds: ['split_config']) */
+        splitConfig: TAccountMetas[13];
+        /** The bond token mint to create when tokenizing (seeds: ['bond_mint', bond]) */
+        bondMint?: TAccountMetas[14] | undefined;
+        /** The mint authority of bond tokens (seeds: ['bond_mint_authority']) */
+        bondMintAuthority?: TAccountMetas[15] | undefined;
+        /** The user's associated token account for the bond token */
+        userBondTokenAccount?: TAccountMetas[16] | undefined;
+        /** The associated token program */
+        associatedTokenProgram?: TAccountMetas[17] | undefined;
+        /** The user PDA of the referrer to set if the user has none yet, or the program ID */
+        referrerPda?: TAccountMetas[18] | undefined;
+        /** The referrer's wallet token account, required once the user has a referrer */
+        referrerAta?: TAccountMetas[19] | undefined;
+        /** The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral */
+        referrerStats?: TAccountMetas[20] | undefined;
     };
     data: InitializeBondInstructionData;
 };
@@ -511,7 +836,7 @@ export function parseInitializeBondInstruction<
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeBondInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 11) {
+    if (instruction.accounts.length < 21) {
         // TODO: Coded error.
         throw new Error('Not enough accounts');
     }
@@ -521,20 +846,36 @@ export function parseInitializeBondInstruction<
         accountIndex += 1;
         return accountMeta;
     };
+    const getNextOptionalAccount = () => {
+        const accountMeta = getNextAccount();
+        return accountMeta.address === TESTUDO_BONDS_PROGRAM_ADDRESS
+            ? undefined
+            : accountMeta;
+    };
     return {
         programAddress: instruction.programAddress,
         accounts: {
             bond: getNextAccount(),
             userWallet: getNextAccount(),
             userPda: getNextAccount(),
+            userBondPage: getNextAccount(),
             globalAdmin: getNextAccount(),
             userWalletAta: getNextAccount(),
             rewardsPoolAta: getNextAccount(),
-            treasuryAta: getNextAccount(),
-            teamAta: getNextAccount(),
+            principalVaultAta: getNextAccount(),
+            teamVestingAta: getNextAccount(),
             nativeTokenMint: getNextAccount(),
             systemProgram: getNextAccount(),
             tokenProgram: getNextAccount(),
+            globalState: getNextAccount(),
+            splitConfig: getNextAccount(),
+            bondMint: getNextOptionalAccount(),
+            bondMintAuthority: getNextOptionalAccount(),
+            userBondTokenAccount: getNextOptionalAccount(),
+            associatedTokenProgram: getNextOptionalAccount(),
+            referrerPda: getNextOptionalAccount(),
+            referrerAta: getNextOptionalAccount(),
+            referrerStats: getNextOptionalAccount(),
         },
         data: getInitializeBondInstructionDataDecoder().decode(
             instruction.data
@@ -12,6 +12,8 @@ import {
     getBooleanEncoder,
     getStructDecoder,
     getStructEncoder,
+    getU16Decoder,
+    getU16Encoder,
     getU8Decoder,
     getU8Encoder,
     transformEncoder,
@@ -25,10 +27,10 @@ import {
     type InstructionWithAccounts,
     type InstructionWithData,
     type ReadonlyAccount,
-    type ReadonlySignerAccount,
     type ReadonlyUint8Array,
     type TransactionSigner,
     type WritableAccount,
+    type WritableSignerAccount,
 } from '@solana/kit';
 import { findBondPda, findGlobalAdminPda, findUserPdaPda } from '../pdas';
 import { TESTUDO_BONDS_PROGRAM_ADDRESS } from '../programs';
@@ -50,12 +52,15 @@ export type ProcessClaimInstruction<
     TAccountBond extends string | AccountMeta<string> = string,
     TAccountUserWallet extends string | AccountMeta<string> = string,
     TAccountUserPda extends string | AccountMeta<string> = string,
+    TAccountUserBondPage extends string | AccountMeta<string> = string,
     TAccountUserWalletAta extends string | AccountMeta<string> = string,
     TAccountGlobalAdmin extends string | AccountMeta<string> = string,
     TAccountRewardsPoolAta extends string | AccountMeta<string> = string,
     TAccountTreasuryAta extends string | AccountMeta<string> = string,
-    TAccountTeamAta extends string | AccountMeta<string> = string,
+    TAccountPrincipalVaultAta extends string | AccountMeta<string> = string,
+    TAccountTeamVestingAta extends string | AccountMeta<string> = string,
     TAccountNewBondPda extends string | AccountMeta<string> = string,
+    TAccountNewBondPage extends string | AccountMeta<string> = string,
     TAccountNativeTokenMint extends string | AccountMeta<string> = string,
     TAccountTokenProgram extends
         | string
@@ -66,6 +71,15 @@ export type ProcessClaimInstruction<
     TAccountSystemProgram extends
         | string
         | AccountMeta<string> = '11111111111111111111111111111111',
+    TAccountGlobalState extends string | AccountMeta<string> = string,
+    TAccountSplitConfig extends string | AccountMeta<string> = string,
+    TAccountRewardStreams extends string | AccountMeta<string> = string,
+    TAccountBondTokenAccount extends string | AccountMeta<string> = string,
+    TAccountReferrerAta extends string | AccountMeta<string> = string,
+    TAccountReferrerStats extends string | AccountMeta<string> = string,
+    TAccountHolderUserPda extends string | AccountMeta<string> = string,
+    TAccountHolderBondPage extends string | AccountMeta<string> = string,
+    TAccountOwnerWallet extends string | AccountMeta<string> = string,
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -75,12 +89,15 @@ export type ProcessClaimInstruction<
                 ? WritableAccount<TAccountBond>
                 : TAccountBond,
             TAccountUserWallet extends string
-                ? ReadonlySignerAccount<TAccountUserWallet> &
+                ? WritableSignerAccount<TAccountUserWallet> &
                       AccountSignerMeta<TAccountUserWallet>
                 : TAccountUserWallet,
             TAccountUserPda extends string
                 ? WritableAccount<TAccountUserPda>
                 : TAccountUserPda,
+            TAccountUserBondPage extends string
+                ? WritableAccount<TAccountUserBondPage>
+                : TAccountUserBondPage,
             TAccountUserWalletAta extends string
                 ? WritableAccount<TAccountUserWalletAta>
                 : TAccountUserWalletAta,
@@ -93,14 +110,20 @@ export type ProcessClaimInstruction<
             TAccountTreasuryAta extends string
                 ? WritableAccount<TAccountTreasuryAta>
                 : TAccountTreasuryAta,
-            TAccountTeamAta extends string
-                ? WritableAccount<TAccountTeamAta>
-                : TAccountTeamAta,
+            TAccountPrincipalVaultAta extends string
+                ? WritableAccount<TAccountPrincipalVaultAta>
+                : TAccountPrincipalVaultAta,
+            TAccountTeamVestingAta extends string
+                ? WritableAccount<TAccountTeamVestingAta>
+                : TAccountTeamVestingAta,
             TAccountNewBondPda extends string
                 ? WritableAccount<TAccountNewBondPda>
                 : TAccountNewBondPda,
+            TAccountNewBondPage extends string
+                ? WritableAccount<TAccountNewBondPage>
+                : TAccountNewBondPage,
             TAccountNativeTokenMint extends string
-                ? ReadonlyAccount<TAccountNativeTokenMint>
+                ? WritableAccount<TAccountNativeTokenMint>
                 : TAccountNativeTokenMint,
             TAccountTokenProgram extends string
                 ? ReadonlyAccount<TAccountTokenProgram>
@@ -111,6 +134,33 @@ export type ProcessClaimInstruction<
             TAccountSystemProgram extends string
                 ? ReadonlyAccount<TAccountSystemProgram>
                 : TAccountSystemProgram,
+            TAccountGlobalState extends string
+                ? WritableAccount<TAccountGlobalState>
+                : TAccountGlobalState,
+            TAccountSplitConfig extends string
+                ? ReadonlyAccount<TAccountSplitConfig>
+                : TAccountSplitConfig,
+            TAccountRewardStreams extends string
+                ? ReadonlyAccount<TAccountRewardStreams>
+                : TAccountRewardStreams,
+            TAccountBondTokenAccount extends string
+                ? ReadonlyAccount<TAccountBondTokenAccount>
+                : TAccountBondTokenAccount,
+            TAccountReferrerAta extends string
+                ? WritableAccount<TAccountReferrerAta>
+                : TAccountReferrerAta,
+            TAccountReferrerStats extends string
+                ? WritableAccount<TAccountReferrerStats>
+                : TAccountReferrerStats,
+            TAccountHolderUserPda extends string
+                ? WritableAccount<TAccountHolderUserPda>
+                : TAccountHolderUserPda,
+            TAccountHolderBondPage extends string
+                ? WritableAccount<TAccountHolderBondPage>
+                : TAccountHolderBondPage,
+            TAccountOwnerWallet extends string
+                ? WritableAccount<TAccountOwnerWallet>
+                : TAccountOwnerWallet,
             ...TRemainingAccounts,
         ]
     >;
@@ -130,7 +180,7 @@ export function getProcessClaimInstructionDataEncoder(): FixedSizeEncoder<Proces
     return transformEncoder(
         getStructEncoder([
             ['discriminator', getU8Encoder()],
-            ['bondIndex', getU8Encoder()],
+            ['bondIndex', getU16Encoder()],
             ['autoCompound', getBooleanEncoder()],
         ]),
         (value) => ({ ...value, discriminator: PROCESS_CLAIM_DISCRIMINATOR })
@@ -140,7 +190,7 @@ export function getProcessClaimInstructionDataEncoder(): FixedSizeEncoder<Proces
 export function getProcessClaimInstructionDataDecoder(): FixedSizeDecoder<ProcessClaimInstructionData> {
     return getStructDecoder([
         ['discriminator', getU8Decoder()],
-        ['bondIndex', getU8Decoder()],
+        ['bondIndex', getU16Decoder()],
         ['autoCompound', getBooleanDecoder()],
     ]);
 }
@@ -159,23 +209,37 @@ export type ProcessClaimAsyncInput<
     TAccountBond extends string = string,
     TAccountUserWallet extends string = string,
     TAccountUserPda extends string = string,
+    TAccountUserBondPage extends string = string,
     TAccountUserWalletAta extends string = string,
     TAccountGlobalAdmin extends string = string,
     TAccountRewardsPoolAta extends string = string,
     TAccountTreasuryAta extends string = string,
-    TAccountTeamAta extends string = string,
+    TAccountPrincipalVaultAta extends string = string,
+    TAccountTeamVestingAta extends string = string,
     TAccountNewBondPda extends string = string,
+    TAccountNewBondPage extends string = string,
     TAccountNativeTokenMint extends string = string,
     TAccountTokenProgram extends string = string,
     TAccountAssociatedTokenProgram extends string = string,
     TAccountSystemProgram extends string = string,
+    TAccountGlobalState extends string = string,
+    TAccountSplitConfig extends string = string,
+    TAccountRewardStreams extends string = string,
+    TAccountBondTokenAccount extends string = string,
+    TAccountReferrerAta extends string = string,
+    TAccountReferrerStats extends string = string,
+    TAccountHolderUserPda extends string = string,
+    TAccountHolderBondPage extends string = string,
+    TAccountOwnerWallet extends string = string,
 > = {
     /** The program derived address of the bond account to claim rewards from (seeds: ['bond', user_pda, bond_index]) */
     bond?: Address<TAccountBond>;
-    /** The wallet of the user */
+    /** The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it */
     userWallet: TransactionSigner<TAccountUserWallet>;
     /** The user's pda */
     userPda?: Address<TAccountUserPda>;
+    /** The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index]) */
+    userBondPage: Address<TAccountUserBondPage>;
     /** The user's wallet token account */
     userWalletAta: Address<TAccountUserWalletAta>;
     /** The global admin account */
@@ -184,11 +248,15 @@ export type ProcessClaimAsyncInput<
     rewardsPoolAta: Address<TAccountRewardsPoolAta>;
     /** The token account of the treasury */
     treasuryAta: Address<TAccountTreasuryAta>;
-    /** The token account of the team */
-    teamAta: Address<TAccountTeamAta>;
+    /** The token account of the principal vault, receiving the treasury share of an auto-compounded bond */
+    principalVaultAta: Address<TAccountPrincipalVaultAta>;
+    /** The token account of the team vesting escrow, receiving the team share of an auto-compounded bond */
+    teamVestingAta: Address<TAccountTeamVestingAta>;
     /** The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index]) */
     newBondPda?: Address<TAccountNewBondPda>;
-    /** The native token mint */
+    /** The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index]) */
+    newBondPage: Address<TAccountNewBondPage>;
+    /** The native token mint, written when penalties are burned or rewards minted */
     nativeTokenMint: Address<TAccountNativeTokenMint>;
     /** The token program */
     tokenProgram?: Address<TAccountTokenProgram>;
@@ -196,6 +264,24 @@ export type ProcessClaimAsyncInput<
     associatedTokenProgram: Address<TAccountAssociatedTokenProgram>;
     /** The system program */
     systemProgram?: Address<TAccountSystemProgram>;
+    /** The global state account (seeds: ['global_state']) */
+    globalState: Address<TAccountGlobalState>;
+    /** The deposit split config (seeds: ['split_config']) */
+    splitConfig: Address<TAccountSplitConfig>;
+    /** The reward stream list (seeds: ['reward_streams']) */
+    rewardStreams: Address<TAccountRewardStreams>;
+    /** The signer's token account holding the bond token, required for tokenized bonds */
+    bondTokenAccount?: Address<TAccountBondTokenAccount>;
+    /** The wallet token account of the bond owner's referrer, required when the owner has one */
+    referrerAta?: Address<TAccountReferrerAta>;
+    /** The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet]) */
+    referrerStats?: Address<TAccountReferrerStats>;
+    /** The signer's user pda, required when the signer holds a tokenized bond it does not own */
+    holderUserPda?: Address<TAccountHolderUserPda>;
+    /** The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index]) */
+    holderBondPage?: Address<TAccountHolderBondPage>;
+    /** The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied */
+    ownerWallet?: Address<TAccountOwnerWallet>;
     bondIndex: ProcessClaimInstructionDataArgs['bondIndex'];
     autoCompound: ProcessClaimInstructionDataArgs['autoCompound'];
 };
@@ -204,32 +290,56 @@ export async function getProcessClaimInstructionAsync<
     TAccountBond extends string,
     TAccountUserWallet extends string,
     TAccountUserPda extends string,
+    TAccountUserBondPage extends string,
     TAccountUserWalletAta extends string,
     TAccountGlobalAdmin extends string,
     TAccountRewardsPoolAta extends string,
     TAccountTreasuryAta extends string,
-    TAccountTeamAta extends string,
+    TAccountPrincipalVaultAta extends string,
+    TAccountTeamVestingAta extends string,
     TAccountNewBondPda extends string,
+    TAccountNewBondPage extends string,
     TAccountNativeTokenMint extends string,
     TAccountTokenProgram extends string,
     TAccountAssociatedTokenProgram extends string,
     TAccountSystemProgram extends string,
+    TAccountGlobalState extends string,
+    TAccountSplitConfig extends string,
+    TAccountRewardStreams extends string,
+    TAccountBondTokenAccount extends string,
+    TAccountReferrerAta extends string,
+    TAccountReferrerStats extends string,
+    TAccountHolderUserPda extends string,
+    TAccountHolderBondPage extends string,
+    TAccountOwnerWallet extends string,
     TProgramAddress extends Address = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
 >(
     input: ProcessClaimAsyncInput<
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountUserWalletAta,
         TAccountGlobalAdmin,
         TAccountRewardsPoolAta,
         TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNewBondPda,
+        TAccountNewBondPage,
         TAccountNativeTokenMint,
         TAccountTokenProgram,
         TAccountAssociatedTokenProgram,
-        TAccountSystemProgram
+        TAccountSystemProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountRewardStreams,
+        TAccountBondTokenAccount,
+        TAccountReferrerAta,
+        TAccountReferrerStats,
+        TAccountHolderUserPda,
+        TAccountHolderBondPage,
+        TAccountOwnerWallet
     >,
     config?: { programAddress?: TProgramAddress }
 ): Promise<
@@ -238,16 +348,28 @@ export async function getProcessClaimInstructionAsync<
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountUserWalletAta,
         TAccountGlobalAdmin,
         TAccountRewardsPoolAta,
         TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNewBondPda,
+        TAccountNewBondPage,
         TAccountNativeTokenMint,
         TAccountTokenProgram,
         TAccountAssociatedTokenProgram,
-        TAccountSystemProgram
+        TAccountSystemProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountRewardStreams,
+        TAccountBondTokenAccount,
+        TAccountReferrerAta,
+        TAccountReferrerStats,
+        TAccountHolderUserPda,
+        TAccountHolderBondPage,
+        TAccountOwnerWallet
     >
 > {
     // Program address.
@@ -257,8 +379,9 @@ export async function getProcessClaimInstructionAsync<
     // Original accounts.
     const originalAccounts = {
         bond: { value: input.bond ?? null, isWritable: true },
-        userWallet: { value: input.userWallet ?? null, isWritable: false },
+        userWallet: { value: input.userWallet ?? null, isWritable: true },
         userPda: { value: input.userPda ?? null, isWritable: true },
+        userBondPage: { value: input.userBondPage ?? null, isWritable: true },
         userWalletAta: { value: input.userWalletAta ?? null, isWritable: true },
         globalAdmin: { value: input.globalAdmin ?? null, isWritable: false },
         rewardsPoolAta: {
@@ -266,11 +389,19 @@ export async function getProcessClaimInstructionAsync<
             isWritable: true,
         },
         treasuryAta: { value: input.treasuryAta ?? null, isWritable: true },
-        teamAta: { value: input.teamAta ?? null, isWritable: true },
+        principalVaultAta: {
+            value: input.principalVaultAta ?? null,
+            isWritable: true,
+        },
+        teamVestingAta: {
+            value: input.teamVestingAta ?? null,
+            isWritable: true,
+        },
         newBondPda: { value: input.newBondPda ?? null, isWritable: true },
+        newBondPage: { value: input.newBondPage ?? null, isWritable: true },
         nativeTokenMint: {
             value: input.nativeTokenMint ?? null,
-            isWritable: false,
+            isWritable: true,
         },
         tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
         associatedTokenProgram: {
@@ -281,6 +412,24 @@ export async function getProcessClaimInstructionAsync<
             value: input.systemProgram ?? null,
             isWritable: false,
         },
+        globalState: { value: input.globalState ?? null, isWritable: true },
+        splitConfig: { value: input.splitConfig ?? null, isWritable: false },
+        rewardStreams: {
+            value: input.rewardStreams ?? null,
+            isWritable: false,
+        },
+        bondTokenAccount: {
+            value: input.bondTokenAccount ?? null,
+            isWritable: false,
+        },
+        referrerAta: { value: input.referrerAta ?? null, isWritable: true },
+        referrerStats: { value: input.referrerStats ?? null, isWritable: true },
+        holderUserPda: { value: input.holderUserPda ?? null, isWritable: true },
+        holderBondPage: {
+            value: input.holderBondPage ?? null,
+            isWritable: true,
+        },
+        ownerWallet: { value: input.ownerWallet ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<
         keyof typeof originalAccounts,
@@ -326,16 +475,28 @@ export async function getProcessClaimInstructionAsync<
             getAccountMeta(accounts.bond),
             getAccountMeta(accounts.userWallet),
             getAccountMeta(accounts.userPda),
+            getAccountMeta(accounts.userBondPage),
             getAccountMeta(accounts.userWalletAta),
             getAccountMeta(accounts.globalAdmin),
             getAccountMeta(accounts.rewardsPoolAta),
             getAccountMeta(accounts.treasuryAta),
-            getAccountMeta(accounts.teamAta),
+            getAccountMeta(accounts.principalVaultAta),
+            getAccountMeta(accounts.teamVestingAta),
             getAccountMeta(accounts.newBondPda),
+            getAccountMeta(accounts.newBondPage),
             getAccountMeta(accounts.nativeTokenMint),
             getAccountMeta(accounts.tokenProgram),
             getAccountMeta(accounts.associatedTokenProgram),
             getAccountMeta(accounts.systemProgram),
+            getAccountMeta(accounts.globalState),
+            getAccountMeta(accounts.splitConfig),
+            getAccountMeta(accounts.rewardStreams),
+            getAccountMeta(accounts.bondTokenAccount),
+            getAccountMeta(accounts.referrerAta),
+            getAccountMeta(accounts.referrerStats),
+            getAccountMeta(accounts.holderUserPda),
+            getAccountMeta(accounts.holderBondPage),
+            getAccountMeta(accounts.ownerWallet),
         ],
         programAddress,
         data: getProcessClaimInstructionDataEncoder().encode(
@@ -346,16 +507,28 @@ export async function getProcessClaimInstructionAsync<
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountUserWalletAta,
         TAccountGlobalAdmin,
         TAccountRewardsPoolAta,
         TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNewBondPda,
+        TAccountNewBondPage,
         TAccountNativeTokenMint,
         TAccountTokenProgram,
         TAccountAssociatedTokenProgram,
-        TAccountSystemProgram
+        TAccountSystemProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountRewardStreams,
+        TAccountBondTokenAccount,
+        TAccountReferrerAta,
+        TAccountReferrerStats,
+        TAccountHolderUserPda,
+        TAccountHolderBondPage,
+        TAccountOwnerWallet
     >;
 
     return instruction;
@@ -365,23 +538,37 @@ export type ProcessClaimInput<
     TAccountBond extends string = string,
     TAccountUserWallet extends string = string,
     TAccountUserPda extends string = string,
+    TAccountUserBondPage extends string = string,
     TAccountUserWalletAta extends string = string,
     TAccountGlobalAdmin extends string = string,
     TAccountRewardsPoolAta extends string = string,
     TAccountTreasuryAta extends string = string,
-    TAccountTeamAta extends string = string,
+    TAccountPrincipalVaultAta extends string = string,
+    TAccountTeamVestingAta extends string = string,
     TAccountNewBondPda extends string = string,
+    TAccountNewBondPage extends string = string,
     TAccountNativeTokenMint extends string = string,
     TAccountTokenProgram extends string = string,
     TAccountAssociatedTokenProgram extends string = string,
     TAccountSystemProgram extends string = string,
+    TAccountGlobalState extends string = string,
+    TAccountSplitConfig extends string = string,
+    TAccountRewardStreams extends string = string,
+    TAccountBondTokenAccount extends string = string,
+    TAccountReferrerAta extends string = string,
+    TAccountReferrerStats extends string = string,
+    TAccountHolderUserPda extends string = string,
+    TAccountHolderBondPage extends string = string,
+    TAccountOwnerWallet extends string = string,
 > = {
     /** The program derived address of the bond account to claim rewards from (seeds: ['bond', user_pda, bond_index]) */
     bond: Address<TAccountBond>;
-    /** The wallet of the user */
+    /** The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it */
     userWallet: TransactionSigner<TAccountUserWallet>;
     /** The user's pda */
     userPda: Address<TAccountUserPda>;
+    /** The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index]) */
+    userBondPage: Address<TAccountUserBondPage>;
     /** The user's wallet token account */
     userWalletAta: Address<TAccountUserWalletAta>;
     /** The global admin account */
@@ -390,11 +577,15 @@ export type ProcessClaimInput<
     rewardsPoolAta: Address<TAccountRewardsPoolAta>;
     /** The token account of the treasury */
     treasuryAta: Address<TAccountTreasuryAta>;
-    /** The token account of the team */
-    teamAta: Address<TAccountTeamAta>;
+    /** The token account of the principal vault, receiving the treasury share of an auto-compounded bond */
+    principalVaultAta: Address<TAccountPrincipalVaultAta>;
+    /** The token account of the team vesting escrow, receiving the team share of an auto-compounded bond */
+    teamVestingAta: Address<TAccountTeamVestingAta>;
     /** The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index]) */
     newBondPda: Address<TAccountNewBondPda>;
-    /** The native token mint */
+    /** The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index]) */
+    newBondPage: Address<TAccountNewBondPage>;
+    /** The native token mint, written when penalties are burned or rewards minted */
     nativeTokenMint: Address<TAccountNativeTokenMint>;
     /** The token program */
     tokenProgram?: Address<TAccountTokenProgram>;
@@ -402,6 +593,24 @@ export type ProcessClaimInput<
     associatedTokenProgram: Address<TAccountAssociatedTokenProgram>;
     /** The system program */
     systemProgram?: Address<TAccountSystemProgram>;
+    /** The global state account (seeds: ['global_state']) */
+    globalState: Address<TAccountGlobalState>;
+    /** The deposit split config (seeds: ['split_config']) */
+    splitConfig: Address<TAccountSplitConfig>;
+    /** The reward stream list (seeds: ['reward_streams']) */
+    rewardStreams: Address<TAccountRewardStreams>;
+    /** The signer's token account holding the bond token, required for tokenized bonds */
+    bondTokenAccount?: Address<TAccountBondTokenAccount>;
+    /** The wallet token account of the bond owner's referrer, required when the owner has one */
+    referrerAta?: Address<TAccountReferrerAta>;
+    /** The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet]) */
+    referrerStats?: Address<TAccountReferrerStats>;
+    /** The signer's user pda, required when the signer holds a tokenized bond it does not own */
+    holderUserPda?: Address<TAccountHolderUserPda>;
+    /** The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index]) */
+    holderBondPage?: Address<TAccountHolderBondPage>;
+    /** The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied */
+    ownerWallet?: Address<TAccountOwnerWallet>;
     bondIndex: ProcessClaimInstructionDataArgs['bondIndex'];
     autoCompound: ProcessClaimInstructionDataArgs['autoCompound'];
 };
@@ -410,32 +619,56 @@ export function getProcessClaimInstruction<
     TAccountBond extends string,
     TAccountUserWallet extends string,
     TAccountUserPda extends string,
+    TAccountUserBondPage extends string,
     TAccountUserWalletAta extends string,
     TAccountGlobalAdmin extends string,
     TAccountRewardsPoolAta extends string,
     TAccountTreasuryAta extends string,
-    TAccountTeamAta extends string,
+    TAccountPrincipalVaultAta extends string,
+    TAccountTeamVestingAta extends string,
     TAccountNewBondPda extends string,
+    TAccountNewBondPage extends string,
     TAccountNativeTokenMint extends string,
     TAccountTokenProgram extends string,
     TAccountAssociatedTokenProgram extends string,
     TAccountSystemProgram extends string,
+    TAccountGlobalState extends string,
+    TAccountSplitConfig extends string,
+    TAccountRewardStreams extends string,
+    TAccountBondTokenAccount extends string,
+    TAccountReferrerAta extends string,
+    TAccountReferrerStats extends string,
+    TAccountHolderUserPda extends string,
+    TAccountHolderBondPage extends string,
+    TAccountOwnerWallet extends string,
     TProgramAddress extends Address = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
 >(
     input: ProcessClaimInput<
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountUserWalletAta,
         TAccountGlobalAdmin,
         TAccountRewardsPoolAta,
         TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNewBondPda,
+        TAccountNewBondPage,
         TAccountNativeTokenMint,
         TAccountTokenProgram,
         TAccountAssociatedTokenProgram,
-        TAccountSystemProgram
+        TAccountSystemProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountRewardStreams,
+        TAccountBondTokenAccount,
+        TAccountReferrerAta,
+        TAccountReferrerStats,
+        TAccountHolderUserPda,
+        TAccountHolderBondPage,
+        TAccountOwnerWallet
     >,
     config?: { programAddress?: TProgramAddress }
 ): ProcessClaimInstruction<
@@ -443,16 +676,28 @@ export function getProcessClaimInstruction<
     TAccountBond,
     TAccountUserWallet,
     TAccountUserPda,
+    TAccountUserBondPage,
     TAccountUserWalletAta,
     TAccountGlobalAdmin,
     TAccountRewardsPoolAta,
     TAccountTreasuryAta,
-    TAccountTeamAta,
+    TAccountPrincipalVaultAta,
+    TAccountTeamVestingAta,
     TAccountNewBondPda,
+    TAccountNewBondPage,
     TAccountNativeTokenMint,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountGlobalState,
+    TAccountSplitConfig,
+    TAccountRewardStreams,
+    TAccountBondTokenAccount,
+    TAccountReferrerAta,
+    TAccountReferrerStats,
+    TAccountHolderUserPda,
+    TAccountHolderBondPage,
+    TAccountOwnerWallet
 > {
     // Program address.
     const programAddress =
@@ -461,8 +706,9 @@ export function getProcessClaimInstruction<
     // Original accounts.
     const originalAccounts = {
         bond: { value: input.bond ?? null, isWritable: true },
-        userWallet: { value: input.userWallet ?? null, isWritable: false },
+        userWallet: { value: input.userWallet ?? null, isWritable: true },
         userPda: { value: input.userPda ?? null, isWritable: true },
+        userBondPage: { value: input.userBondPage ?? null, isWritable: true },
         userWalletAta: { value: input.userWalletAta ?? null, isWritable: true },
         globalAdmin: { value: input.globalAdmin ?? null, isWritable: false },
         rewardsPoolAta: {
@@ -470,11 +716,19 @@ export function getProcessClaimInstruction<
             isWritable: true,
         },
         treasuryAta: { value: input.treasuryAta ?? null, isWritable: true },
-        teamAta: { value: input.teamAta ?? null, isWritable: true },
+        principalVaultAta: {
+            value: input.principalVaultAta ?? null,
+            isWritable: true,
+        },
+        teamVestingAta: {
+            value: input.teamVestingAta ?? null,
+            isWritable: true,
+        },
         newBondPda: { value: input.newBondPda ?? null, isWritable: true },
+        newBondPage: { value: input.newBondPage ?? null, isWritable: true },
         nativeTokenMint: {
             value: input.nativeTokenMint ?? null,
-            isWritable: false,
+            isWritable: true,
         },
         tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
         associatedTokenProgram: {
@@ -485,6 +739,24 @@ export function getProcessClaimInstruction<
             value: input.systemProgram ?? null,
             isWritable: false,
         },
+        globalState: { value: input.globalState ?? null, isWritable: true },
+        splitConfig: { value: input.splitConfig ?? null, isWritable: false },
+        rewardStreams: {
+            value: input.rewardStreams ?? null,
+            isWritable: false,
+        },
+        bondTokenAccount: {
+            value: input.bondTokenAccount ?? null,
+            isWritable: false,
+        },
+        referrerAta: { value: input.referrerAta ?? null, isWritable: true },
+        referrerStats: { value: input.referrerStats ?? null, isWritable: true },
+        holderUserPda: { value: input.holderUserPda ?? null, isWritable: true },
+        holderBondPage: {
+            value: input.holderBondPage ?? null,
+            isWritable: true,
+        },
+        ownerWallet: { value: input.ownerWallet ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<
         keyof typeof originalAccounts,
@@ -510,16 +782,28 @@ export function getProcessClaimInstruction<
             getAccountMeta(accounts.bond),
             getAccountMeta(accounts.userWallet),
             getAccountMeta(accounts.userPda),
+            getAccountMeta(accounts.userBondPage),
             getAccountMeta(accounts.userWalletAta),
             getAccountMeta(accounts.globalAdmin),
             getAccountMeta(accounts.rewardsPoolAta),
             getAccountMeta(accounts.treasuryAta),
-            getAccountMeta(accounts.teamAta),
+            getAccountMeta(accounts.principalVaultAta),
+            getAccountMeta(accounts.teamVestingAta),
             getAccountMeta(accounts.newBondPda),
+            getAccountMeta(accounts.newBondPage),
             getAccountMeta(accounts.nativeTokenMint),
             getAccountMeta(accounts.tokenProgram),
             getAccountMeta(accounts.associatedTokenProgram),
             getAccountMeta(accounts.systemProgram),
+            getAccountMeta(accounts.globalState),
+            getAccountMeta(accounts.splitConfig),
+            getAccountMeta(accounts.rewardStreams),
+            getAccountMeta(accounts.bondTokenAccount),
+            getAccountMeta(accounts.referrerAta),
+            getAccountMeta(accounts.referrerStats),
+            getAccountMeta(accounts.holderUserPda),
+            getAccountMeta(accounts.holderBondPage),
+            getAccountMeta(accounts.ownerWallet),
         ],
         programAddress,
         data: getProcessClaimInstructionDataEncoder().encode(
@@ -530,16 +814,28 @@ export function getProcessClaimInstruction<
         TAccountBond,
         TAccountUserWallet,
         TAccountUserPda,
+        TAccountUserBondPage,
         TAccountUserWalletAta,
         TAccountGlobalAdmin,
         TAccountRewardsPoolAta,
         TAccountTreasuryAta,
-        TAccountTeamAta,
+        TAccountPrincipalVaultAta,
+        TAccountTeamVestingAta,
         TAccountNewBondPda,
+        TAccountNewBondPage,
         TAccountNativeTokenMint,
         TAccountTokenProgram,
         TAccountAssociatedTokenProgram,
-        TAccountSystemProgram
+        TAccountSystemProgram,
+        TAccountGlobalState,
+        TAccountSplitConfig,
+        TAccountRewardStreams,
+        TAccountBondTokenAccount,
+        TAccountReferrerAta,
+        TAccountReferrerStats,
+        TAccountHolderUserPda,
+        TAccountHolderBondPage,
+        TAccountOwnerWallet
     >;
 
     return instruction;
@@ -553,30 +849,54 @@ export type ParsedProcessClaimInstruction<
     accounts: {
         /** The program derived address of the bond account to claim rewards from (seeds: ['bond', user_pda, bond_index]) */
         bond: TAccountMetas[0];
-        /** The wallet of the user */
+        /** The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it */
         userWallet: TAccountMetas[1];
         /** The user's pda */
         userPda: TAccountMetas[2];
+        /** The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index]) */
+        userBondPage: TAccountMetas[3];
         /** The user's wallet token account */
-        userWalletAta: TAccountMetas[3];
+        userWalletAta: TAccountMetas[4];
         /** The global admin account */
-        globalAdmin: TAccountMetas[4];
+        globalAdmin: TAccountMetas[5];
         /** The rewards pool (token account) of the global admin */
-        rewardsPoolAta: TAccountMetas[5];
+        rewardsPoolAta: TAccountMetas[6];
         /** The token account of the treasury */
-        treasuryAta: TAccountMetas[6];
-        /** The token account of the team */
-        teamAta: TAccountMetas[7];
+        treasuryAta: TAccountMetas[7];
+        /** The token account of the principal vault, receiving the treasury share of an auto-compounded bond */
+        principalVaultAta: TAccountMetas[8];
+        /** The token account of the team vesting escrow, receiving the team share of an auto-compounded bond */
+        teamVestingAta: TAccountMetas[9];
         /** The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index]) */
-        newBondPda: TAccountMetas[8];
-        /** The native token mint */
-        nativeTokenMint: TAccountMetas[9];
+        newBondPda: TAccountMetas[10];
+        /** The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index]) */
+        newBondPage: TAccountMetas[11];
+        /** The native token mint, written when penalties are burned or rewards minted */
+        nativeTokenMint: TAccountMetas[12];
         /** The token program */
-        tokenProgram: TAccountMetas[10];
+        tokenProgram: TAccountMetas[13];
         /** The associated token program (for the rewards pool) */
-        associatedTokenProgram: TAccountMetas[11];
+        associatedTokenProgram: TAccountMetas[14];
         /** The system program */
-        systemProgram: TAccountMetas[12];
+        systemProgram: TAccountMetas[15];
+        /** The global state account (seeds: ['global_state']) */
+        globalState: TAccountMetas[16];
+        /** The deposit split config (seeds: ['split_config']) */
+        splitConfig: TAccountMetas[17];
+        /** The reward stream list (seeds: ['reward_streams']) */
+        rewardStreams: TAccountMetas[18];
+        /** The signer's token account holding the bond token, required for tokenized bonds */
+        bondTokenAccount?: TAccountMetas[19] | undefined;
+        /** The wallet token account of the bond owner's referrer, required when the owner has one */
+        referrerAta?: TAccountMetas[20] | undefined;
+        /** The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet]) */
+        referrerStats?: TAccountMetas[21] | undefined;
+        /** The signer's user pda, required when the signer holds a tokenized bond it does not own */
+        holderUserPda?: TAccountMetas[22] | undefined;
+        /** The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index]) */
+        holderBondPage?: TAccountMetas[23] | undefined;
+        /** The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied */
+        ownerWallet?: TAccountMetas[24] | undefined;
     };
     data: ProcessClaimInstructionData;
 };
@@ -589,7 +909,7 @@ export function parseProcessClaimInstruction<
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>
 ): ParsedProcessClaimInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 13) {
+    if (instruction.accounts.length < 25) {
         // TODO: Coded error.
         throw new Error('Not enough accounts');
     }
@@ -599,22 +919,40 @@ export function parseProcessClaimInstruction<
         accountIndex += 1;
         return accountMeta;
     };
+    const getNextOptionalAccount = () => {
+        const accountMeta = getNextAccount();
+        return accountMeta.address === TESTUDO_BONDS_PROGRAM_ADDRESS
+            ? undefined
+            : accountMeta;
+    };
     return {
         programAddress: instruction.programAddress,
         accounts: {
             bond: getNextAccount(),
             userWallet: getNextAccount(),
             userPda: getNextAccount(),
+            userBondPage: getNextAccount(),
             userWalletAta: getNextAccount(),
             globalAdmin: getNextAccount(),
             rewardsPoolAta: getNextAccount(),
             treasuryAta: getNextAccount(),
-            teamAta: getNextAccount(),
+            principalVaultAta: getNextAccount(),
+            teamVestingAta: getNextAccount(),
             newBondPda: getNextAccount(),
+            newBondPage: getNextAccount(),
             nativeTokenMint: getNextAccount(),
             tokenProgram: getNextAccount(),
             associatedTokenProgram: getNextAccount(),
             systemProgram: getNextAccount(),
+            globalState: getNextAccount(),
+            splitConfig: getNextAccount(),
+            rewardStreams: getNextAccount(),
+            bondTokenAccount: getNextOptionalAccount(),
+            referrerAta: getNextOptionalAccount(),
+            referrerStats: getNextOptionalAccount(),
+            holderUserPda: getNextOptionalAccount(),
+            holderBondPage: getNextOptionalAccount(),
+            ownerWallet: getNextOptionalAccount(),
         },
         data: getProcessClaimInstructionDataDecoder().decode(instruction.data),
     };
@@ -41,6 +41,7 @@ export type UpdateAdminInstruction<
     TProgram extends string = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
     TAccountGlobalAdmin extends string | AccountMeta<string> = string,
     TAccountAuthority extends string | AccountMeta<string> = string,
+    TAccountGlobalState extends string | AccountMeta<string> = string,
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -53,6 +54,9 @@ export type UpdateAdminInstruction<
                 ? ReadonlySignerAccount<TAccountAuthority> &
                       AccountSignerMeta<TAccountAuthority>
                 : TAccountAuthority,
+            TAccountGlobalState extends string
+                ? WritableAccount<TAccountGlobalState>
+                : TAccountGlobalState,
             ...TRemainingAccounts,
         ]
     >;
@@ -85,25 +89,34 @@ export function getUpdateAdminInstructionDataCodec(): FixedSizeCodec<
 export type UpdateAdminAsyncInput<
     TAccountGlobalAdmin extends string = string,
     TAccountAuthority extends string = string,
+    TAccountGlobalState extends string = string,
 > = {
     /** The program derived address of the global admin account to update (seeds: ['global_admin']) */
     globalAdmin?: Address<TAccountGlobalAdmin>;
     /** The authority of the global admin */
     authority: TransactionSigner<TAccountAuthority>;
+    /** The global state account (seeds: ['global_state']) */
+    globalState: Address<TAccountGlobalState>;
 };
 
 export async function getUpdateAdminInstructionAsync<
     TAccountGlobalAdmin extends string,
     TAccountAuthority extends string,
+    TAccountGlobalState extends string,
     TProgramAddress extends Address = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
 >(
-    input: UpdateAdminAsyncInput<TAccountGlobalAdmin, TAccountAuthority>,
+    input: UpdateAdminAsyncInput<
+        TAccountGlobalAdmin,
+        TAccountAuthority,
+        TAccountGlobalState
+    >,
     config?: { programAddress?: TProgramAddress }
 ): Promise<
     UpdateAdminInstruction<
         TProgramAddress,
         TAccountGlobalAdmin,
-        TAccountAuthority
+        TAccountAuthority,
+        TAccountGlobalState
     >
 > {
     // Program address.
@@ -114,6 +127,7 @@ export async function getUpdateAdminInstructionAsync<
     const originalAccounts = {
         globalAdmin: { value: input.globalAdmin ?? null, isWritable: true },
         authority: { value: input.authority ?? null, isWritable: false },
+        globalState: { value: input.globalState ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<
         keyof typeof originalAccounts,
@@ -130,13 +144,15 @@ export async function getUpdateAdminInstructionAsync<
         accounts: [
             getAccountMeta(accounts.globalAdmin),
             getAccountMeta(accounts.authority),
+            getAccountMeta(accounts.globalState),
         ],
         programAddress,
         data: getUpdateAdminInstructionDataEncoder().encode({}),
     } as UpdateAdminInstruction<
         TProgramAddress,
         TAccountGlobalAdmin,
-        TAccountAuthority
+        TAccountAuthority,
+        TAccountGlobalState
     >;
 
     return instruction;
@@ -145,24 +161,33 @@ export async function getUpdateAdminInstructionAsync<
 export type UpdateAdminInput<
     TAccountGlobalAdmin extends string = string,
     TAccountAuthority extends string = string,
+    TAccountGlobalState extends string = string,
 > = {
     /** The program derived address of the global admin account to update (seeds: ['global_admin']) */
     globalAdmin: Address<TAccountGlobalAdmin>;
     /** The authority of the global admin */
     authority: TransactionSigner<TAccountAuthority>;
+    /** The global state account (seeds: ['global_state']) */
+    globalState: Address<TAccountGlobalState>;
 };
 
 export function getUpdateAdminInstruction<
     TAccountGlobalAdmin extends string,
     TAccountAuthority extends string,
+    TAccountGlobalState extends string,
     TProgramAddress extends Address = typeof TESTUDO_BONDS_PROGRAM_ADDRESS,
 >(
-    input: UpdateAdminInput<TAccountGlobalAdmin, TAccountAuthority>,
+    input: UpdateAdminInput<
+        TAccountGlobalAdmin,
+        TAccountAuthority,
+        TAccountGlobalState
+    >,
     config?: { programAddress?: TProgramAddress }
 ): UpdateAdminInstruction<
     TProgramAddress,
     TAccountGlobalAdmin,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountGlobalState
 > {
     // Program address.
     const programAddress =
@@ -172,6 +197,7 @@ export function getUpdateAdminInstruction<
     const originalAccounts = {
         globalAdmin: { value: input.globalAdmin ?? null, isWritable: true },
         authority: { value: input.authority ?? null, isWritable: false },
+        globalState: { value: input.globalState ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<
         keyof typeof originalAccounts,
@@ -183,13 +209,15 @@ export function getUpdateAdminInstruction<
         accounts: [
             getAccountMeta(accounts.globalAdmin),
             getAccountMeta(accounts.authority),
+            getAccountMeta(accounts.globalState),
         ],
         programAddress,
         data: getUpdateAdminInstructionDataEncoder().encode({}),
     } as UpdateAdminInstruction<
         TProgramAddress,
         TAccountGlobalAdmin,
-        TAccountAuthority
+        TAccountAuthority,
+        TAccountGlobalState
     >;
 
     return instruction;
@@ -205,6 +233,8 @@ export type ParsedUpdateAdminInstruction<
         globalAdmin: TAccountMetas[0];
         /** The authority of the global admin */
         authority: TAccountMetas[1];
+        /** The global state account (seeds: ['global_state']) */
+        globalState: TAccountMetas[2];
     };
     data: UpdateAdminInstructionData;
 };
@@ -217,7 +247,7 @@ export function parseUpdateAdminInstruction<
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdateAdminInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 2) {
+    if (instruction.accounts.length < 3) {
         // TODO: Coded error.
         throw new Error('Not enough accounts');
     }
@@ -232,6 +262,7 @@ export function parseUpdateAdminInstruction<
         accounts: {
             globalAdmin: getNextAccount(),
             authority: getNextAccount(),
+            globalState: getNextAccount(),
         },
         data: getUpdateAdminInstructionDataDecoder().decode(instruction.data),
     };
@@ -9,7 +9,7 @@
 import {
     getAddressEncoder,
     getProgramDerivedAddress,
-    getU8Encoder,
+    getU16Encoder,
     getUtf8Encoder,
     type Address,
     type ProgramDerivedAddress,
@@ -34,7 +34,7 @@ export async function findBondPda(
         seeds: [
             getUtf8Encoder().encode('bond'),
             getAddressEncoder().encode(seeds.userPda),
-            getU8Encoder().encode(seeds.bondIndex),
+            getU16Encoder().encode(seeds.bondIndex),
         ],
     });
 }
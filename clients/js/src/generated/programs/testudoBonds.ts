@@ -13,11 +13,25 @@ import {
     type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+    type ParsedAttachRewardStreamInstruction,
+    type ParsedBuyBondInstruction,
+    type ParsedCancelListingInstruction,
     type ParsedCreateUserInstruction,
+    type ParsedDetachRewardStreamInstruction,
+    type ParsedDistributeVaultInstruction,
+    type ParsedGrantBondInstruction,
     type ParsedInitializeAdminInstruction,
     type ParsedInitializeBondInstruction,
+    type ParsedListBondInstruction,
+    type ParsedMatureBondInstruction,
+    type ParsedMergeBondsInstruction,
     type ParsedProcessClaimInstruction,
+    type ParsedRedeemBondInstruction,
+    type ParsedReleaseVestedInstruction,
+    type ParsedSplitBondInstruction,
+    type ParsedTransferBondInstruction,
     type ParsedUpdateAdminInstruction,
+    type ParsedUpdateSplitConfigInstruction,
 } from '../instructions';
 
 export const TESTUDO_BONDS_PROGRAM_ADDRESS =
@@ -26,7 +40,15 @@ export const TESTUDO_BONDS_PROGRAM_ADDRESS =
 export enum TestudoBondsAccount {
     GlobalAdmin,
     UserPda,
+    UserBondPage,
     Bond,
+    Listing,
+    GlobalState,
+    SplitConfig,
+    RewardStream,
+    RewardStreamList,
+    StreamSettlement,
+    ReferrerStats,
 }
 
 export enum TestudoBondsInstruction {
@@ -35,6 +57,20 @@ export enum TestudoBondsInstruction {
     InitializeBond,
     ProcessClaim,
     UpdateAdmin,
+    RedeemBond,
+    TransferBond,
+    ListBond,
+    CancelListing,
+    BuyBond,
+    MergeBonds,
+    SplitBond,
+    MatureBond,
+    DistributeVault,
+    ReleaseVested,
+    UpdateSplitConfig,
+    AttachRewardStream,
+    GrantBond,
+    DetachRewardStream,
 }
 
 export function identifyTestudoBondsInstruction(
@@ -56,6 +92,48 @@ export function identifyTestudoBondsInstruction(
     if (containsBytes(data, getU8Encoder().encode(4), 0)) {
         return TestudoBondsInstruction.UpdateAdmin;
     }
+    if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+        return TestudoBondsInstruction.RedeemBond;
+    }
+    if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+        return TestudoBondsInstruction.TransferBond;
+    }
+    if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+        return TestudoBondsInstruction.ListBond;
+    }
+    if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+        return TestudoBondsInstruction.CancelListing;
+    }
+    if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+        return TestudoBondsInstruction.BuyBond;
+    }
+    if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+        return TestudoBondsInstruction.MergeBonds;
+    }
+    if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+        return TestudoBondsInstruction.SplitBond;
+    }
+    if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+        return TestudoBondsInstruction.MatureBond;
+    }
+    if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+        return TestudoBondsInstruction.DistributeVault;
+    }
+    if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+        return TestudoBondsInstruction.ReleaseVested;
+    }
+    if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+        return TestudoBondsInstruction.UpdateSplitConfig;
+    }
+    if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+        return TestudoBondsInstruction.AttachRewardStream;
+    }
+    if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+        return TestudoBondsInstruction.GrantBond;
+    }
+    if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+        return TestudoBondsInstruction.DetachRewardStream;
+    }
     throw new Error(
         'The provided instruction could not be identified as a testudoBonds instruction.'
     );
@@ -78,4 +156,46 @@ export type ParsedTestudoBondsInstruction<
       } & ParsedProcessClaimInstruction<TProgram>)
     | ({
           instructionType: TestudoBondsInstruction.UpdateAdmin;
-      } & ParsedUpdateAdminInstruction<TProgram>);
+      } & ParsedUpdateAdminInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.RedeemBond;
+      } & ParsedRedeemBondInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.TransferBond;
+      } & ParsedTransferBondInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.ListBond;
+      } & ParsedListBondInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.CancelListing;
+      } & ParsedCancelListingInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.BuyBond;
+      } & ParsedBuyBondInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.MergeBonds;
+      } & ParsedMergeBondsInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.SplitBond;
+      } & ParsedSplitBondInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.MatureBond;
+      } & ParsedMatureBondInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.DistributeVault;
+      } & ParsedDistributeVaultInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.ReleaseVested;
+      } & ParsedReleaseVestedInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.UpdateSplitConfig;
+      } & ParsedUpdateSplitConfigInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.AttachRewardStream;
+      } & ParsedAttachRewardStreamInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.GrantBond;
+      } & ParsedGrantBondInstruction<TProgram>)
+    | ({
+          instructionType: TestudoBondsInstruction.DetachRewardStream;
+      } & ParsedDetachRewardStreamInstruction<TProgram>);
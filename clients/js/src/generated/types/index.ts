@@ -6,5 +6,32 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './allowlistPhase';
+export * from './allowlistProof';
+export * from './attachRewardStreamPayload';
+export * from './buyBondPayload';
+export * from './cancelListingPayload';
+export * from './emissionCurve';
+export * from './emissionMode';
+export * from './fixedTermConfig';
+export * from './grantBondPayload';
+export * from './grantSource';
+export * from './initializeBondPayload';
+export * from './listBondPayload';
+export * from './lockTier';
+export * from './loyaltyTier';
+export * from './matureBondPayload';
+export * from './mergeBondsPayload';
+export * from './penaltyDestination';
+export * from './penaltyTier';
 export * from './processClaimPayload';
+export * from './redeemBondPayload';
+export * from './rewardSource';
+export * from './splitBondPayload';
+export * from './splitDestination';
+export * from './splitRecipient';
+export * from './transferBondPayload';
 export * from './updateAdminPayload';
+export * from './updateSplitConfigPayload';
+export * from './vestingSchedule';
+export * from './vestingTranche';
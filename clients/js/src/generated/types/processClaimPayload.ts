@@ -12,8 +12,8 @@ import {
     getBooleanEncoder,
     getStructDecoder,
     getStructEncoder,
-    getU8Decoder,
-    getU8Encoder,
+    getU16Decoder,
+    getU16Encoder,
     type FixedSizeCodec,
     type FixedSizeDecoder,
     type FixedSizeEncoder,
@@ -25,14 +25,14 @@ export type ProcessClaimPayloadArgs = ProcessClaimPayload;
 
 export function getProcessClaimPayloadEncoder(): FixedSizeEncoder<ProcessClaimPayloadArgs> {
     return getStructEncoder([
-        ['bondIndex', getU8Encoder()],
+        ['bondIndex', getU16Encoder()],
         ['autoCompound', getBooleanEncoder()],
     ]);
 }
 
 export function getProcessClaimPayloadDecoder(): FixedSizeDecoder<ProcessClaimPayload> {
     return getStructDecoder([
-        ['bondIndex', getU8Decoder()],
+        ['bondIndex', getU16Decoder()],
         ['autoCompound', getBooleanDecoder()],
     ]);
 }
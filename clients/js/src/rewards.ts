@@ -0,0 +1,238 @@
+/**
+ * Client-side reward previews. Mirrors the per bond reward math of the
+ * program (`utils/calculation_utils.rs`) so wallets can show what a claim
+ * would pay, and the loyalty boost a bond currently earns, before sending
+ * a transaction.
+ */
+
+const SECONDS_PER_DAY = 86_400n;
+const STANDARD_BOND_WEIGHT = 10_000n;
+const BASIS_POINTS = 10_000n;
+const DECAY_FACTOR_PRECISION = 1_000_000_000_000_000_000n;
+const ACCRUAL_DENOMINATOR =
+    SECONDS_PER_DAY * STANDARD_BOND_WEIGHT * BASIS_POINTS;
+
+export type PenaltyTier = { maxElapsedSeconds: number; penaltyBps: number };
+
+export type LoyaltyTier = { minAgeSeconds: number; boostBps: number };
+
+export type EmissionCurve = {
+    startTimestamp: bigint;
+    periodSeconds: number;
+    decayBps: number;
+};
+
+export type RewardConfig = {
+    dailyEmissionRate: bigint;
+    maxEmissionPerBond: bigint;
+    emissionCurve: EmissionCurve;
+    loyaltySchedule: LoyaltyTier[];
+    claimPenaltySchedule: PenaltyTier[];
+};
+
+export type BondRewardState = {
+    creationTimestamp: bigint;
+    lastClaimTimestamp: bigint;
+    totalClaimed: bigint;
+    weight: number;
+    rewardRemainder: bigint;
+    lockBoostBps: number;
+};
+
+export type RewardPreview = {
+    /** Shells paid to the bond owner on a claim at the preview time. */
+    reward: bigint;
+    /** Shells withheld by the claim penalty schedule. */
+    penalty: bigint;
+    /** Loyalty boost in basis points the bond earns at the preview time. */
+    loyaltyBoostBps: number;
+};
+
+/**
+ * Loyalty boost in basis points for a bond `ageSeconds` old: the boost of
+ * the last tier of the schedule the bond is old enough for, or 0.
+ */
+export function getLoyaltyBoostBps(
+    loyaltySchedule: LoyaltyTier[],
+    ageSeconds: bigint
+): number {
+    let boostBps = 0;
+    for (const tier of loyaltySchedule) {
+        if (
+            tier.minAgeSeconds > 0 &&
+            ageSeconds >= BigInt(tier.minAgeSeconds)
+        ) {
+            boostBps = tier.boostBps;
+        }
+    }
+    return boostBps;
+}
+
+/**
+ * Preview the reward of a per bond mode claim on `bond` at `now` (unix
+ * seconds), including its lock-up boost. Claims on a bond still locked at
+ * `now` fail on chain. Redistributed penalty shares and global emission
+ * mode are not included.
+ */
+export function previewBondReward(
+    bond: BondRewardState,
+    config: RewardConfig,
+    now: bigint
+): RewardPreview {
+    const age =
+        now > bond.creationTimestamp ? now - bond.creationTimestamp : 0n;
+    const loyaltyBoostBps = getLoyaltyBoostBps(config.loyaltySchedule, age);
+    if (now < bond.lastClaimTimestamp) {
+        return { reward: 0n, penalty: 0n, loyaltyBoostBps };
+    }
+
+    const emission = integrateBoostedEmission(
+        config,
+        bond.creationTimestamp,
+        bond.lockBoostBps,
+        bond.lastClaimTimestamp,
+        now
+    );
+    const accrued =
+        (emission * BigInt(bond.weight) + bond.rewardRemainder) /
+        ACCRUAL_DENOMINATOR;
+    if (accrued === 0n) {
+        return { reward: 0n, penalty: 0n, loyaltyBoostBps };
+    }
+
+    const elapsed = now - bond.lastClaimTimestamp;
+    const penaltyTier = config.claimPenaltySchedule.find(
+        (tier) =>
+            tier.maxElapsedSeconds > 0 &&
+            elapsed < BigInt(tier.maxElapsedSeconds)
+    );
+    const withheld = penaltyTier
+        ? (accrued * BigInt(penaltyTier.penaltyBps)) / BASIS_POINTS
+        : 0n;
+
+    const emissionCap =
+        (config.maxEmissionPerBond * BigInt(bond.weight)) /
+        STANDARD_BOND_WEIGHT;
+    const headroom =
+        emissionCap > bond.totalClaimed ? emissionCap - bond.totalClaimed : 0n;
+    const reward = min(accrued - withheld, headroom);
+    return {
+        reward,
+        penalty: min(withheld, headroom - reward),
+        loyaltyBoostBps,
+    };
+}
+
+function integrateBoostedEmission(
+    config: RewardConfig,
+    creationTimestamp: bigint,
+    lockBoostBps: number,
+    from: bigint,
+    to: bigint
+): bigint {
+    let emission = 0n;
+    let timestamp = from;
+    for (;;) {
+        const age =
+            timestamp > creationTimestamp ? timestamp - creationTimestamp : 0n;
+        const boostBps = getLoyaltyBoostBps(config.loyaltySchedule, age);
+        let segmentEnd = to;
+        for (const tier of config.loyaltySchedule) {
+            const tierStart = creationTimestamp + BigInt(tier.minAgeSeconds);
+            if (BigInt(tier.minAgeSeconds) > age && tierStart < segmentEnd) {
+                segmentEnd = tierStart;
+            }
+        }
+        emission +=
+            integrateEmission(
+                config.dailyEmissionRate,
+                config.emissionCurve,
+                timestamp,
+                segmentEnd
+            ) *
+            (BASIS_POINTS + BigInt(boostBps) + BigInt(lockBoostBps));
+        if (segmentEnd >= to) {
+            return emission;
+        }
+        timestamp = segmentEnd;
+    }
+}
+
+function integrateEmission(
+    rate: bigint,
+    curve: EmissionCurve,
+    from: bigint,
+    to: bigint
+): bigint {
+    if (curve.decayBps === 0 || curve.periodSeconds === 0) {
+        return rate * (to - from);
+    }
+    const beforeStart =
+        from < curve.startTimestamp
+            ? rate * (min(to, curve.startTimestamp) - from)
+            : 0n;
+    if (to <= curve.startTimestamp) {
+        return beforeStart;
+    }
+    const start =
+        from > curve.startTimestamp ? from : curve.startTimestamp;
+    return (
+        cumulativeEmission(rate, curve, to) -
+        cumulativeEmission(rate, curve, start) +
+        beforeStart
+    );
+}
+
+function cumulativeEmission(
+    rate: bigint,
+    curve: EmissionCurve,
+    timestamp: bigint
+): bigint {
+    const period = BigInt(curve.periodSeconds);
+    const elapsed = timestamp - curve.startTimestamp;
+    const epoch = elapsed / period;
+    const periodElapsed = elapsed % period;
+    const epochStart = geometricEmission(rate, curve, epoch);
+    if (periodElapsed === 0n) {
+        return epochStart;
+    }
+    let epochEnd = geometricEmission(rate, curve, epoch + 1n);
+    if (epochEnd < epochStart) {
+        epochEnd = epochStart;
+    }
+    return epochStart + ((epochEnd - epochStart) * periodElapsed) / period;
+}
+
+function geometricEmission(
+    rate: bigint,
+    curve: EmissionCurve,
+    epochs: bigint
+): bigint {
+    const decayed =
+        DECAY_FACTOR_PRECISION - decayFactor(curve.decayBps, epochs);
+    const decayBps = BigInt(Math.min(curve.decayBps, 10_000));
+    return (
+        (rate * decayed * BASIS_POINTS * BigInt(curve.periodSeconds)) /
+        (decayBps * DECAY_FACTOR_PRECISION)
+    );
+}
+
+function decayFactor(decayBps: number, epoch: bigint): bigint {
+    let base =
+        (BASIS_POINTS - BigInt(Math.min(decayBps, 10_000))) *
+        (DECAY_FACTOR_PRECISION / BASIS_POINTS);
+    let factor = DECAY_FACTOR_PRECISION;
+    let exponent = epoch;
+    while (exponent > 0n && factor > 0n) {
+        if (exponent & 1n) {
+            factor = (factor * base) / DECAY_FACTOR_PRECISION;
+        }
+        base = (base * base) / DECAY_FACTOR_PRECISION;
+        exponent >>= 1n;
+    }
+    return factor;
+}
+
+function min(a: bigint, b: bigint): bigint {
+    return a < b ? a : b;
+}
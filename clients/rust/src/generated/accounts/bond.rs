@@ -6,6 +6,7 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::GrantSource;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -15,18 +16,35 @@ use borsh::BorshDeserialize;
 pub struct Bond {
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub owner: Pubkey,
-pub bond_index: u8,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub creator: Pubkey,
+pub bond_index: u16,
+pub page_index: u16,
 pub creation_timestamp: i64,
 pub last_claim_timestamp: i64,
 pub total_claimed: u64,
 pub is_active: bool,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub mint: Pubkey,
+pub is_listed: bool,
+pub weight: u32,
+pub penalty_per_weight_checkpoint: u128,
+pub reward_remainder: u64,
+pub reward_per_weight_checkpoint: u128,
+pub lock_until: i64,
+pub lock_boost_bps: u16,
+pub maturity_timestamp: i64,
+pub principal: u64,
+pub maturity_reward: u64,
+pub principal_return_bps: u16,
+pub grant_source: GrantSource,
 }
 
 
 
 
 impl Bond {
-      pub const LEN: usize = 58;
+      pub const LEN: usize = 207;
   
           /// Prefix values used to generate a PDA for this account.
     ///
@@ -34,12 +52,12 @@ impl Bond {
     ///
                   ///   0. `Bond::PREFIX`
                                 ///   1. user_pda (`Pubkey`)
-                        ///   2. bond_index (`u8`)
+                        ///   2. bond_index (`u16`)
                     pub const PREFIX: &'static [u8] = "bond".as_bytes();
       
       pub fn create_pda(
                                                                 user_pda: Pubkey,
-                                                bond_index: u8,
+                                                bond_index: u16,
                                   bump: u8,
     ) -> Result<solana_pubkey::Pubkey, solana_pubkey::PubkeyError> {
       solana_pubkey::Pubkey::create_program_address(
@@ -55,7 +73,7 @@ impl Bond {
 
     pub fn find_pda(
                                                     user_pda: &Pubkey,
-                                        bond_index: u8,
+                                        bond_index: u16,
                           ) -> (solana_pubkey::Pubkey, u8) {
       solana_pubkey::Pubkey::find_program_address(
         &[
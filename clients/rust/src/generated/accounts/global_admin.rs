@@ -6,6 +6,16 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::PenaltyTier;
+use crate::generated::types::PenaltyDestination;
+use crate::generated::types::EmissionMode;
+use crate::generated::types::EmissionCurve;
+use crate::generated::types::LoyaltyTier;
+use crate::generated::types::LockTier;
+use crate::generated::types::FixedTermConfig;
+use crate::generated::types::VestingSchedule;
+use crate::generated::types::RewardSource;
+use crate::generated::types::AllowlistPhase;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -25,17 +35,34 @@ pub rewards_pool: Pubkey,
 pub native_token_mint: Pubkey,
 pub daily_emission_rate: u64,
 pub max_emission_per_bond: u64,
-pub max_bonds_per_wallet: u8,
-pub token_deposit_split: [u16; 3],
-pub claim_penalty: u16,
+pub max_bonds_per_wallet: u16,
+pub claim_penalty_schedule: [PenaltyTier; 4],
 pub pause_bond_operations: bool,
+pub redemption_rate: u16,
+pub marketplace_fee: u16,
+pub penalty_destination: PenaltyDestination,
+pub emission_mode: EmissionMode,
+pub global_emission_rate: u64,
+pub emission_curve: EmissionCurve,
+pub loyalty_schedule: [LoyaltyTier; 4],
+pub lock_tiers: [LockTier; 4],
+pub fixed_term: FixedTermConfig,
+pub distribution_interval: u32,
+pub team_vesting: VestingSchedule,
+pub reward_source: RewardSource,
+pub emission_ceiling: u64,
+pub referral_deposit_bps: u16,
+pub referral_claim_bps: u16,
+pub allowlist: AllowlistPhase,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub grant_authority: Pubkey,
 }
 
 
 
 
 impl GlobalAdmin {
-      pub const LEN: usize = 186;
+      pub const LEN: usize = 406;
   
           /// Prefix values used to generate a PDA for this account.
     ///
@@ -7,9 +7,25 @@
 
   pub(crate) mod r#bond;
   pub(crate) mod r#global_admin;
+  pub(crate) mod r#global_state;
+  pub(crate) mod r#listing;
+  pub(crate) mod r#referrer_stats;
+  pub(crate) mod r#reward_stream;
+  pub(crate) mod r#reward_stream_list;
+  pub(crate) mod r#split_config;
+  pub(crate) mod r#stream_settlement;
+  pub(crate) mod r#user_bond_page;
   pub(crate) mod r#user_pda;
 
   pub use self::r#bond::*;
   pub use self::r#global_admin::*;
+  pub use self::r#global_state::*;
+  pub use self::r#listing::*;
+  pub use self::r#referrer_stats::*;
+  pub use self::r#reward_stream::*;
+  pub use self::r#reward_stream_list::*;
+  pub use self::r#split_config::*;
+  pub use self::r#stream_settlement::*;
+  pub use self::r#user_bond_page::*;
   pub use self::r#user_pda::*;
 
@@ -15,16 +15,22 @@ use borsh::BorshDeserialize;
 pub struct UserPda {
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 pub user: Pubkey,
-pub bond_count: u8,
+pub bond_count: u16,
 pub total_accrued_rewards: u64,
-pub bond_index: u8,
-pub active_bonds: Vec<(u8, Pubkey)>,
+pub bond_index: u16,
+pub page_count: u16,
+pub total_penalties: u64,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub referrer: Pubkey,
+pub allowlist_root: [u8; 32],
+pub allowlist_used: u16,
 }
 
 
 
 
 impl UserPda {
+      pub const LEN: usize = 120;
   
           /// Prefix values used to generate a PDA for this account.
     ///
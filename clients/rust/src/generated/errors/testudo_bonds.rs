@@ -70,6 +70,93 @@ pub enum TestudoBondsError {
     /// 19 - Bond is active
     #[error("Bond is active")]
     BondIsActive = 0x13,
+    /// 20 - Bond page is full
+    #[error("Bond page is full")]
+    BondPageFull = 0x14,
+    /// 21 - Bond is tokenized
+    #[error("Bond is tokenized")]
+    BondIsTokenized = 0x15,
+    /// 22 - Signer does not hold the bond token
+    #[error("Signer does not hold the bond token")]
+    NotBondHolder = 0x16,
+    /// 23 - Bond is listed for sale
+    #[error("Bond is listed for sale")]
+    BondIsListed = 0x17,
+    /// 24 - Invalid listing price
+    #[error("Invalid listing price")]
+    InvalidListingPrice = 0x18,
+    /// 25 - Invalid bond weight
+    #[error("Invalid bond weight")]
+    InvalidBondWeight = 0x19,
+    /// 26 - Invalid penalty schedule
+    #[error("Invalid penalty schedule")]
+    InvalidPenaltySchedule = 0x1A,
+    /// 27 - Current timestamp is before the last claim
+    #[error("Current timestamp is before the last claim")]
+    InvalidTimestamp = 0x1B,
+    /// 28 - Numerical underflow
+    #[error("Numerical underflow")]
+    NumericalUnderflow = 0x1C,
+    /// 29 - Invalid emission curve
+    #[error("Invalid emission curve")]
+    InvalidEmissionCurve = 0x1D,
+    /// 30 - Invalid loyalty schedule
+    #[error("Invalid loyalty schedule")]
+    InvalidLoyaltySchedule = 0x1E,
+    /// 31 - Bond is locked
+    #[error("Bond is locked")]
+    BondLocked = 0x1F,
+    /// 32 - Invalid lock tier
+    #[error("Invalid lock tier")]
+    InvalidLockTier = 0x20,
+    /// 33 - Bonds with different lock boosts cannot be merged
+    #[error("Bonds with different lock boosts cannot be merged")]
+    LockBoostMismatch = 0x21,
+    /// 34 - Fixed-term bonds are unavailable or misconfigured
+    #[error("Fixed-term bonds are unavailable or misconfigured")]
+    InvalidFixedTerm = 0x22,
+    /// 35 - Bond is a fixed-term bond
+    #[error("Bond is a fixed-term bond")]
+    BondIsFixedTerm = 0x23,
+    /// 36 - Bond is not a matured fixed-term bond
+    #[error("Bond is not a matured fixed-term bond")]
+    BondNotMature = 0x24,
+    /// 37 - Vault distribution is not due yet
+    #[error("Vault distribution is not due yet")]
+    DistributionNotDue = 0x25,
+    /// 38 - Invalid vesting schedule
+    #[error("Invalid vesting schedule")]
+    InvalidVestingSchedule = 0x26,
+    /// 39 - No vested tokens to release
+    #[error("No vested tokens to release")]
+    NothingToRelease = 0x27,
+    /// 40 - Invalid deposit split config
+    #[error("Invalid deposit split config")]
+    InvalidSplitConfig = 0x28,
+    /// 41 - Minting the rewards would exceed the emission ceiling
+    #[error("Minting the rewards would exceed the emission ceiling")]
+    EmissionCeilingReached = 0x29,
+    /// 42 - Invalid reward stream
+    #[error("Invalid reward stream")]
+    InvalidRewardStream = 0x2A,
+    /// 43 - Invalid referrer or referral rate
+    #[error("Invalid referrer or referral rate")]
+    InvalidReferral = 0x2B,
+    /// 44 - Invalid allowlist phase
+    #[error("Invalid allowlist phase")]
+    InvalidAllowlistPhase = 0x2C,
+    /// 45 - Wallet is not allowlisted or has used its allocation
+    #[error("Wallet is not allowlisted or has used its allocation")]
+    NotAllowlisted = 0x2D,
+    /// 46 - Granted and bought bonds cannot be merged
+    #[error("Granted and bought bonds cannot be merged")]
+    BondGrantMismatch = 0x2E,
+    /// 47 - Listing price is above the buyer's maximum price
+    #[error("Listing price is above the buyer's maximum price")]
+    PriceAboveMaximum = 0x2F,
+    /// 48 - A granted bond needs a grant source
+    #[error("A granted bond needs a grant source")]
+    InvalidGrantSource = 0x30,
 }
 
 impl solana_program_error::PrintProgramError for TestudoBondsError {
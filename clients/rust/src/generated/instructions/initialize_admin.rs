@@ -68,6 +68,36 @@ pub struct InitializeAdmin {
     
               
           pub associated_token_program: solana_pubkey::Pubkey,
+                /// The program derived address of the global state account to create (seeds: ['global_state'])
+
+    
+              
+          pub global_state: solana_pubkey::Pubkey,
+                /// The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault'])
+
+    
+              
+          pub principal_vault: solana_pubkey::Pubkey,
+                /// The token account of the principal vault
+
+    
+              
+          pub principal_vault_ata: solana_pubkey::Pubkey,
+                /// The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting'])
+
+    
+              
+          pub team_vesting: solana_pubkey::Pubkey,
+                /// The token account of the team vesting escrow
+
+    
+              
+          pub team_vesting_ata: solana_pubkey::Pubkey,
+                /// The program derived address of the deposit split config to create (seeds: ['split_config'])
+
+    
+              
+          pub split_config: solana_pubkey::Pubkey,
       }
 
 impl InitializeAdmin {
@@ -77,7 +107,7 @@ impl InitializeAdmin {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(11+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(17+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.global_admin,
             false
@@ -121,6 +151,30 @@ impl InitializeAdmin {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.associated_token_program,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.global_state,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.principal_vault,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.principal_vault_ata,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.team_vesting,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.team_vesting_ata,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.split_config,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let data = borsh::to_vec(&InitializeAdminInstructionData::new()).unwrap();
@@ -170,6 +224,12 @@ impl Default for InitializeAdminInstructionData {
                 ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
                 ///   9. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
           ///   10. `[]` associated_token_program
+                ///   11. `[writable]` global_state
+          ///   12. `[]` principal_vault
+                ///   13. `[writable]` principal_vault_ata
+          ///   14. `[]` team_vesting
+                ///   15. `[writable]` team_vesting_ata
+                ///   16. `[writable]` split_config
 #[derive(Clone, Debug, Default)]
 pub struct InitializeAdminBuilder {
             global_admin: Option<solana_pubkey::Pubkey>,
@@ -183,6 +243,12 @@ pub struct InitializeAdminBuilder {
                 system_program: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
+                global_state: Option<solana_pubkey::Pubkey>,
+                principal_vault: Option<solana_pubkey::Pubkey>,
+                principal_vault_ata: Option<solana_pubkey::Pubkey>,
+                team_vesting: Option<solana_pubkey::Pubkey>,
+                team_vesting_ata: Option<solana_pubkey::Pubkey>,
+                split_config: Option<solana_pubkey::Pubkey>,
                 __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -257,6 +323,42 @@ impl InitializeAdminBuilder {
     pub fn associated_token_program(&mut self, associated_token_program: solana_pubkey::Pubkey) -> &mut Self {
                         self.associated_token_program = Some(associated_token_program);
                     self
+    }
+            /// The program derived address of the global state account to create (seeds: ['global_state'])
+#[inline(always)]
+    pub fn global_state(&mut self, global_state: solana_pubkey::Pubkey) -> &mut Self {
+                        self.global_state = Some(global_state);
+                    self
+    }
+            /// The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault'])
+#[inline(always)]
+    pub fn principal_vault(&mut self, principal_vault: solana_pubkey::Pubkey) -> &mut Self {
+                        self.principal_vault = Some(principal_vault);
+                    self
+    }
+            /// The token account of the principal vault
+#[inline(always)]
+    pub fn principal_vault_ata(&mut self, principal_vault_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.principal_vault_ata = Some(principal_vault_ata);
+                    self
+    }
+            /// The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting'])
+#[inline(always)]
+    pub fn team_vesting(&mut self, team_vesting: solana_pubkey::Pubkey) -> &mut Self {
+                        self.team_vesting = Some(team_vesting);
+                    self
+    }
+            /// The token account of the team vesting escrow
+#[inline(always)]
+    pub fn team_vesting_ata(&mut self, team_vesting_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.team_vesting_ata = Some(team_vesting_ata);
+                    self
+    }
+            /// The program derived address of the deposit split config to create (seeds: ['split_config'])
+#[inline(always)]
+    pub fn split_config(&mut self, split_config: solana_pubkey::Pubkey) -> &mut Self {
+                        self.split_config = Some(split_config);
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -284,6 +386,12 @@ impl InitializeAdminBuilder {
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.expect("associated_token_program is not set"),
+                                        global_state: self.global_state.expect("global_state is not set"),
+                                        principal_vault: self.principal_vault.expect("principal_vault is not set"),
+                                        principal_vault_ata: self.principal_vault_ata.expect("principal_vault_ata is not set"),
+                                        team_vesting: self.team_vesting.expect("team_vesting is not set"),
+                                        team_vesting_ata: self.team_vesting_ata.expect("team_vesting_ata is not set"),
+                                        split_config: self.split_config.expect("split_config is not set"),
                       };
     
     accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -347,6 +455,36 @@ impl InitializeAdminBuilder {
       
                     
               pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
+                        /// The program derived address of the global state account to create (seeds: ['global_state'])
+
+      
+                    
+              pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                        /// The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault'])
+
+      
+                    
+              pub principal_vault: &'b solana_account_info::AccountInfo<'a>,
+                        /// The token account of the principal vault
+
+      
+                    
+              pub principal_vault_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting'])
+
+      
+                    
+              pub team_vesting: &'b solana_account_info::AccountInfo<'a>,
+                        /// The token account of the team vesting escrow
+
+      
+                    
+              pub team_vesting_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// The program derived address of the deposit split config to create (seeds: ['split_config'])
+
+      
+                    
+              pub split_config: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `initialize_admin` CPI instruction.
@@ -408,6 +546,36 @@ pub struct InitializeAdminCpi<'a, 'b> {
     
               
           pub associated_token_program: &'b solana_account_info::AccountInfo<'a>,
+                /// The program derived address of the global state account to create (seeds: ['global_state'])
+
+    
+              
+          pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                /// The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault'])
+
+    
+              
+          pub principal_vault: &'b solana_account_info::AccountInfo<'a>,
+                /// The token account of the principal vault
+
+    
+              
+          pub principal_vault_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting'])
+
+    
+              
+          pub team_vesting: &'b solana_account_info::AccountInfo<'a>,
+                /// The token account of the team vesting escrow
+
+    
+              
+          pub team_vesting_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// The program derived address of the deposit split config to create (seeds: ['split_config'])
+
+    
+              
+          pub split_config: &'b solana_account_info::AccountInfo<'a>,
         }
 
 impl<'a, 'b> InitializeAdminCpi<'a, 'b> {
@@ -428,6 +596,12 @@ impl<'a, 'b> InitializeAdminCpi<'a, 'b> {
               system_program: accounts.system_program,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
+              global_state: accounts.global_state,
+              principal_vault: accounts.principal_vault,
+              principal_vault_ata: accounts.principal_vault_ata,
+              team_vesting: accounts.team_vesting,
+              team_vesting_ata: accounts.team_vesting_ata,
+              split_config: accounts.split_config,
                 }
   }
   #[inline(always)]
@@ -450,7 +624,7 @@ impl<'a, 'b> InitializeAdminCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(11+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(17+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.global_admin.key,
             false
@@ -494,6 +668,30 @@ impl<'a, 'b> InitializeAdminCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.associated_token_program.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.global_state.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.principal_vault.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.principal_vault_ata.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.team_vesting.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.team_vesting_ata.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.split_config.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -509,7 +707,7 @@ impl<'a, 'b> InitializeAdminCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(18 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.global_admin.clone());
                         account_infos.push(self.authority.clone());
@@ -522,6 +720,12 @@ impl<'a, 'b> InitializeAdminCpi<'a, 'b> {
                         account_infos.push(self.system_program.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
+                        account_infos.push(self.global_state.clone());
+                        account_infos.push(self.principal_vault.clone());
+                        account_infos.push(self.principal_vault_ata.clone());
+                        account_infos.push(self.team_vesting.clone());
+                        account_infos.push(self.team_vesting_ata.clone());
+                        account_infos.push(self.split_config.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -547,6 +751,12 @@ impl<'a, 'b> InitializeAdminCpi<'a, 'b> {
           ///   8. `[]` system_program
           ///   9. `[]` token_program
           ///   10. `[]` associated_token_program
+                ///   11. `[writable]` global_state
+          ///   12. `[]` principal_vault
+                ///   13. `[writable]` principal_vault_ata
+          ///   14. `[]` team_vesting
+                ///   15. `[writable]` team_vesting_ata
+                ///   16. `[writable]` split_config
 #[derive(Clone, Debug)]
 pub struct InitializeAdminCpiBuilder<'a, 'b> {
   instruction: Box<InitializeAdminCpiBuilderInstruction<'a, 'b>>,
@@ -567,6 +777,12 @@ impl<'a, 'b> InitializeAdminCpiBuilder<'a, 'b> {
               system_program: None,
               token_program: None,
               associated_token_program: None,
+              global_state: None,
+              principal_vault: None,
+              principal_vault_ata: None,
+              team_vesting: None,
+              team_vesting_ata: None,
+              split_config: None,
                                 __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -636,6 +852,42 @@ impl<'a, 'b> InitializeAdminCpiBuilder<'a, 'b> {
     pub fn associated_token_program(&mut self, associated_token_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.associated_token_program = Some(associated_token_program);
                     self
+    }
+      /// The program derived address of the global state account to create (seeds: ['global_state'])
+#[inline(always)]
+    pub fn global_state(&mut self, global_state: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.global_state = Some(global_state);
+                    self
+    }
+      /// The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault'])
+#[inline(always)]
+    pub fn principal_vault(&mut self, principal_vault: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.principal_vault = Some(principal_vault);
+                    self
+    }
+      /// The token account of the principal vault
+#[inline(always)]
+    pub fn principal_vault_ata(&mut self, principal_vault_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.principal_vault_ata = Some(principal_vault_ata);
+                    self
+    }
+      /// The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting'])
+#[inline(always)]
+    pub fn team_vesting(&mut self, team_vesting: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.team_vesting = Some(team_vesting);
+                    self
+    }
+      /// The token account of the team vesting escrow
+#[inline(always)]
+    pub fn team_vesting_ata(&mut self, team_vesting_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.team_vesting_ata = Some(team_vesting_ata);
+                    self
+    }
+      /// The program derived address of the deposit split config to create (seeds: ['split_config'])
+#[inline(always)]
+    pub fn split_config(&mut self, split_config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.split_config = Some(split_config);
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -683,6 +935,18 @@ impl<'a, 'b> InitializeAdminCpiBuilder<'a, 'b> {
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
           associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
+                  
+          global_state: self.instruction.global_state.expect("global_state is not set"),
+                  
+          principal_vault: self.instruction.principal_vault.expect("principal_vault is not set"),
+                  
+          principal_vault_ata: self.instruction.principal_vault_ata.expect("principal_vault_ata is not set"),
+                  
+          team_vesting: self.instruction.team_vesting.expect("team_vesting is not set"),
+                  
+          team_vesting_ata: self.instruction.team_vesting_ata.expect("team_vesting_ata is not set"),
+                  
+          split_config: self.instruction.split_config.expect("split_config is not set"),
                     };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
@@ -702,6 +966,12 @@ struct InitializeAdminCpiBuilderInstruction<'a, 'b> {
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                global_state: Option<&'b solana_account_info::AccountInfo<'a>>,
+                principal_vault: Option<&'b solana_account_info::AccountInfo<'a>>,
+                principal_vault_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                team_vesting: Option<&'b solana_account_info::AccountInfo<'a>>,
+                team_vesting_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                split_config: Option<&'b solana_account_info::AccountInfo<'a>>,
                 /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
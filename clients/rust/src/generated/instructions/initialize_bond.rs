@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::AllowlistProof;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -28,6 +29,11 @@ pub struct InitializeBond {
     
               
           pub user_pda: solana_pubkey::Pubkey,
+                /// The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index])
+
+    
+              
+          pub user_bond_page: solana_pubkey::Pubkey,
                 /// The global admin account
 
     
@@ -43,17 +49,17 @@ pub struct InitializeBond {
     
               
           pub rewards_pool_ata: solana_pubkey::Pubkey,
-                /// The token account of the treasury
+                /// The token account of the principal vault, holding the treasury share until distributed
 
     
               
-          pub treasury_ata: solana_pubkey::Pubkey,
-                /// The token account of the team
+          pub principal_vault_ata: solana_pubkey::Pubkey,
+                /// The token account of the team vesting escrow, receiving the team share
 
     
               
-          pub team_ata: solana_pubkey::Pubkey,
-                /// The native token mint
+          pub team_vesting_ata: solana_pubkey::Pubkey,
+                /// The native token mint, written when a deposit share is burned
 
     
               
@@ -68,16 +74,61 @@ pub struct InitializeBond {
     
               
           pub token_program: solana_pubkey::Pubkey,
+                /// The global state account (seeds: ['global_state'])
+
+    
+              
+          pub global_state: solana_pubkey::Pubkey,
+                /// The deposit split config (seeds: ['split_config'])
+
+    
+              
+          pub split_config: solana_pubkey::Pubkey,
+                /// The bond token mint to create when tokenizing (seeds: ['bond_mint', bond])
+
+    
+              
+          pub bond_mint: Option<solana_pubkey::Pubkey>,
+                /// The mint authority of bond tokens (seeds: ['bond_mint_authority'])
+
+    
+              
+          pub bond_mint_authority: Option<solana_pubkey::Pubkey>,
+                /// The user's associated token account for the bond token
+
+    
+              
+          pub user_bond_token_account: Option<solana_pubkey::Pubkey>,
+                /// The associated token program
+
+    
+              
+          pub associated_token_program: Option<solana_pubkey::Pubkey>,
+                /// The user PDA of the referrer to set if the user has none yet, or the program ID
+
+    
+              
+          pub referrer_pda: Option<solana_pubkey::Pubkey>,
+                /// The referrer's wallet token account, required once the user has a referrer
+
+    
+              
+          pub referrer_ata: Option<solana_pubkey::Pubkey>,
+                /// The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral
+
+    
+              
+          pub referrer_stats: Option<solana_pubkey::Pubkey>,
       }
 
 impl InitializeBond {
-  pub fn instruction(&self) -> solana_instruction::Instruction {
-    self.instruction_with_remaining_accounts(&[])
+  pub fn instruction(&self, args: InitializeBondInstructionArgs) -> solana_instruction::Instruction {
+    self.instruction_with_remaining_accounts(args, &[])
   }
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
-  pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(11+ remaining_accounts.len());
+  pub fn instruction_with_remaining_accounts(&self, args: InitializeBondInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
+    let mut accounts = Vec::with_capacity(21+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.bond,
             false
@@ -89,6 +140,10 @@ impl InitializeBond {
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.user_pda,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.user_bond_page,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.global_admin,
@@ -103,14 +158,14 @@ impl InitializeBond {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            self.treasury_ata,
+            self.principal_vault_ata,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            self.team_ata,
+            self.team_vesting_ata,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             self.native_token_mint,
             false
           ));
@@ -122,8 +177,95 @@ impl InitializeBond {
             self.token_program,
             false
           ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.global_state,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.split_config,
+            false
+          ));
+                                          if let Some(bond_mint) = self.bond_mint {
+              accounts.push(solana_instruction::AccountMeta::new(
+                bond_mint,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(bond_mint_authority) = self.bond_mint_authority {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                bond_mint_authority,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(user_bond_token_account) = self.user_bond_token_account {
+              accounts.push(solana_instruction::AccountMeta::new(
+                user_bond_token_account,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(associated_token_program) = self.associated_token_program {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                associated_token_program,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_pda) = self.referrer_pda {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                referrer_pda,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_ata) = self.referrer_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                referrer_ata,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_stats) = self.referrer_stats {
+              accounts.push(solana_instruction::AccountMeta::new(
+                referrer_stats,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
                       accounts.extend_from_slice(remaining_accounts);
-    let data = borsh::to_vec(&InitializeBondInstructionData::new()).unwrap();
+    let mut data = borsh::to_vec(&InitializeBondInstructionData::new()).unwrap();
+          let mut args = borsh::to_vec(&args).unwrap();
+      data.append(&mut args);
     
     solana_instruction::Instruction {
       program_id: crate::TESTUDO_BONDS_ID,
@@ -137,13 +279,13 @@ impl InitializeBond {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct InitializeBondInstructionData {
             discriminator: u8,
-      }
+                  }
 
 impl InitializeBondInstructionData {
   pub fn new() -> Self {
     Self {
                         discriminator: 2,
-                  }
+                                              }
   }
 }
 
@@ -153,6 +295,14 @@ impl Default for InitializeBondInstructionData {
   }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+ pub struct InitializeBondInstructionArgs {
+                  pub tokenize: bool,
+                pub lock_tier: Option<u8>,
+                pub fixed_term: bool,
+                pub allowlist_proof: Option<AllowlistProof>,
+      }
 
 
 /// Instruction builder for `InitializeBond`.
@@ -162,28 +312,52 @@ impl Default for InitializeBondInstructionData {
                 ///   0. `[writable]` bond
                 ///   1. `[signer]` user_wallet
                 ///   2. `[writable]` user_pda
-          ///   3. `[]` global_admin
-                ///   4. `[writable]` user_wallet_ata
-                ///   5. `[writable]` rewards_pool_ata
-                ///   6. `[writable]` treasury_ata
-                ///   7. `[writable]` team_ata
-          ///   8. `[]` native_token_mint
-                ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
-                ///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   3. `[writable]` user_bond_page
+          ///   4. `[]` global_admin
+                ///   5. `[writable]` user_wallet_ata
+                ///   6. `[writable]` rewards_pool_ata
+                ///   7. `[writable]` principal_vault_ata
+                ///   8. `[writable]` team_vesting_ata
+                ///   9. `[writable]` native_token_mint
+                ///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   12. `[writable]` global_state
+          ///   13. `[]` split_config
+                ///   14. `[writable, optional]` bond_mint
+                ///   15. `[optional]` bond_mint_authority
+                ///   16. `[writable, optional]` user_bond_token_account
+                ///   17. `[optional]` associated_token_program
+                ///   18. `[optional]` referrer_pda
+                ///   19. `[writable, optional]` referrer_ata
+                ///   20. `[writable, optional]` referrer_stats
 #[derive(Clone, Debug, Default)]
 pub struct InitializeBondBuilder {
             bond: Option<solana_pubkey::Pubkey>,
                 user_wallet: Option<solana_pubkey::Pubkey>,
                 user_pda: Option<solana_pubkey::Pubkey>,
+                user_bond_page: Option<solana_pubkey::Pubkey>,
                 global_admin: Option<solana_pubkey::Pubkey>,
                 user_wallet_ata: Option<solana_pubkey::Pubkey>,
                 rewards_pool_ata: Option<solana_pubkey::Pubkey>,
-                treasury_ata: Option<solana_pubkey::Pubkey>,
-                team_ata: Option<solana_pubkey::Pubkey>,
+                principal_vault_ata: Option<solana_pubkey::Pubkey>,
+                team_vesting_ata: Option<solana_pubkey::Pubkey>,
                 native_token_mint: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
-                __remaining_accounts: Vec<solana_instruction::AccountMeta>,
+                global_state: Option<solana_pubkey::Pubkey>,
+                split_config: Option<solana_pubkey::Pubkey>,
+                bond_mint: Option<solana_pubkey::Pubkey>,
+                bond_mint_authority: Option<solana_pubkey::Pubkey>,
+                user_bond_token_account: Option<solana_pubkey::Pubkey>,
+                associated_token_program: Option<solana_pubkey::Pubkey>,
+                referrer_pda: Option<solana_pubkey::Pubkey>,
+                referrer_ata: Option<solana_pubkey::Pubkey>,
+                referrer_stats: Option<solana_pubkey::Pubkey>,
+                        tokenize: Option<bool>,
+                lock_tier: Option<u8>,
+                fixed_term: Option<bool>,
+                allowlist_proof: Option<AllowlistProof>,
+        __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
 impl InitializeBondBuilder {
@@ -207,6 +381,12 @@ impl InitializeBondBuilder {
     pub fn user_pda(&mut self, user_pda: solana_pubkey::Pubkey) -> &mut Self {
                         self.user_pda = Some(user_pda);
                     self
+    }
+            /// The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index])
+#[inline(always)]
+    pub fn user_bond_page(&mut self, user_bond_page: solana_pubkey::Pubkey) -> &mut Self {
+                        self.user_bond_page = Some(user_bond_page);
+                    self
     }
             /// The global admin account
 #[inline(always)]
@@ -226,19 +406,19 @@ impl InitializeBondBuilder {
                         self.rewards_pool_ata = Some(rewards_pool_ata);
                     self
     }
-            /// The token account of the treasury
+            /// The token account of the principal vault, holding the treasury share until distributed
 #[inline(always)]
-    pub fn treasury_ata(&mut self, treasury_ata: solana_pubkey::Pubkey) -> &mut Self {
-                        self.treasury_ata = Some(treasury_ata);
+    pub fn principal_vault_ata(&mut self, principal_vault_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.principal_vault_ata = Some(principal_vault_ata);
                     self
     }
-            /// The token account of the team
+            /// The token account of the team vesting escrow, receiving the team share
 #[inline(always)]
-    pub fn team_ata(&mut self, team_ata: solana_pubkey::Pubkey) -> &mut Self {
-                        self.team_ata = Some(team_ata);
+    pub fn team_vesting_ata(&mut self, team_vesting_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.team_vesting_ata = Some(team_vesting_ata);
                     self
     }
-            /// The native token mint
+            /// The native token mint, written when a deposit share is burned
 #[inline(always)]
     pub fn native_token_mint(&mut self, native_token_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.native_token_mint = Some(native_token_mint);
@@ -258,7 +438,90 @@ impl InitializeBondBuilder {
                         self.token_program = Some(token_program);
                     self
     }
-            /// Add an additional account to the instruction.
+            /// The global state account (seeds: ['global_state'])
+#[inline(always)]
+    pub fn global_state(&mut self, global_state: solana_pubkey::Pubkey) -> &mut Self {
+                        self.global_state = Some(global_state);
+                    self
+    }
+            /// The deposit split config (seeds: ['split_config'])
+#[inline(always)]
+    pub fn split_config(&mut self, split_config: solana_pubkey::Pubkey) -> &mut Self {
+                        self.split_config = Some(split_config);
+                    self
+    }
+            /// `[optional account]`
+/// The bond token mint to create when tokenizing (seeds: ['bond_mint', bond])
+#[inline(always)]
+    pub fn bond_mint(&mut self, bond_mint: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.bond_mint = bond_mint;
+                    self
+    }
+            /// `[optional account]`
+/// The mint authority of bond tokens (seeds: ['bond_mint_authority'])
+#[inline(always)]
+    pub fn bond_mint_authority(&mut self, bond_mint_authority: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.bond_mint_authority = bond_mint_authority;
+                    self
+    }
+            /// `[optional account]`
+/// The user's associated token account for the bond token
+#[inline(always)]
+    pub fn user_bond_token_account(&mut self, user_bond_token_account: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.user_bond_token_account = user_bond_token_account;
+                    self
+    }
+            /// `[optional account]`
+/// The associated token program
+#[inline(always)]
+    pub fn associated_token_program(&mut self, associated_token_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.associated_token_program = associated_token_program;
+                    self
+    }
+            /// `[optional account]`
+/// The user PDA of the referrer to set if the user has none yet, or the program ID
+#[inline(always)]
+    pub fn referrer_pda(&mut self, referrer_pda: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.referrer_pda = referrer_pda;
+                    self
+    }
+            /// `[optional account]`
+/// The referrer's wallet token account, required once the user has a referrer
+#[inline(always)]
+    pub fn referrer_ata(&mut self, referrer_ata: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.referrer_ata = referrer_ata;
+                    self
+    }
+            /// `[optional account]`
+/// The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral
+#[inline(always)]
+    pub fn referrer_stats(&mut self, referrer_stats: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.referrer_stats = referrer_stats;
+                    self
+    }
+                    #[inline(always)]
+      pub fn tokenize(&mut self, tokenize: bool) -> &mut Self {
+        self.tokenize = Some(tokenize);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn lock_tier(&mut self, lock_tier: u8) -> &mut Self {
+        self.lock_tier = Some(lock_tier);
+        self
+      }
+                #[inline(always)]
+      pub fn fixed_term(&mut self, fixed_term: bool) -> &mut Self {
+        self.fixed_term = Some(fixed_term);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn allowlist_proof(&mut self, allowlist_proof: AllowlistProof) -> &mut Self {
+        self.allowlist_proof = Some(allowlist_proof);
+        self
+      }
+        /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
     self.__remaining_accounts.push(account);
@@ -276,17 +539,33 @@ impl InitializeBondBuilder {
                               bond: self.bond.expect("bond is not set"),
                                         user_wallet: self.user_wallet.expect("user_wallet is not set"),
                                         user_pda: self.user_pda.expect("user_pda is not set"),
+                                        user_bond_page: self.user_bond_page.expect("user_bond_page is not set"),
                                         global_admin: self.global_admin.expect("global_admin is not set"),
                                         user_wallet_ata: self.user_wallet_ata.expect("user_wallet_ata is not set"),
                                         rewards_pool_ata: self.rewards_pool_ata.expect("rewards_pool_ata is not set"),
-                                        treasury_ata: self.treasury_ata.expect("treasury_ata is not set"),
-                                        team_ata: self.team_ata.expect("team_ata is not set"),
+                                        principal_vault_ata: self.principal_vault_ata.expect("principal_vault_ata is not set"),
+                                        team_vesting_ata: self.team_vesting_ata.expect("team_vesting_ata is not set"),
                                         native_token_mint: self.native_token_mint.expect("native_token_mint is not set"),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
+                                        global_state: self.global_state.expect("global_state is not set"),
+                                        split_config: self.split_config.expect("split_config is not set"),
+                                        bond_mint: self.bond_mint,
+                                        bond_mint_authority: self.bond_mint_authority,
+                                        user_bond_token_account: self.user_bond_token_account,
+                                        associated_token_program: self.associated_token_program,
+                                        referrer_pda: self.referrer_pda,
+                                        referrer_ata: self.referrer_ata,
+                                        referrer_stats: self.referrer_stats,
                       };
+          let args = InitializeBondInstructionArgs {
+                                                              tokenize: self.tokenize.clone().expect("tokenize is not set"),
+                                                                  lock_tier: self.lock_tier.clone(),
+                                                                  fixed_term: self.fixed_term.clone().expect("fixed_term is not set"),
+                                                                  allowlist_proof: self.allowlist_proof.clone(),
+                                    };
     
-    accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
   }
 }
 
@@ -307,6 +586,11 @@ impl InitializeBondBuilder {
       
                     
               pub user_pda: &'b solana_account_info::AccountInfo<'a>,
+                        /// The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index])
+
+      
+                    
+              pub user_bond_page: &'b solana_account_info::AccountInfo<'a>,
                         /// The global admin account
 
       
@@ -322,17 +606,17 @@ impl InitializeBondBuilder {
       
                     
               pub rewards_pool_ata: &'b solana_account_info::AccountInfo<'a>,
-                        /// The token account of the treasury
+                        /// The token account of the principal vault, holding the treasury share until distributed
 
       
                     
-              pub treasury_ata: &'b solana_account_info::AccountInfo<'a>,
-                        /// The token account of the team
+              pub principal_vault_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// The token account of the team vesting escrow, receiving the team share
 
       
                     
-              pub team_ata: &'b solana_account_info::AccountInfo<'a>,
-                        /// The native token mint
+              pub team_vesting_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// The native token mint, written when a deposit share is burned
 
       
                     
@@ -347,6 +631,51 @@ impl InitializeBondBuilder {
       
                     
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
+                        /// The global state account (seeds: ['global_state'])
+
+      
+                    
+              pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                        /// The deposit split config (seeds: ['split_config'])
+
+      
+                    
+              pub split_config: &'b solana_account_info::AccountInfo<'a>,
+                        /// The bond token mint to create when tokenizing (seeds: ['bond_mint', bond])
+
+      
+                    
+              pub bond_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The mint authority of bond tokens (seeds: ['bond_mint_authority'])
+
+      
+                    
+              pub bond_mint_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The user's associated token account for the bond token
+
+      
+                    
+              pub user_bond_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The associated token program
+
+      
+                    
+              pub associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The user PDA of the referrer to set if the user has none yet, or the program ID
+
+      
+                    
+              pub referrer_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The referrer's wallet token account, required once the user has a referrer
+
+      
+                    
+              pub referrer_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral
+
+      
+                    
+              pub referrer_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
             }
 
 /// `initialize_bond` CPI instruction.
@@ -368,6 +697,11 @@ pub struct InitializeBondCpi<'a, 'b> {
     
               
           pub user_pda: &'b solana_account_info::AccountInfo<'a>,
+                /// The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index])
+
+    
+              
+          pub user_bond_page: &'b solana_account_info::AccountInfo<'a>,
                 /// The global admin account
 
     
@@ -383,17 +717,17 @@ pub struct InitializeBondCpi<'a, 'b> {
     
               
           pub rewards_pool_ata: &'b solana_account_info::AccountInfo<'a>,
-                /// The token account of the treasury
+                /// The token account of the principal vault, holding the treasury share until distributed
 
     
               
-          pub treasury_ata: &'b solana_account_info::AccountInfo<'a>,
-                /// The token account of the team
+          pub principal_vault_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// The token account of the team vesting escrow, receiving the team share
 
     
               
-          pub team_ata: &'b solana_account_info::AccountInfo<'a>,
-                /// The native token mint
+          pub team_vesting_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// The native token mint, written when a deposit share is burned
 
     
               
@@ -408,27 +742,86 @@ pub struct InitializeBondCpi<'a, 'b> {
     
               
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
-        }
+                /// The global state account (seeds: ['global_state'])
+
+    
+              
+          pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                /// The deposit split config (seeds: ['split_config'])
+
+    
+              
+          pub split_config: &'b solana_account_info::AccountInfo<'a>,
+                /// The bond token mint to create when tokenizing (seeds: ['bond_mint', bond])
+
+    
+              
+          pub bond_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The mint authority of bond tokens (seeds: ['bond_mint_authority'])
+
+    
+              
+          pub bond_mint_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The user's associated token account for the bond token
+
+    
+              
+          pub user_bond_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The associated token program
+
+    
+              
+          pub associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The user PDA of the referrer to set if the user has none yet, or the program ID
+
+    
+              
+          pub referrer_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The referrer's wallet token account, required once the user has a referrer
+
+    
+              
+          pub referrer_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral
+
+    
+              
+          pub referrer_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+            /// The arguments for the instruction.
+    pub __args: InitializeBondInstructionArgs,
+  }
 
 impl<'a, 'b> InitializeBondCpi<'a, 'b> {
   pub fn new(
     program: &'b solana_account_info::AccountInfo<'a>,
           accounts: InitializeBondCpiAccounts<'a, 'b>,
-          ) -> Self {
+              args: InitializeBondInstructionArgs,
+      ) -> Self {
     Self {
       __program: program,
               bond: accounts.bond,
               user_wallet: accounts.user_wallet,
               user_pda: accounts.user_pda,
+              user_bond_page: accounts.user_bond_page,
               global_admin: accounts.global_admin,
               user_wallet_ata: accounts.user_wallet_ata,
               rewards_pool_ata: accounts.rewards_pool_ata,
-              treasury_ata: accounts.treasury_ata,
-              team_ata: accounts.team_ata,
+              principal_vault_ata: accounts.principal_vault_ata,
+              team_vesting_ata: accounts.team_vesting_ata,
               native_token_mint: accounts.native_token_mint,
               system_program: accounts.system_program,
               token_program: accounts.token_program,
-                }
+              global_state: accounts.global_state,
+              split_config: accounts.split_config,
+              bond_mint: accounts.bond_mint,
+              bond_mint_authority: accounts.bond_mint_authority,
+              user_bond_token_account: accounts.user_bond_token_account,
+              associated_token_program: accounts.associated_token_program,
+              referrer_pda: accounts.referrer_pda,
+              referrer_ata: accounts.referrer_ata,
+              referrer_stats: accounts.referrer_stats,
+                    __args: args,
+          }
   }
   #[inline(always)]
   pub fn invoke(&self) -> solana_program_entrypoint::ProgramResult {
@@ -450,7 +843,7 @@ impl<'a, 'b> InitializeBondCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(11+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(21+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.bond.key,
             false
@@ -462,6 +855,10 @@ impl<'a, 'b> InitializeBondCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.user_pda.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.user_bond_page.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.global_admin.key,
@@ -476,14 +873,14 @@ impl<'a, 'b> InitializeBondCpi<'a, 'b> {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            *self.treasury_ata.key,
+            *self.principal_vault_ata.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            *self.team_ata.key,
+            *self.team_vesting_ata.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             *self.native_token_mint.key,
             false
           ));
@@ -495,6 +892,91 @@ impl<'a, 'b> InitializeBondCpi<'a, 'b> {
             *self.token_program.key,
             false
           ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.global_state.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.split_config.key,
+            false
+          ));
+                                          if let Some(bond_mint) = self.bond_mint {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *bond_mint.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(bond_mint_authority) = self.bond_mint_authority {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *bond_mint_authority.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(user_bond_token_account) = self.user_bond_token_account {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *user_bond_token_account.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(associated_token_program) = self.associated_token_program {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *associated_token_program.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_pda) = self.referrer_pda {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *referrer_pda.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_ata) = self.referrer_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *referrer_ata.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_stats) = self.referrer_stats {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *referrer_stats.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
           pubkey: *remaining_account.0.key,
@@ -502,26 +984,52 @@ impl<'a, 'b> InitializeBondCpi<'a, 'b> {
           is_writable: remaining_account.2,
       })
     });
-    let data = borsh::to_vec(&InitializeBondInstructionData::new()).unwrap();
+    let mut data = borsh::to_vec(&InitializeBondInstructionData::new()).unwrap();
+          let mut args = borsh::to_vec(&self.__args).unwrap();
+      data.append(&mut args);
     
     let instruction = solana_instruction::Instruction {
       program_id: crate::TESTUDO_BONDS_ID,
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(22 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.bond.clone());
                         account_infos.push(self.user_wallet.clone());
                         account_infos.push(self.user_pda.clone());
+                        account_infos.push(self.user_bond_page.clone());
                         account_infos.push(self.global_admin.clone());
                         account_infos.push(self.user_wallet_ata.clone());
                         account_infos.push(self.rewards_pool_ata.clone());
-                        account_infos.push(self.treasury_ata.clone());
-                        account_infos.push(self.team_ata.clone());
+                        account_infos.push(self.principal_vault_ata.clone());
+                        account_infos.push(self.team_vesting_ata.clone());
                         account_infos.push(self.native_token_mint.clone());
                         account_infos.push(self.system_program.clone());
                         account_infos.push(self.token_program.clone());
+                        account_infos.push(self.global_state.clone());
+                        account_infos.push(self.split_config.clone());
+                        if let Some(bond_mint) = self.bond_mint {
+        account_infos.push(bond_mint.clone());
+      }
+                        if let Some(bond_mint_authority) = self.bond_mint_authority {
+        account_infos.push(bond_mint_authority.clone());
+      }
+                        if let Some(user_bond_token_account) = self.user_bond_token_account {
+        account_infos.push(user_bond_token_account.clone());
+      }
+                        if let Some(associated_token_program) = self.associated_token_program {
+        account_infos.push(associated_token_program.clone());
+      }
+                        if let Some(referrer_pda) = self.referrer_pda {
+        account_infos.push(referrer_pda.clone());
+      }
+                        if let Some(referrer_ata) = self.referrer_ata {
+        account_infos.push(referrer_ata.clone());
+      }
+                        if let Some(referrer_stats) = self.referrer_stats {
+        account_infos.push(referrer_stats.clone());
+      }
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -539,14 +1047,24 @@ impl<'a, 'b> InitializeBondCpi<'a, 'b> {
                 ///   0. `[writable]` bond
                 ///   1. `[signer]` user_wallet
                 ///   2. `[writable]` user_pda
-          ///   3. `[]` global_admin
-                ///   4. `[writable]` user_wallet_ata
-                ///   5. `[writable]` rewards_pool_ata
-                ///   6. `[writable]` treasury_ata
-                ///   7. `[writable]` team_ata
-          ///   8. `[]` native_token_mint
-          ///   9. `[]` system_program
-          ///   10. `[]` token_program
+                ///   3. `[writable]` user_bond_page
+          ///   4. `[]` global_admin
+                ///   5. `[writable]` user_wallet_ata
+                ///   6. `[writable]` rewards_pool_ata
+                ///   7. `[writable]` principal_vault_ata
+                ///   8. `[writable]` team_vesting_ata
+                ///   9. `[writable]` native_token_mint
+          ///   10. `[]` system_program
+          ///   11. `[]` token_program
+                ///   12. `[writable]` global_state
+          ///   13. `[]` split_config
+                ///   14. `[writable, optional]` bond_mint
+                ///   15. `[optional]` bond_mint_authority
+                ///   16. `[writable, optional]` user_bond_token_account
+                ///   17. `[optional]` associated_token_program
+                ///   18. `[optional]` referrer_pda
+                ///   19. `[writable, optional]` referrer_ata
+                ///   20. `[writable, optional]` referrer_stats
 #[derive(Clone, Debug)]
 pub struct InitializeBondCpiBuilder<'a, 'b> {
   instruction: Box<InitializeBondCpiBuilderInstruction<'a, 'b>>,
@@ -559,15 +1077,29 @@ impl<'a, 'b> InitializeBondCpiBuilder<'a, 'b> {
               bond: None,
               user_wallet: None,
               user_pda: None,
+              user_bond_page: None,
               global_admin: None,
               user_wallet_ata: None,
               rewards_pool_ata: None,
-              treasury_ata: None,
-              team_ata: None,
+              principal_vault_ata: None,
+              team_vesting_ata: None,
               native_token_mint: None,
               system_program: None,
               token_program: None,
-                                __remaining_accounts: Vec::new(),
+              global_state: None,
+              split_config: None,
+              bond_mint: None,
+              bond_mint_authority: None,
+              user_bond_token_account: None,
+              associated_token_program: None,
+              referrer_pda: None,
+              referrer_ata: None,
+              referrer_stats: None,
+                                            tokenize: None,
+                                lock_tier: None,
+                                fixed_term: None,
+                                allowlist_proof: None,
+                    __remaining_accounts: Vec::new(),
     });
     Self { instruction }
   }
@@ -588,6 +1120,12 @@ impl<'a, 'b> InitializeBondCpiBuilder<'a, 'b> {
     pub fn user_pda(&mut self, user_pda: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.user_pda = Some(user_pda);
                     self
+    }
+      /// The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index])
+#[inline(always)]
+    pub fn user_bond_page(&mut self, user_bond_page: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.user_bond_page = Some(user_bond_page);
+                    self
     }
       /// The global admin account
 #[inline(always)]
@@ -607,19 +1145,19 @@ impl<'a, 'b> InitializeBondCpiBuilder<'a, 'b> {
                         self.instruction.rewards_pool_ata = Some(rewards_pool_ata);
                     self
     }
-      /// The token account of the treasury
+      /// The token account of the principal vault, holding the treasury share until distributed
 #[inline(always)]
-    pub fn treasury_ata(&mut self, treasury_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.treasury_ata = Some(treasury_ata);
+    pub fn principal_vault_ata(&mut self, principal_vault_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.principal_vault_ata = Some(principal_vault_ata);
                     self
     }
-      /// The token account of the team
+      /// The token account of the team vesting escrow, receiving the team share
 #[inline(always)]
-    pub fn team_ata(&mut self, team_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.team_ata = Some(team_ata);
+    pub fn team_vesting_ata(&mut self, team_vesting_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.team_vesting_ata = Some(team_vesting_ata);
                     self
     }
-      /// The native token mint
+      /// The native token mint, written when a deposit share is burned
 #[inline(always)]
     pub fn native_token_mint(&mut self, native_token_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.native_token_mint = Some(native_token_mint);
@@ -637,7 +1175,90 @@ impl<'a, 'b> InitializeBondCpiBuilder<'a, 'b> {
                         self.instruction.token_program = Some(token_program);
                     self
     }
-            /// Add an additional account to the instruction.
+      /// The global state account (seeds: ['global_state'])
+#[inline(always)]
+    pub fn global_state(&mut self, global_state: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.global_state = Some(global_state);
+                    self
+    }
+      /// The deposit split config (seeds: ['split_config'])
+#[inline(always)]
+    pub fn split_config(&mut self, split_config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.split_config = Some(split_config);
+                    self
+    }
+      /// `[optional account]`
+/// The bond token mint to create when tokenizing (seeds: ['bond_mint', bond])
+#[inline(always)]
+    pub fn bond_mint(&mut self, bond_mint: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.bond_mint = bond_mint;
+                    self
+    }
+      /// `[optional account]`
+/// The mint authority of bond tokens (seeds: ['bond_mint_authority'])
+#[inline(always)]
+    pub fn bond_mint_authority(&mut self, bond_mint_authority: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.bond_mint_authority = bond_mint_authority;
+                    self
+    }
+      /// `[optional account]`
+/// The user's associated token account for the bond token
+#[inline(always)]
+    pub fn user_bond_token_account(&mut self, user_bond_token_account: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.user_bond_token_account = user_bond_token_account;
+                    self
+    }
+      /// `[optional account]`
+/// The associated token program
+#[inline(always)]
+    pub fn associated_token_program(&mut self, associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.associated_token_program = associated_token_program;
+                    self
+    }
+      /// `[optional account]`
+/// The user PDA of the referrer to set if the user has none yet, or the program ID
+#[inline(always)]
+    pub fn referrer_pda(&mut self, referrer_pda: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.referrer_pda = referrer_pda;
+                    self
+    }
+      /// `[optional account]`
+/// The referrer's wallet token account, required once the user has a referrer
+#[inline(always)]
+    pub fn referrer_ata(&mut self, referrer_ata: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.referrer_ata = referrer_ata;
+                    self
+    }
+      /// `[optional account]`
+/// The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral
+#[inline(always)]
+    pub fn referrer_stats(&mut self, referrer_stats: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.referrer_stats = referrer_stats;
+                    self
+    }
+                    #[inline(always)]
+      pub fn tokenize(&mut self, tokenize: bool) -> &mut Self {
+        self.instruction.tokenize = Some(tokenize);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn lock_tier(&mut self, lock_tier: u8) -> &mut Self {
+        self.instruction.lock_tier = Some(lock_tier);
+        self
+      }
+                #[inline(always)]
+      pub fn fixed_term(&mut self, fixed_term: bool) -> &mut Self {
+        self.instruction.fixed_term = Some(fixed_term);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn allowlist_proof(&mut self, allowlist_proof: AllowlistProof) -> &mut Self {
+        self.instruction.allowlist_proof = Some(allowlist_proof);
+        self
+      }
+        /// Add an additional account to the instruction.
   #[inline(always)]
   pub fn add_remaining_account(&mut self, account: &'b solana_account_info::AccountInfo<'a>, is_writable: bool, is_signer: bool) -> &mut Self {
     self.instruction.__remaining_accounts.push((account, is_writable, is_signer));
@@ -659,6 +1280,12 @@ impl<'a, 'b> InitializeBondCpiBuilder<'a, 'b> {
   #[allow(clippy::clone_on_copy)]
   #[allow(clippy::vec_init_then_push)]
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_entrypoint::ProgramResult {
+          let args = InitializeBondInstructionArgs {
+                                                              tokenize: self.instruction.tokenize.clone().expect("tokenize is not set"),
+                                                                  lock_tier: self.instruction.lock_tier.clone(),
+                                                                  fixed_term: self.instruction.fixed_term.clone().expect("fixed_term is not set"),
+                                                                  allowlist_proof: self.instruction.allowlist_proof.clone(),
+                                    };
         let instruction = InitializeBondCpi {
         __program: self.instruction.__program,
                   
@@ -668,22 +1295,43 @@ impl<'a, 'b> InitializeBondCpiBuilder<'a, 'b> {
                   
           user_pda: self.instruction.user_pda.expect("user_pda is not set"),
                   
+          user_bond_page: self.instruction.user_bond_page.expect("user_bond_page is not set"),
+                  
           global_admin: self.instruction.global_admin.expect("global_admin is not set"),
                   
           user_wallet_ata: self.instruction.user_wallet_ata.expect("user_wallet_ata is not set"),
                   
           rewards_pool_ata: self.instruction.rewards_pool_ata.expect("rewards_pool_ata is not set"),
                   
-          treasury_ata: self.instruction.treasury_ata.expect("treasury_ata is not set"),
+          principal_vault_ata: self.instruction.principal_vault_ata.expect("principal_vault_ata is not set"),
                   
-          team_ata: self.instruction.team_ata.expect("team_ata is not set"),
+          team_vesting_ata: self.instruction.team_vesting_ata.expect("team_vesting_ata is not set"),
                   
           native_token_mint: self.instruction.native_token_mint.expect("native_token_mint is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
-                    };
+                  
+          global_state: self.instruction.global_state.expect("global_state is not set"),
+                  
+          split_config: self.instruction.split_config.expect("split_config is not set"),
+                  
+          bond_mint: self.instruction.bond_mint,
+                  
+          bond_mint_authority: self.instruction.bond_mint_authority,
+                  
+          user_bond_token_account: self.instruction.user_bond_token_account,
+                  
+          associated_token_program: self.instruction.associated_token_program,
+                  
+          referrer_pda: self.instruction.referrer_pda,
+                  
+          referrer_ata: self.instruction.referrer_ata,
+                  
+          referrer_stats: self.instruction.referrer_stats,
+                          __args: args,
+            };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
 }
@@ -694,15 +1342,29 @@ struct InitializeBondCpiBuilderInstruction<'a, 'b> {
             bond: Option<&'b solana_account_info::AccountInfo<'a>>,
                 user_wallet: Option<&'b solana_account_info::AccountInfo<'a>>,
                 user_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                user_bond_page: Option<&'b solana_account_info::AccountInfo<'a>>,
                 global_admin: Option<&'b solana_account_info::AccountInfo<'a>>,
                 user_wallet_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 rewards_pool_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
-                treasury_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
-                team_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                principal_vault_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                team_vesting_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 native_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-                /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+                global_state: Option<&'b solana_account_info::AccountInfo<'a>>,
+                split_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+                bond_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
+                bond_mint_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                user_bond_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                referrer_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                referrer_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                referrer_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        tokenize: Option<bool>,
+                lock_tier: Option<u8>,
+                fixed_term: Option<bool>,
+                allowlist_proof: Option<AllowlistProof>,
+        /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
 
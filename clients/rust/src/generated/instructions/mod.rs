@@ -5,15 +5,43 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#attach_reward_stream;
+  pub(crate) mod r#buy_bond;
+  pub(crate) mod r#cancel_listing;
   pub(crate) mod r#create_user;
+  pub(crate) mod r#detach_reward_stream;
+  pub(crate) mod r#distribute_vault;
+  pub(crate) mod r#grant_bond;
   pub(crate) mod r#initialize_admin;
   pub(crate) mod r#initialize_bond;
+  pub(crate) mod r#list_bond;
+  pub(crate) mod r#mature_bond;
+  pub(crate) mod r#merge_bonds;
   pub(crate) mod r#process_claim;
+  pub(crate) mod r#redeem_bond;
+  pub(crate) mod r#release_vested;
+  pub(crate) mod r#split_bond;
+  pub(crate) mod r#transfer_bond;
   pub(crate) mod r#update_admin;
+  pub(crate) mod r#update_split_config;
 
+  pub use self::r#attach_reward_stream::*;
+  pub use self::r#buy_bond::*;
+  pub use self::r#cancel_listing::*;
   pub use self::r#create_user::*;
+  pub use self::r#detach_reward_stream::*;
+  pub use self::r#distribute_vault::*;
+  pub use self::r#grant_bond::*;
   pub use self::r#initialize_admin::*;
   pub use self::r#initialize_bond::*;
+  pub use self::r#list_bond::*;
+  pub use self::r#mature_bond::*;
+  pub use self::r#merge_bonds::*;
   pub use self::r#process_claim::*;
+  pub use self::r#redeem_bond::*;
+  pub use self::r#release_vested::*;
+  pub use self::r#split_bond::*;
+  pub use self::r#transfer_bond::*;
   pub use self::r#update_admin::*;
+  pub use self::r#update_split_config::*;
 
@@ -18,7 +18,7 @@ pub struct ProcessClaim {
     
               
           pub bond: solana_pubkey::Pubkey,
-                /// The wallet of the user
+                /// The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it
 
     
               
@@ -28,6 +28,11 @@ pub struct ProcessClaim {
     
               
           pub user_pda: solana_pubkey::Pubkey,
+                /// The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])
+
+    
+              
+          pub user_bond_page: solana_pubkey::Pubkey,
                 /// The user's wallet token account
 
     
@@ -48,17 +53,27 @@ pub struct ProcessClaim {
     
               
           pub treasury_ata: solana_pubkey::Pubkey,
-                /// The token account of the team
+                /// The token account of the principal vault, receiving the treasury share of an auto-compounded bond
+
+    
+              
+          pub principal_vault_ata: solana_pubkey::Pubkey,
+                /// The token account of the team vesting escrow, receiving the team share of an auto-compounded bond
 
     
               
-          pub team_ata: solana_pubkey::Pubkey,
+          pub team_vesting_ata: solana_pubkey::Pubkey,
                 /// The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index])
 
     
               
           pub new_bond_pda: solana_pubkey::Pubkey,
-                /// The native token mint
+                /// The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index])
+
+    
+              
+          pub new_bond_page: solana_pubkey::Pubkey,
+                /// The native token mint, written when penalties are burned or rewards minted
 
     
               
@@ -78,6 +93,51 @@ pub struct ProcessClaim {
     
               
           pub system_program: solana_pubkey::Pubkey,
+                /// The global state account (seeds: ['global_state'])
+
+    
+              
+          pub global_state: solana_pubkey::Pubkey,
+                /// The deposit split config (seeds: ['split_config'])
+
+    
+              
+          pub split_config: solana_pubkey::Pubkey,
+                /// The reward stream list (seeds: ['reward_streams'])
+
+    
+              
+          pub reward_streams: solana_pubkey::Pubkey,
+                /// The signer's token account holding the bond token, required for tokenized bonds
+
+    
+              
+          pub bond_token_account: Option<solana_pubkey::Pubkey>,
+                /// The wallet token account of the bond owner's referrer, required when the owner has one
+
+    
+              
+          pub referrer_ata: Option<solana_pubkey::Pubkey>,
+                /// The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet])
+
+    
+              
+          pub referrer_stats: Option<solana_pubkey::Pubkey>,
+                /// The signer's user pda, required when the signer holds a tokenized bond it does not own
+
+    
+              
+          pub holder_user_pda: Option<solana_pubkey::Pubkey>,
+                /// The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index])
+
+    
+              
+          pub holder_bond_page: Option<solana_pubkey::Pubkey>,
+                /// The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied
+
+    
+              
+          pub owner_wallet: Option<solana_pubkey::Pubkey>,
       }
 
 impl ProcessClaim {
@@ -87,18 +147,22 @@ impl ProcessClaim {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, args: ProcessClaimInstructionArgs, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(13+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(25+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.bond,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             self.user_wallet,
             true
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.user_pda,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.user_bond_page,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.user_wallet_ata,
@@ -117,14 +181,22 @@ impl ProcessClaim {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            self.team_ata,
+            self.principal_vault_ata,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.team_vesting_ata,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.new_bond_pda,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.new_bond_page,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
             self.native_token_mint,
             false
           ));
@@ -140,6 +212,84 @@ impl ProcessClaim {
             self.system_program,
             false
           ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.global_state,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.split_config,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.reward_streams,
+            false
+          ));
+                                          if let Some(bond_token_account) = self.bond_token_account {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                bond_token_account,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_ata) = self.referrer_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                referrer_ata,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_stats) = self.referrer_stats {
+              accounts.push(solana_instruction::AccountMeta::new(
+                referrer_stats,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(holder_user_pda) = self.holder_user_pda {
+              accounts.push(solana_instruction::AccountMeta::new(
+                holder_user_pda,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(holder_bond_page) = self.holder_bond_page {
+              accounts.push(solana_instruction::AccountMeta::new(
+                holder_bond_page,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(owner_wallet) = self.owner_wallet {
+              accounts.push(solana_instruction::AccountMeta::new(
+                owner_wallet,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
                       accounts.extend_from_slice(remaining_accounts);
     let mut data = borsh::to_vec(&ProcessClaimInstructionData::new()).unwrap();
           let mut args = borsh::to_vec(&args).unwrap();
@@ -176,7 +326,7 @@ impl Default for ProcessClaimInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct ProcessClaimInstructionArgs {
-                  pub bond_index: u8,
+                  pub bond_index: u16,
                 pub auto_compound: bool,
       }
 
@@ -186,34 +336,58 @@ impl Default for ProcessClaimInstructionData {
 /// ### Accounts:
 ///
                 ///   0. `[writable]` bond
-                ///   1. `[signer]` user_wallet
+                ///   1. `[writable, signer]` user_wallet
                 ///   2. `[writable]` user_pda
-                ///   3. `[writable]` user_wallet_ata
-          ///   4. `[]` global_admin
-                ///   5. `[writable]` rewards_pool_ata
-                ///   6. `[writable]` treasury_ata
-                ///   7. `[writable]` team_ata
-                ///   8. `[writable]` new_bond_pda
-          ///   9. `[]` native_token_mint
-                ///   10. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-          ///   11. `[]` associated_token_program
-                ///   12. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   3. `[writable]` user_bond_page
+                ///   4. `[writable]` user_wallet_ata
+          ///   5. `[]` global_admin
+                ///   6. `[writable]` rewards_pool_ata
+                ///   7. `[writable]` treasury_ata
+                ///   8. `[writable]` principal_vault_ata
+                ///   9. `[writable]` team_vesting_ata
+                ///   10. `[writable]` new_bond_pda
+                ///   11. `[writable]` new_bond_page
+                ///   12. `[writable]` native_token_mint
+                ///   13. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+          ///   14. `[]` associated_token_program
+                ///   15. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   16. `[writable]` global_state
+          ///   17. `[]` split_config
+          ///   18. `[]` reward_streams
+                ///   19. `[optional]` bond_token_account
+                ///   20. `[writable, optional]` referrer_ata
+                ///   21. `[writable, optional]` referrer_stats
+                ///   22. `[writable, optional]` holder_user_pda
+                ///   23. `[writable, optional]` holder_bond_page
+                ///   24. `[writable, optional]` owner_wallet
 #[derive(Clone, Debug, Default)]
 pub struct ProcessClaimBuilder {
             bond: Option<solana_pubkey::Pubkey>,
                 user_wallet: Option<solana_pubkey::Pubkey>,
                 user_pda: Option<solana_pubkey::Pubkey>,
+                user_bond_page: Option<solana_pubkey::Pubkey>,
                 user_wallet_ata: Option<solana_pubkey::Pubkey>,
                 global_admin: Option<solana_pubkey::Pubkey>,
                 rewards_pool_ata: Option<solana_pubkey::Pubkey>,
                 treasury_ata: Option<solana_pubkey::Pubkey>,
-                team_ata: Option<solana_pubkey::Pubkey>,
+                principal_vault_ata: Option<solana_pubkey::Pubkey>,
+                team_vesting_ata: Option<solana_pubkey::Pubkey>,
                 new_bond_pda: Option<solana_pubkey::Pubkey>,
+                new_bond_page: Option<solana_pubkey::Pubkey>,
                 native_token_mint: Option<solana_pubkey::Pubkey>,
                 token_program: Option<solana_pubkey::Pubkey>,
                 associated_token_program: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
-                        bond_index: Option<u8>,
+                global_state: Option<solana_pubkey::Pubkey>,
+                split_config: Option<solana_pubkey::Pubkey>,
+                reward_streams: Option<solana_pubkey::Pubkey>,
+                bond_token_account: Option<solana_pubkey::Pubkey>,
+                referrer_ata: Option<solana_pubkey::Pubkey>,
+                referrer_stats: Option<solana_pubkey::Pubkey>,
+                holder_user_pda: Option<solana_pubkey::Pubkey>,
+                holder_bond_page: Option<solana_pubkey::Pubkey>,
+                owner_wallet: Option<solana_pubkey::Pubkey>,
+                        bond_index: Option<u16>,
                 auto_compound: Option<bool>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
@@ -228,7 +402,7 @@ impl ProcessClaimBuilder {
                         self.bond = Some(bond);
                     self
     }
-            /// The wallet of the user
+            /// The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it
 #[inline(always)]
     pub fn user_wallet(&mut self, user_wallet: solana_pubkey::Pubkey) -> &mut Self {
                         self.user_wallet = Some(user_wallet);
@@ -239,6 +413,12 @@ impl ProcessClaimBuilder {
     pub fn user_pda(&mut self, user_pda: solana_pubkey::Pubkey) -> &mut Self {
                         self.user_pda = Some(user_pda);
                     self
+    }
+            /// The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])
+#[inline(always)]
+    pub fn user_bond_page(&mut self, user_bond_page: solana_pubkey::Pubkey) -> &mut Self {
+                        self.user_bond_page = Some(user_bond_page);
+                    self
     }
             /// The user's wallet token account
 #[inline(always)]
@@ -264,10 +444,16 @@ impl ProcessClaimBuilder {
                         self.treasury_ata = Some(treasury_ata);
                     self
     }
-            /// The token account of the team
+            /// The token account of the principal vault, receiving the treasury share of an auto-compounded bond
+#[inline(always)]
+    pub fn principal_vault_ata(&mut self, principal_vault_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.principal_vault_ata = Some(principal_vault_ata);
+                    self
+    }
+            /// The token account of the team vesting escrow, receiving the team share of an auto-compounded bond
 #[inline(always)]
-    pub fn team_ata(&mut self, team_ata: solana_pubkey::Pubkey) -> &mut Self {
-                        self.team_ata = Some(team_ata);
+    pub fn team_vesting_ata(&mut self, team_vesting_ata: solana_pubkey::Pubkey) -> &mut Self {
+                        self.team_vesting_ata = Some(team_vesting_ata);
                     self
     }
             /// The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index])
@@ -276,7 +462,13 @@ impl ProcessClaimBuilder {
                         self.new_bond_pda = Some(new_bond_pda);
                     self
     }
-            /// The native token mint
+            /// The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index])
+#[inline(always)]
+    pub fn new_bond_page(&mut self, new_bond_page: solana_pubkey::Pubkey) -> &mut Self {
+                        self.new_bond_page = Some(new_bond_page);
+                    self
+    }
+            /// The native token mint, written when penalties are burned or rewards minted
 #[inline(always)]
     pub fn native_token_mint(&mut self, native_token_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.native_token_mint = Some(native_token_mint);
@@ -301,9 +493,69 @@ impl ProcessClaimBuilder {
     pub fn system_program(&mut self, system_program: solana_pubkey::Pubkey) -> &mut Self {
                         self.system_program = Some(system_program);
                     self
+    }
+            /// The global state account (seeds: ['global_state'])
+#[inline(always)]
+    pub fn global_state(&mut self, global_state: solana_pubkey::Pubkey) -> &mut Self {
+                        self.global_state = Some(global_state);
+                    self
+    }
+            /// The deposit split config (seeds: ['split_config'])
+#[inline(always)]
+    pub fn split_config(&mut self, split_config: solana_pubkey::Pubkey) -> &mut Self {
+                        self.split_config = Some(split_config);
+                    self
+    }
+            /// The reward stream list (seeds: ['reward_streams'])
+#[inline(always)]
+    pub fn reward_streams(&mut self, reward_streams: solana_pubkey::Pubkey) -> &mut Self {
+                        self.reward_streams = Some(reward_streams);
+                    self
+    }
+            /// `[optional account]`
+/// The signer's token account holding the bond token, required for tokenized bonds
+#[inline(always)]
+    pub fn bond_token_account(&mut self, bond_token_account: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.bond_token_account = bond_token_account;
+                    self
+    }
+            /// `[optional account]`
+/// The wallet token account of the bond owner's referrer, required when the owner has one
+#[inline(always)]
+    pub fn referrer_ata(&mut self, referrer_ata: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.referrer_ata = referrer_ata;
+                    self
+    }
+            /// `[optional account]`
+/// The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet])
+#[inline(always)]
+    pub fn referrer_stats(&mut self, referrer_stats: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.referrer_stats = referrer_stats;
+                    self
+    }
+            /// `[optional account]`
+/// The signer's user pda, required when the signer holds a tokenized bond it does not own
+#[inline(always)]
+    pub fn holder_user_pda(&mut self, holder_user_pda: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.holder_user_pda = holder_user_pda;
+                    self
+    }
+            /// `[optional account]`
+/// The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index])
+#[inline(always)]
+    pub fn holder_bond_page(&mut self, holder_bond_page: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.holder_bond_page = holder_bond_page;
+                    self
+    }
+            /// `[optional account]`
+/// The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied
+#[inline(always)]
+    pub fn owner_wallet(&mut self, owner_wallet: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.owner_wallet = owner_wallet;
+                    self
     }
                     #[inline(always)]
-      pub fn bond_index(&mut self, bond_index: u8) -> &mut Self {
+      pub fn bond_index(&mut self, bond_index: u16) -> &mut Self {
         self.bond_index = Some(bond_index);
         self
       }
@@ -330,16 +582,28 @@ impl ProcessClaimBuilder {
                               bond: self.bond.expect("bond is not set"),
                                         user_wallet: self.user_wallet.expect("user_wallet is not set"),
                                         user_pda: self.user_pda.expect("user_pda is not set"),
+                                        user_bond_page: self.user_bond_page.expect("user_bond_page is not set"),
                                         user_wallet_ata: self.user_wallet_ata.expect("user_wallet_ata is not set"),
                                         global_admin: self.global_admin.expect("global_admin is not set"),
                                         rewards_pool_ata: self.rewards_pool_ata.expect("rewards_pool_ata is not set"),
                                         treasury_ata: self.treasury_ata.expect("treasury_ata is not set"),
-                                        team_ata: self.team_ata.expect("team_ata is not set"),
+                                        principal_vault_ata: self.principal_vault_ata.expect("principal_vault_ata is not set"),
+                                        team_vesting_ata: self.team_vesting_ata.expect("team_vesting_ata is not set"),
                                         new_bond_pda: self.new_bond_pda.expect("new_bond_pda is not set"),
+                                        new_bond_page: self.new_bond_page.expect("new_bond_page is not set"),
                                         native_token_mint: self.native_token_mint.expect("native_token_mint is not set"),
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.expect("associated_token_program is not set"),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
+                                        global_state: self.global_state.expect("global_state is not set"),
+                                        split_config: self.split_config.expect("split_config is not set"),
+                                        reward_streams: self.reward_streams.expect("reward_streams is not set"),
+                                        bond_token_account: self.bond_token_account,
+                                        referrer_ata: self.referrer_ata,
+                                        referrer_stats: self.referrer_stats,
+                                        holder_user_pda: self.holder_user_pda,
+                                        holder_bond_page: self.holder_bond_page,
+                                        owner_wallet: self.owner_wallet,
                       };
           let args = ProcessClaimInstructionArgs {
                                                               bond_index: self.bond_index.clone().expect("bond_index is not set"),
@@ -357,7 +621,7 @@ impl ProcessClaimBuilder {
       
                     
               pub bond: &'b solana_account_info::AccountInfo<'a>,
-                        /// The wallet of the user
+                        /// The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it
 
       
                     
@@ -367,6 +631,11 @@ impl ProcessClaimBuilder {
       
                     
               pub user_pda: &'b solana_account_info::AccountInfo<'a>,
+                        /// The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])
+
+      
+                    
+              pub user_bond_page: &'b solana_account_info::AccountInfo<'a>,
                         /// The user's wallet token account
 
       
@@ -387,17 +656,27 @@ impl ProcessClaimBuilder {
       
                     
               pub treasury_ata: &'b solana_account_info::AccountInfo<'a>,
-                        /// The token account of the team
+                        /// The token account of the principal vault, receiving the treasury share of an auto-compounded bond
+
+      
+                    
+              pub principal_vault_ata: &'b solana_account_info::AccountInfo<'a>,
+                        /// The token account of the team vesting escrow, receiving the team share of an auto-compounded bond
 
       
                     
-              pub team_ata: &'b solana_account_info::AccountInfo<'a>,
+              pub team_vesting_ata: &'b solana_account_info::AccountInfo<'a>,
                         /// The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index])
 
       
                     
               pub new_bond_pda: &'b solana_account_info::AccountInfo<'a>,
-                        /// The native token mint
+                        /// The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index])
+
+      
+                    
+              pub new_bond_page: &'b solana_account_info::AccountInfo<'a>,
+                        /// The native token mint, written when penalties are burned or rewards minted
 
       
                     
@@ -417,6 +696,51 @@ impl ProcessClaimBuilder {
       
                     
               pub system_program: &'b solana_account_info::AccountInfo<'a>,
+                        /// The global state account (seeds: ['global_state'])
+
+      
+                    
+              pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                        /// The deposit split config (seeds: ['split_config'])
+
+      
+                    
+              pub split_config: &'b solana_account_info::AccountInfo<'a>,
+                        /// The reward stream list (seeds: ['reward_streams'])
+
+      
+                    
+              pub reward_streams: &'b solana_account_info::AccountInfo<'a>,
+                        /// The signer's token account holding the bond token, required for tokenized bonds
+
+      
+                    
+              pub bond_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The wallet token account of the bond owner's referrer, required when the owner has one
+
+      
+                    
+              pub referrer_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet])
+
+      
+                    
+              pub referrer_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The signer's user pda, required when the signer holds a tokenized bond it does not own
+
+      
+                    
+              pub holder_user_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index])
+
+      
+                    
+              pub holder_bond_page: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        /// The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied
+
+      
+                    
+              pub owner_wallet: Option<&'b solana_account_info::AccountInfo<'a>>,
             }
 
 /// `process_claim` CPI instruction.
@@ -428,7 +752,7 @@ pub struct ProcessClaimCpi<'a, 'b> {
     
               
           pub bond: &'b solana_account_info::AccountInfo<'a>,
-                /// The wallet of the user
+                /// The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it
 
     
               
@@ -438,6 +762,11 @@ pub struct ProcessClaimCpi<'a, 'b> {
     
               
           pub user_pda: &'b solana_account_info::AccountInfo<'a>,
+                /// The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])
+
+    
+              
+          pub user_bond_page: &'b solana_account_info::AccountInfo<'a>,
                 /// The user's wallet token account
 
     
@@ -458,17 +787,27 @@ pub struct ProcessClaimCpi<'a, 'b> {
     
               
           pub treasury_ata: &'b solana_account_info::AccountInfo<'a>,
-                /// The token account of the team
+                /// The token account of the principal vault, receiving the treasury share of an auto-compounded bond
 
     
               
-          pub team_ata: &'b solana_account_info::AccountInfo<'a>,
+          pub principal_vault_ata: &'b solana_account_info::AccountInfo<'a>,
+                /// The token account of the team vesting escrow, receiving the team share of an auto-compounded bond
+
+    
+              
+          pub team_vesting_ata: &'b solana_account_info::AccountInfo<'a>,
                 /// The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index])
 
     
               
           pub new_bond_pda: &'b solana_account_info::AccountInfo<'a>,
-                /// The native token mint
+                /// The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index])
+
+    
+              
+          pub new_bond_page: &'b solana_account_info::AccountInfo<'a>,
+                /// The native token mint, written when penalties are burned or rewards minted
 
     
               
@@ -488,6 +827,51 @@ pub struct ProcessClaimCpi<'a, 'b> {
     
               
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
+                /// The global state account (seeds: ['global_state'])
+
+    
+              
+          pub global_state: &'b solana_account_info::AccountInfo<'a>,
+                /// The deposit split config (seeds: ['split_config'])
+
+    
+              
+          pub split_config: &'b solana_account_info::AccountInfo<'a>,
+                /// The reward stream list (seeds: ['reward_streams'])
+
+    
+              
+          pub reward_streams: &'b solana_account_info::AccountInfo<'a>,
+                /// The signer's token account holding the bond token, required for tokenized bonds
+
+    
+              
+          pub bond_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The wallet token account of the bond owner's referrer, required when the owner has one
+
+    
+              
+          pub referrer_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet])
+
+    
+              
+          pub referrer_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The signer's user pda, required when the signer holds a tokenized bond it does not own
+
+    
+              
+          pub holder_user_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index])
+
+    
+              
+          pub holder_bond_page: Option<&'b solana_account_info::AccountInfo<'a>>,
+                /// The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied
+
+    
+              
+          pub owner_wallet: Option<&'b solana_account_info::AccountInfo<'a>>,
             /// The arguments for the instruction.
     pub __args: ProcessClaimInstructionArgs,
   }
@@ -503,16 +887,28 @@ impl<'a, 'b> ProcessClaimCpi<'a, 'b> {
               bond: accounts.bond,
               user_wallet: accounts.user_wallet,
               user_pda: accounts.user_pda,
+              user_bond_page: accounts.user_bond_page,
               user_wallet_ata: accounts.user_wallet_ata,
               global_admin: accounts.global_admin,
               rewards_pool_ata: accounts.rewards_pool_ata,
               treasury_ata: accounts.treasury_ata,
-              team_ata: accounts.team_ata,
+              principal_vault_ata: accounts.principal_vault_ata,
+              team_vesting_ata: accounts.team_vesting_ata,
               new_bond_pda: accounts.new_bond_pda,
+              new_bond_page: accounts.new_bond_page,
               native_token_mint: accounts.native_token_mint,
               token_program: accounts.token_program,
               associated_token_program: accounts.associated_token_program,
               system_program: accounts.system_program,
+              global_state: accounts.global_state,
+              split_config: accounts.split_config,
+              reward_streams: accounts.reward_streams,
+              bond_token_account: accounts.bond_token_account,
+              referrer_ata: accounts.referrer_ata,
+              referrer_stats: accounts.referrer_stats,
+              holder_user_pda: accounts.holder_user_pda,
+              holder_bond_page: accounts.holder_bond_page,
+              owner_wallet: accounts.owner_wallet,
                     __args: args,
           }
   }
@@ -536,18 +932,22 @@ impl<'a, 'b> ProcessClaimCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(13+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(25+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.bond.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             *self.user_wallet.key,
             true
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.user_pda.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.user_bond_page.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.user_wallet_ata.key,
@@ -566,14 +966,22 @@ impl<'a, 'b> ProcessClaimCpi<'a, 'b> {
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
-            *self.team_ata.key,
+            *self.principal_vault_ata.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.team_vesting_ata.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.new_bond_pda.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.new_bond_page.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
             *self.native_token_mint.key,
             false
           ));
@@ -589,6 +997,84 @@ impl<'a, 'b> ProcessClaimCpi<'a, 'b> {
             *self.system_program.key,
             false
           ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.global_state.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.split_config.key,
+            false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.reward_streams.key,
+            false
+          ));
+                                          if let Some(bond_token_account) = self.bond_token_account {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *bond_token_account.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_ata) = self.referrer_ata {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *referrer_ata.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(referrer_stats) = self.referrer_stats {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *referrer_stats.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(holder_user_pda) = self.holder_user_pda {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *holder_user_pda.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(holder_bond_page) = self.holder_bond_page {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *holder_bond_page.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
+                                          if let Some(owner_wallet) = self.owner_wallet {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *owner_wallet.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::TESTUDO_BONDS_ID,
+                false,
+              ));
+            }
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
           pubkey: *remaining_account.0.key,
@@ -605,21 +1091,45 @@ impl<'a, 'b> ProcessClaimCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(26 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.bond.clone());
                         account_infos.push(self.user_wallet.clone());
                         account_infos.push(self.user_pda.clone());
+                        account_infos.push(self.user_bond_page.clone());
                         account_infos.push(self.user_wallet_ata.clone());
                         account_infos.push(self.global_admin.clone());
                         account_infos.push(self.rewards_pool_ata.clone());
                         account_infos.push(self.treasury_ata.clone());
-                        account_infos.push(self.team_ata.clone());
+                        account_infos.push(self.principal_vault_ata.clone());
+                        account_infos.push(self.team_vesting_ata.clone());
                         account_infos.push(self.new_bond_pda.clone());
+                        account_infos.push(self.new_bond_page.clone());
                         account_infos.push(self.native_token_mint.clone());
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.system_program.clone());
+                        account_infos.push(self.global_state.clone());
+                        account_infos.push(self.split_config.clone());
+                        account_infos.push(self.reward_streams.clone());
+                        if let Some(bond_token_account) = self.bond_token_account {
+        account_infos.push(bond_token_account.clone());
+      }
+                        if let Some(referrer_ata) = self.referrer_ata {
+        account_infos.push(referrer_ata.clone());
+      }
+                        if let Some(referrer_stats) = self.referrer_stats {
+        account_infos.push(referrer_stats.clone());
+      }
+                        if let Some(holder_user_pda) = self.holder_user_pda {
+        account_infos.push(holder_user_pda.clone());
+      }
+                        if let Some(holder_bond_page) = self.holder_bond_page {
+        account_infos.push(holder_bond_page.clone());
+      }
+                        if let Some(owner_wallet) = self.owner_wallet {
+        account_infos.push(owner_wallet.clone());
+      }
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -635,18 +1145,30 @@ impl<'a, 'b> ProcessClaimCpi<'a, 'b> {
 /// ### Accounts:
 ///
                 ///   0. `[writable]` bond
-                ///   1. `[signer]` user_wallet
+                ///   1. `[writable, signer]` user_wallet
                 ///   2. `[writable]` user_pda
-                ///   3. `[writable]` user_wallet_ata
-          ///   4. `[]` global_admin
-                ///   5. `[writable]` rewards_pool_ata
-                ///   6. `[writable]` treasury_ata
-                ///   7. `[writable]` team_ata
-                ///   8. `[writable]` new_bond_pda
-          ///   9. `[]` native_token_mint
-          ///   10. `[]` token_program
-          ///   11. `[]` associated_token_program
-          ///   12. `[]` system_program
+                ///   3. `[writable]` user_bond_page
+                ///   4. `[writable]` user_wallet_ata
+          ///   5. `[]` global_admin
+                ///   6. `[writable]` rewards_pool_ata
+                ///   7. `[writable]` treasury_ata
+                ///   8. `[writable]` principal_vault_ata
+                ///   9. `[writable]` team_vesting_ata
+                ///   10. `[writable]` new_bond_pda
+                ///   11. `[writable]` new_bond_page
+                ///   12. `[writable]` native_token_mint
+          ///   13. `[]` token_program
+          ///   14. `[]` associated_token_program
+          ///   15. `[]` system_program
+                ///   16. `[writable]` global_state
+          ///   17. `[]` split_config
+          ///   18. `[]` reward_streams
+                ///   19. `[optional]` bond_token_account
+                ///   20. `[writable, optional]` referrer_ata
+                ///   21. `[writable, optional]` referrer_stats
+                ///   22. `[writable, optional]` holder_user_pda
+                ///   23. `[writable, optional]` holder_bond_page
+                ///   24. `[writable, optional]` owner_wallet
 #[derive(Clone, Debug)]
 pub struct ProcessClaimCpiBuilder<'a, 'b> {
   instruction: Box<ProcessClaimCpiBuilderInstruction<'a, 'b>>,
@@ -659,16 +1181,28 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
               bond: None,
               user_wallet: None,
               user_pda: None,
+              user_bond_page: None,
               user_wallet_ata: None,
               global_admin: None,
               rewards_pool_ata: None,
               treasury_ata: None,
-              team_ata: None,
+              principal_vault_ata: None,
+              team_vesting_ata: None,
               new_bond_pda: None,
+              new_bond_page: None,
               native_token_mint: None,
               token_program: None,
               associated_token_program: None,
               system_program: None,
+              global_state: None,
+              split_config: None,
+              reward_streams: None,
+              bond_token_account: None,
+              referrer_ata: None,
+              referrer_stats: None,
+              holder_user_pda: None,
+              holder_bond_page: None,
+              owner_wallet: None,
                                             bond_index: None,
                                 auto_compound: None,
                     __remaining_accounts: Vec::new(),
@@ -681,7 +1215,7 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
                         self.instruction.bond = Some(bond);
                     self
     }
-      /// The wallet of the user
+      /// The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it
 #[inline(always)]
     pub fn user_wallet(&mut self, user_wallet: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.user_wallet = Some(user_wallet);
@@ -692,6 +1226,12 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
     pub fn user_pda(&mut self, user_pda: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.user_pda = Some(user_pda);
                     self
+    }
+      /// The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])
+#[inline(always)]
+    pub fn user_bond_page(&mut self, user_bond_page: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.user_bond_page = Some(user_bond_page);
+                    self
     }
       /// The user's wallet token account
 #[inline(always)]
@@ -717,10 +1257,16 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
                         self.instruction.treasury_ata = Some(treasury_ata);
                     self
     }
-      /// The token account of the team
+      /// The token account of the principal vault, receiving the treasury share of an auto-compounded bond
+#[inline(always)]
+    pub fn principal_vault_ata(&mut self, principal_vault_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.principal_vault_ata = Some(principal_vault_ata);
+                    self
+    }
+      /// The token account of the team vesting escrow, receiving the team share of an auto-compounded bond
 #[inline(always)]
-    pub fn team_ata(&mut self, team_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.team_ata = Some(team_ata);
+    pub fn team_vesting_ata(&mut self, team_vesting_ata: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.team_vesting_ata = Some(team_vesting_ata);
                     self
     }
       /// The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index])
@@ -729,7 +1275,13 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
                         self.instruction.new_bond_pda = Some(new_bond_pda);
                     self
     }
-      /// The native token mint
+      /// The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index])
+#[inline(always)]
+    pub fn new_bond_page(&mut self, new_bond_page: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.new_bond_page = Some(new_bond_page);
+                    self
+    }
+      /// The native token mint, written when penalties are burned or rewards minted
 #[inline(always)]
     pub fn native_token_mint(&mut self, native_token_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.native_token_mint = Some(native_token_mint);
@@ -752,9 +1304,69 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
     pub fn system_program(&mut self, system_program: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.system_program = Some(system_program);
                     self
+    }
+      /// The global state account (seeds: ['global_state'])
+#[inline(always)]
+    pub fn global_state(&mut self, global_state: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.global_state = Some(global_state);
+                    self
+    }
+      /// The deposit split config (seeds: ['split_config'])
+#[inline(always)]
+    pub fn split_config(&mut self, split_config: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.split_config = Some(split_config);
+                    self
+    }
+      /// The reward stream list (seeds: ['reward_streams'])
+#[inline(always)]
+    pub fn reward_streams(&mut self, reward_streams: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.reward_streams = Some(reward_streams);
+                    self
+    }
+      /// `[optional account]`
+/// The signer's token account holding the bond token, required for tokenized bonds
+#[inline(always)]
+    pub fn bond_token_account(&mut self, bond_token_account: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.bond_token_account = bond_token_account;
+                    self
+    }
+      /// `[optional account]`
+/// The wallet token account of the bond owner's referrer, required when the owner has one
+#[inline(always)]
+    pub fn referrer_ata(&mut self, referrer_ata: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.referrer_ata = referrer_ata;
+                    self
+    }
+      /// `[optional account]`
+/// The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet])
+#[inline(always)]
+    pub fn referrer_stats(&mut self, referrer_stats: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.referrer_stats = referrer_stats;
+                    self
+    }
+      /// `[optional account]`
+/// The signer's user pda, required when the signer holds a tokenized bond it does not own
+#[inline(always)]
+    pub fn holder_user_pda(&mut self, holder_user_pda: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.holder_user_pda = holder_user_pda;
+                    self
+    }
+      /// `[optional account]`
+/// The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index])
+#[inline(always)]
+    pub fn holder_bond_page(&mut self, holder_bond_page: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.holder_bond_page = holder_bond_page;
+                    self
+    }
+      /// `[optional account]`
+/// The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied
+#[inline(always)]
+    pub fn owner_wallet(&mut self, owner_wallet: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.owner_wallet = owner_wallet;
+                    self
     }
                     #[inline(always)]
-      pub fn bond_index(&mut self, bond_index: u8) -> &mut Self {
+      pub fn bond_index(&mut self, bond_index: u16) -> &mut Self {
         self.instruction.bond_index = Some(bond_index);
         self
       }
@@ -798,6 +1410,8 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
                   
           user_pda: self.instruction.user_pda.expect("user_pda is not set"),
                   
+          user_bond_page: self.instruction.user_bond_page.expect("user_bond_page is not set"),
+                  
           user_wallet_ata: self.instruction.user_wallet_ata.expect("user_wallet_ata is not set"),
                   
           global_admin: self.instruction.global_admin.expect("global_admin is not set"),
@@ -806,10 +1420,14 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
                   
           treasury_ata: self.instruction.treasury_ata.expect("treasury_ata is not set"),
                   
-          team_ata: self.instruction.team_ata.expect("team_ata is not set"),
+          principal_vault_ata: self.instruction.principal_vault_ata.expect("principal_vault_ata is not set"),
+                  
+          team_vesting_ata: self.instruction.team_vesting_ata.expect("team_vesting_ata is not set"),
                   
           new_bond_pda: self.instruction.new_bond_pda.expect("new_bond_pda is not set"),
                   
+          new_bond_page: self.instruction.new_bond_page.expect("new_bond_page is not set"),
+                  
           native_token_mint: self.instruction.native_token_mint.expect("native_token_mint is not set"),
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
@@ -817,6 +1435,24 @@ impl<'a, 'b> ProcessClaimCpiBuilder<'a, 'b> {
           associated_token_program: self.instruction.associated_token_program.expect("associated_token_program is not set"),
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
+                  
+          global_state: self.instruction.global_state.expect("global_state is not set"),
+                  
+          split_config: self.instruction.split_config.expect("split_config is not set"),
+                  
+          reward_streams: self.instruction.reward_streams.expect("reward_streams is not set"),
+                  
+          bond_token_account: self.instruction.bond_token_account,
+                  
+          referrer_ata: self.instruction.referrer_ata,
+                  
+          referrer_stats: self.instruction.referrer_stats,
+                  
+          holder_user_pda: self.instruction.holder_user_pda,
+                  
+          holder_bond_page: self.instruction.holder_bond_page,
+                  
+          owner_wallet: self.instruction.owner_wallet,
                           __args: args,
             };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
@@ -829,17 +1465,29 @@ struct ProcessClaimCpiBuilderInstruction<'a, 'b> {
             bond: Option<&'b solana_account_info::AccountInfo<'a>>,
                 user_wallet: Option<&'b solana_account_info::AccountInfo<'a>>,
                 user_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                user_bond_page: Option<&'b solana_account_info::AccountInfo<'a>>,
                 user_wallet_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 global_admin: Option<&'b solana_account_info::AccountInfo<'a>>,
                 rewards_pool_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 treasury_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
-                team_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                principal_vault_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                team_vesting_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
                 new_bond_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                new_bond_page: Option<&'b solana_account_info::AccountInfo<'a>>,
                 native_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 associated_token_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
-                        bond_index: Option<u8>,
+                global_state: Option<&'b solana_account_info::AccountInfo<'a>>,
+                split_config: Option<&'b solana_account_info::AccountInfo<'a>>,
+                reward_streams: Option<&'b solana_account_info::AccountInfo<'a>>,
+                bond_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
+                referrer_ata: Option<&'b solana_account_info::AccountInfo<'a>>,
+                referrer_stats: Option<&'b solana_account_info::AccountInfo<'a>>,
+                holder_user_pda: Option<&'b solana_account_info::AccountInfo<'a>>,
+                holder_bond_page: Option<&'b solana_account_info::AccountInfo<'a>>,
+                owner_wallet: Option<&'b solana_account_info::AccountInfo<'a>>,
+                        bond_index: Option<u16>,
                 auto_compound: Option<bool>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
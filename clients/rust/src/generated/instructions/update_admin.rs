@@ -23,6 +23,11 @@ pub struct UpdateAdmin {
     
               
           pub authority: solana_pubkey::Pubkey,
+                /// The global state account (seeds: ['global_state'])
+
+    
+              
+          pub global_state: solana_pubkey::Pubkey,
       }
 
 impl UpdateAdmin {
@@ -32,7 +37,7 @@ impl UpdateAdmin {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(2+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(3+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.global_admin,
             false
@@ -40,6 +45,10 @@ impl UpdateAdmin {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.authority,
             true
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            self.global_state,
+            false
           ));
                       accounts.extend_from_slice(remaining_accounts);
     let data = borsh::to_vec(&UpdateAdminInstructionData::new()).unwrap();
@@ -80,10 +89,12 @@ impl Default for UpdateAdminInstructionData {
 ///
                 ///   0. `[writable]` global_admin
                 ///   1. `[signer]` authority
+                ///   2. `[writable]` global_state
 #[derive(Clone, Debug, Default)]
 pub struct UpdateAdminBuilder {
             global_admin: Option<solana_pubkey::Pubkey>,
                 authority: Option<solana_pubkey::Pubkey>,
+                global_state: Option<solana_pubkey::Pubkey>,
                 __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -102,6 +113,12 @@ impl UpdateAdminBuilder {
     pub fn authority(&mut self, authority: solana_pubkey::Pubkey) -> &mut Self {
                         self.authority = Some(authority);
                     self
+    }
+            /// The global state account (seeds: ['global_state'])
+#[inline(always)]
+    pub fn global_state(&mut self, global_state: solana_pubkey::Pubkey) -> &mut Self {
+                        self.global_state = Some(global_state);
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -120,6 +137,7 @@ impl UpdateAdminBuilder {
     let accounts = UpdateAdmin {
                               global_admin: self.global_admin.expect("global_admin is not set"),
                                         authority: self.authority.expect("authority is not set"),
+                                        global_state: self.global_state.expect("global_state is not set"),
                       };
     
     accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -138,6 +156,11 @@ impl UpdateAdminBuilder {
       
                     
               pub authority: &'b solana_account_info::AccountInfo<'a>,
+                        /// The global state account (seeds: ['global_state'])
+
+      
+                    
+              pub global_state: &'b solana_account_info::AccountInfo<'a>,
             }
 
 /// `update_admin` CPI instruction.
@@ -154,6 +177,11 @@ pub struct UpdateAdminCpi<'a, 'b> {
     
               
           pub authority: &'b solana_account_info::AccountInfo<'a>,
+                /// The global state account (seeds: ['global_state'])
+
+    
+              
+          pub global_state: &'b solana_account_info::AccountInfo<'a>,
         }
 
 impl<'a, 'b> UpdateAdminCpi<'a, 'b> {
@@ -165,6 +193,7 @@ impl<'a, 'b> UpdateAdminCpi<'a, 'b> {
       __program: program,
               global_admin: accounts.global_admin,
               authority: accounts.authority,
+              global_state: accounts.global_state,
                 }
   }
   #[inline(always)]
@@ -187,7 +216,7 @@ impl<'a, 'b> UpdateAdminCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_entrypoint::ProgramResult {
-    let mut accounts = Vec::with_capacity(2+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(3+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.global_admin.key,
             false
@@ -195,6 +224,10 @@ impl<'a, 'b> UpdateAdminCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new(
+            *self.global_state.key,
+            false
           ));
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
@@ -210,10 +243,11 @@ impl<'a, 'b> UpdateAdminCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(3 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.global_admin.clone());
                         account_infos.push(self.authority.clone());
+                        account_infos.push(self.global_state.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -230,6 +264,7 @@ impl<'a, 'b> UpdateAdminCpi<'a, 'b> {
 ///
                 ///   0. `[writable]` global_admin
                 ///   1. `[signer]` authority
+                ///   2. `[writable]` global_state
 #[derive(Clone, Debug)]
 pub struct UpdateAdminCpiBuilder<'a, 'b> {
   instruction: Box<UpdateAdminCpiBuilderInstruction<'a, 'b>>,
@@ -241,6 +276,7 @@ impl<'a, 'b> UpdateAdminCpiBuilder<'a, 'b> {
       __program: program,
               global_admin: None,
               authority: None,
+              global_state: None,
                                 __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -256,6 +292,12 @@ impl<'a, 'b> UpdateAdminCpiBuilder<'a, 'b> {
     pub fn authority(&mut self, authority: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.authority = Some(authority);
                     self
+    }
+      /// The global state account (seeds: ['global_state'])
+#[inline(always)]
+    pub fn global_state(&mut self, global_state: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.global_state = Some(global_state);
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -285,6 +327,8 @@ impl<'a, 'b> UpdateAdminCpiBuilder<'a, 'b> {
           global_admin: self.instruction.global_admin.expect("global_admin is not set"),
                   
           authority: self.instruction.authority.expect("authority is not set"),
+                  
+          global_state: self.instruction.global_state.expect("global_state is not set"),
                     };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
@@ -295,6 +339,7 @@ struct UpdateAdminCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
             global_admin: Option<&'b solana_account_info::AccountInfo<'a>>,
                 authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+                global_state: Option<&'b solana_account_info::AccountInfo<'a>>,
                 /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -5,9 +5,63 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#allowlist_phase;
+  pub(crate) mod r#allowlist_proof;
+  pub(crate) mod r#attach_reward_stream_payload;
+  pub(crate) mod r#buy_bond_payload;
+  pub(crate) mod r#cancel_listing_payload;
+  pub(crate) mod r#emission_curve;
+  pub(crate) mod r#emission_mode;
+  pub(crate) mod r#fixed_term_config;
+  pub(crate) mod r#grant_bond_payload;
+  pub(crate) mod r#grant_source;
+  pub(crate) mod r#initialize_bond_payload;
+  pub(crate) mod r#list_bond_payload;
+  pub(crate) mod r#lock_tier;
+  pub(crate) mod r#loyalty_tier;
+  pub(crate) mod r#mature_bond_payload;
+  pub(crate) mod r#merge_bonds_payload;
+  pub(crate) mod r#penalty_destination;
+  pub(crate) mod r#penalty_tier;
   pub(crate) mod r#process_claim_payload;
+  pub(crate) mod r#redeem_bond_payload;
+  pub(crate) mod r#reward_source;
+  pub(crate) mod r#split_bond_payload;
+  pub(crate) mod r#split_destination;
+  pub(crate) mod r#split_recipient;
+  pub(crate) mod r#transfer_bond_payload;
   pub(crate) mod r#update_admin_payload;
+  pub(crate) mod r#update_split_config_payload;
+  pub(crate) mod r#vesting_schedule;
+  pub(crate) mod r#vesting_tranche;
 
+  pub use self::r#allowlist_phase::*;
+  pub use self::r#allowlist_proof::*;
+  pub use self::r#attach_reward_stream_payload::*;
+  pub use self::r#buy_bond_payload::*;
+  pub use self::r#cancel_listing_payload::*;
+  pub use self::r#emission_curve::*;
+  pub use self::r#emission_mode::*;
+  pub use self::r#fixed_term_config::*;
+  pub use self::r#grant_bond_payload::*;
+  pub use self::r#grant_source::*;
+  pub use self::r#initialize_bond_payload::*;
+  pub use self::r#list_bond_payload::*;
+  pub use self::r#lock_tier::*;
+  pub use self::r#loyalty_tier::*;
+  pub use self::r#mature_bond_payload::*;
+  pub use self::r#merge_bonds_payload::*;
+  pub use self::r#penalty_destination::*;
+  pub use self::r#penalty_tier::*;
   pub use self::r#process_claim_payload::*;
+  pub use self::r#redeem_bond_payload::*;
+  pub use self::r#reward_source::*;
+  pub use self::r#split_bond_payload::*;
+  pub use self::r#split_destination::*;
+  pub use self::r#split_recipient::*;
+  pub use self::r#transfer_bond_payload::*;
   pub use self::r#update_admin_payload::*;
+  pub use self::r#update_split_config_payload::*;
+  pub use self::r#vesting_schedule::*;
+  pub use self::r#vesting_tranche::*;
 
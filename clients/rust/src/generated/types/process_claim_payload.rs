@@ -11,7 +11,7 @@ use borsh::BorshDeserialize;
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessClaimPayload {
-pub bond_index: u8,
+pub bond_index: u16,
 pub auto_compound: bool,
 }
 
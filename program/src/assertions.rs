@@ -1,5 +1,11 @@
+use crate::constants::{
+    MAX_LOCK_BOOST, MAX_LOYALTY_BOOST, MAX_REFERRAL_BPS, MIN_EMISSION_DECAY_PERIOD,
+};
 use crate::error::TestudoBondsError;
-use crate::state::{Bond, UserAccount};
+use crate::state::{
+    AllowlistPhase, Bond, EmissionCurve, FixedTermConfig, LockTier, LoyaltyTier, PenaltyTier,
+    SplitConfig, SplitDestination, SplitRecipient, UserBondPage, VestingSchedule,
+};
 use solana_program::program_pack::Pack;
 use solana_program::system_program::ID as system_program;
 use solana_program::{
@@ -222,22 +228,324 @@ pub fn assert_sufficient_tokens(
     }
 }
 
-pub fn assert_valid_bond(bond_pda_data: &Bond, user_pda_data: &UserAccount) -> ProgramResult {
+pub fn assert_valid_bond(
+    bond_pda: &Pubkey,
+    bond_pda_data: &Bond,
+    bond_page_data: &UserBondPage,
+) -> ProgramResult {
     if !bond_pda_data.is_active {
         msg!("Bond is not active");
         Err(TestudoBondsError::BondNotActive.into())
-    } else if !user_pda_data
-        .active_bonds
-        .iter()
-        .any(|(index, _)| *index == bond_pda_data.bond_index)
+    } else if bond_pda_data.is_listed {
+        msg!(
+            "Bond index [{}] is listed for sale",
+            bond_pda_data.bond_index
+        );
+        Err(TestudoBondsError::BondIsListed.into())
+    } else if bond_page_data.owner != bond_pda_data.owner
+        || !bond_page_data.bonds.contains(bond_pda)
     {
-        msg!("Bond index [{}] is invalid", bond_pda_data.bond_index);
+        msg!(
+            "Bond index [{}] is not tracked on bond page [{}]",
+            bond_pda_data.bond_index,
+            bond_page_data.page_index
+        );
         Err(TestudoBondsError::InvalidBondIndex.into())
     } else {
         Ok(())
     }
 }
 
+/// Assert that a claim penalty schedule is well formed: penalties are at most 100%, used tiers come
+/// first and their thresholds are strictly increasing.
+pub fn assert_valid_penalty_schedule(schedule: &[PenaltyTier]) -> ProgramResult {
+    let mut previous_threshold = 0;
+    let mut schedule_ended = false;
+    for tier in schedule {
+        if tier.penalty_bps > 10_000 {
+            msg!(
+                "Penalty tier exceeds 10000 basis points: {}",
+                tier.penalty_bps
+            );
+            return Err(TestudoBondsError::InvalidPenaltySchedule.into());
+        }
+        if tier.max_elapsed_seconds == 0 {
+            schedule_ended = true;
+            continue;
+        }
+        if schedule_ended || tier.max_elapsed_seconds <= previous_threshold {
+            msg!("Penalty tiers must be sorted by elapsed time and come before unused tiers");
+            return Err(TestudoBondsError::InvalidPenaltySchedule.into());
+        }
+        previous_threshold = tier.max_elapsed_seconds;
+    }
+    Ok(())
+}
+
+/// Assert that a loyalty schedule is well formed: boosts are at most `MAX_LOYALTY_BOOST`, used tiers
+/// come first and their ages are strictly increasing.
+pub fn assert_valid_loyalty_schedule(schedule: &[LoyaltyTier]) -> ProgramResult {
+    let mut previous_age = 0;
+    let mut schedule_ended = false;
+    for tier in schedule {
+        if tier.boost_bps > MAX_LOYALTY_BOOST {
+            msg!(
+                "Loyalty boost exceeds {} basis points: {}",
+                MAX_LOYALTY_BOOST,
+                tier.boost_bps
+            );
+            return Err(TestudoBondsError::InvalidLoyaltySchedule.into());
+        }
+        if tier.min_age_seconds == 0 {
+            schedule_ended = true;
+            continue;
+        }
+        if schedule_ended || tier.min_age_seconds <= previous_age {
+            msg!("Loyalty tiers must be sorted by bond age and come before unused tiers");
+            return Err(TestudoBondsError::InvalidLoyaltySchedule.into());
+        }
+        previous_age = tier.min_age_seconds;
+    }
+    Ok(())
+}
+
+/// Assert that the lock tiers are well formed: boosts are at most `MAX_LOCK_BOOST`, used tiers come
+/// first and their lock periods are strictly increasing.
+pub fn assert_valid_lock_tiers(lock_tiers: &[LockTier]) -> ProgramResult {
+    let mut previous_lock = 0;
+    let mut tiers_ended = false;
+    for tier in lock_tiers {
+        if tier.boost_bps > MAX_LOCK_BOOST {
+            msg!(
+                "Lock boost exceeds {} basis points: {}",
+                MAX_LOCK_BOOST,
+                tier.boost_bps
+            );
+            return Err(TestudoBondsError::InvalidLockTier.into());
+        }
+        if tier.lock_seconds == 0 {
+            tiers_ended = true;
+            continue;
+        }
+        if tiers_ended || tier.lock_seconds <= previous_lock {
+            msg!("Lock tiers must be sorted by lock period and come before unused tiers");
+            return Err(TestudoBondsError::InvalidLockTier.into());
+        }
+        previous_lock = tier.lock_seconds;
+    }
+    Ok(())
+}
+
+/// Assert that a deposit split is well formed: between one and `SplitConfig::MAX_RECIPIENTS`
+/// recipients with non-zero shares adding up to 10000 basis points, each built-in destination at
+/// most once and distinct token accounts for `Account` recipients.
+pub fn assert_valid_split_config(recipients: &[SplitRecipient]) -> ProgramResult {
+    if recipients.is_empty() || recipients.len() > SplitConfig::MAX_RECIPIENTS {
+        msg!(
+            "Deposit split needs 1 to {} recipients",
+            SplitConfig::MAX_RECIPIENTS
+        );
+        return Err(TestudoBondsError::InvalidSplitConfig.into());
+    }
+    let mut total_bps: u32 = 0;
+    for (index, recipient) in recipients.iter().enumerate() {
+        if recipient.bps == 0 {
+            msg!("Split recipient {} has no share", index);
+            return Err(TestudoBondsError::InvalidSplitConfig.into());
+        }
+        total_bps += recipient.bps as u32;
+        let duplicate = recipients[..index].iter().any(|other| {
+            other.destination == recipient.destination
+                && (recipient.destination != SplitDestination::Account
+                    || other.token_account == recipient.token_account)
+        });
+        if duplicate {
+            msg!("Split recipient {} is a duplicate", index);
+            return Err(TestudoBondsError::InvalidSplitConfig.into());
+        }
+    }
+    if total_bps != 10_000 {
+        msg!(
+            "Split shares add up to {} basis points, not 10000",
+            total_bps
+        );
+        return Err(TestudoBondsError::InvalidSplitConfig.into());
+    }
+    Ok(())
+}
+
+/// Assert that a team vesting schedule is well formed, with the cliff within the vesting duration,
+/// and that it vests no faster than the `current` schedule at any time.
+pub fn assert_valid_vesting_schedule(
+    schedule: &VestingSchedule,
+    current: &VestingSchedule,
+) -> ProgramResult {
+    if schedule.duration_seconds == 0 || schedule.cliff_seconds > schedule.duration_seconds {
+        msg!(
+            "Vesting cliff {} must be within a non-zero duration {}",
+            schedule.cliff_seconds,
+            schedule.duration_seconds
+        );
+        return Err(TestudoBondsError::InvalidVestingSchedule.into());
+    }
+    if schedule.start_timestamp < current.start_timestamp
+        || schedule.cliff_seconds < current.cliff_seconds
+        || schedule.duration_seconds < current.duration_seconds
+    {
+        msg!("Team vesting can only be delayed or slowed down");
+        return Err(TestudoBondsError::InvalidVestingSchedule.into());
+    }
+    Ok(())
+}
+
+/// Assert that a reward stream emits a non-zero rate over a non-empty period that has not ended.
+pub fn assert_valid_reward_stream(
+    rate: u64,
+    start_timestamp: i64,
+    end_timestamp: i64,
+    current_timestamp: i64,
+) -> ProgramResult {
+    if rate == 0 || end_timestamp <= start_timestamp || end_timestamp <= current_timestamp {
+        msg!(
+            "Reward stream must emit a non-zero rate {} from {} until a later end {}",
+            rate,
+            start_timestamp,
+            end_timestamp
+        );
+        return Err(TestudoBondsError::InvalidRewardStream.into());
+    }
+    Ok(())
+}
+
+/// Assert that the fixed-term bond terms return at most the whole principal.
+pub fn assert_valid_fixed_term(fixed_term: &FixedTermConfig) -> ProgramResult {
+    if fixed_term.principal_return_bps > 10_000 {
+        msg!(
+            "Principal return exceeds 10000 basis points: {}",
+            fixed_term.principal_return_bps
+        );
+        return Err(TestudoBondsError::InvalidFixedTerm.into());
+    }
+    Ok(())
+}
+
+/// Assert that an enabled allowlist phase ends after it starts.
+pub fn assert_valid_allowlist_phase(phase: &AllowlistPhase) -> ProgramResult {
+    if phase.end_timestamp != 0 && phase.end_timestamp <= phase.start_timestamp {
+        msg!(
+            "Allowlist phase must end after its start {}, got {}",
+            phase.start_timestamp,
+            phase.end_timestamp
+        );
+        return Err(TestudoBondsError::InvalidAllowlistPhase.into());
+    }
+    Ok(())
+}
+
+/// Assert that referrers get at most `MAX_REFERRAL_BPS` of referred deposits and claims.
+pub fn assert_valid_referral_rates(deposit_bps: u16, claim_bps: u16) -> ProgramResult {
+    if deposit_bps > MAX_REFERRAL_BPS || claim_bps > MAX_REFERRAL_BPS {
+        msg!(
+            "Referral rates {} and {} must not exceed {} basis points",
+            deposit_bps,
+            claim_bps,
+            MAX_REFERRAL_BPS
+        );
+        return Err(TestudoBondsError::InvalidReferral.into());
+    }
+    Ok(())
+}
+
+/// Assert that an emission curve decays by at most 100% per period, and that decaying curves use
+/// periods of at least `MIN_EMISSION_DECAY_PERIOD` so integrating them stays cheap.
+pub fn assert_valid_emission_curve(curve: &EmissionCurve) -> ProgramResult {
+    if curve.decay_bps > 10_000 {
+        msg!(
+            "Emission decay exceeds 10000 basis points: {}",
+            curve.decay_bps
+        );
+        return Err(TestudoBondsError::InvalidEmissionCurve.into());
+    }
+    if curve.decay_bps > 0 && curve.period_seconds < MIN_EMISSION_DECAY_PERIOD {
+        msg!(
+            "Emission decay period must be at least {} seconds",
+            MIN_EMISSION_DECAY_PERIOD
+        );
+        return Err(TestudoBondsError::InvalidEmissionCurve.into());
+    }
+    Ok(())
+}
+
+/// Assert that the given bond is past the end of its lock-up.
+pub fn assert_bond_unlocked(bond_pda_data: &Bond, current_timestamp: i64) -> ProgramResult {
+    if current_timestamp < bond_pda_data.lock_until {
+        msg!(
+            "Bond index [{}] is locked until {}",
+            bond_pda_data.bond_index,
+            bond_pda_data.lock_until
+        );
+        Err(TestudoBondsError::BondLocked.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given bond is a perpetual bond. Fixed-term bonds only settle through MatureBond.
+pub fn assert_bond_perpetual(bond_pda_data: &Bond) -> ProgramResult {
+    if bond_pda_data.maturity_timestamp != 0 {
+        msg!(
+            "Bond index [{}] is a fixed-term bond maturing at {}",
+            bond_pda_data.bond_index,
+            bond_pda_data.maturity_timestamp
+        );
+        Err(TestudoBondsError::BondIsFixedTerm.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given bond is not tokenized. Tokenized bonds change hands with their token.
+pub fn assert_bond_not_tokenized(bond_pda_data: &Bond) -> ProgramResult {
+    if bond_pda_data.mint != Pubkey::default() {
+        msg!(
+            "Bond index [{}] is tokenized by mint [{}]",
+            bond_pda_data.bond_index,
+            bond_pda_data.mint
+        );
+        Err(TestudoBondsError::BondIsTokenized.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Assert that the given token account holds the one-of-one bond token of `mint` for `holder`.
+pub fn assert_bond_holder(
+    holder: &Pubkey,
+    mint: &Pubkey,
+    token_account: &AccountInfo,
+) -> ProgramResult {
+    assert_program_owner_either(
+        "Bond Token Account",
+        token_account,
+        &[token_program, token_2022_program],
+    )?;
+    let token_account_data = TokenAccount::unpack(&token_account.data.borrow())?;
+    if token_account_data.mint != *mint
+        || token_account_data.owner != *holder
+        || token_account_data.amount != 1
+    {
+        msg!(
+            "Wallet [{}] does not hold the bond token [{}] in [{}]",
+            holder,
+            mint,
+            token_account.key
+        );
+        Err(TestudoBondsError::NotBondHolder.into())
+    } else {
+        Ok(())
+    }
+}
+
 pub fn assert_valid_token_account(
     account_name: &str,
     account: &Pubkey,
@@ -1,4 +1,15 @@
 pub const DAILY_EMISSION_RATE: u64 = 55_000_000;
 pub const MAX_EMISSION_PER_BOND: u64 = 20_000_000_000;
 pub const CLAIM_PENALTY: u16 = 500;
+pub const CLAIM_PENALTY_PERIOD: u32 = 5 * 86_400;
 pub const SHELLS_PER_TESTUDO: u64 = 1_000_000_000;
+pub const BOND_PRICE: u64 = 10 * SHELLS_PER_TESTUDO;
+pub const STANDARD_BOND_WEIGHT: u32 = 10_000;
+pub const REDEMPTION_RATE: u16 = 5000;
+pub const MARKETPLACE_FEE: u16 = 250;
+pub const PENALTY_PER_WEIGHT_PRECISION: u128 = 1_000_000_000_000;
+pub const MIN_EMISSION_DECAY_PERIOD: u32 = 86_400;
+pub const MAX_LOYALTY_BOOST: u16 = 10_000;
+pub const MAX_LOCK_BOOST: u16 = 10_000;
+pub const REWARD_PER_WEIGHT_PRECISION: u128 = 1_000_000_000_000;
+pub const MAX_REFERRAL_BPS: u16 = 2_000;
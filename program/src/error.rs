@@ -64,6 +64,93 @@ pub enum TestudoBondsError {
     /// 19 - Bond is active
     #[error("Bond is active")]
     BondIsActive,
+    /// 20 - Bond page full
+    #[error("Bond page is full")]
+    BondPageFull,
+    /// 21 - Bond is tokenized
+    #[error("Bond is tokenized")]
+    BondIsTokenized,
+    /// 22 - Not the bond holder
+    #[error("Signer does not hold the bond token")]
+    NotBondHolder,
+    /// 23 - Bond is listed
+    #[error("Bond is listed for sale")]
+    BondIsListed,
+    /// 24 - Invalid listing price
+    #[error("Invalid listing price")]
+    InvalidListingPrice,
+    /// 25 - Invalid bond weight
+    #[error("Invalid bond weight")]
+    InvalidBondWeight,
+    /// 26 - Invalid penalty schedule
+    #[error("Invalid penalty schedule")]
+    InvalidPenaltySchedule,
+    /// 27 - Invalid timestamp
+    #[error("Current timestamp is before the last claim")]
+    InvalidTimestamp,
+    /// 28 - Numerical underflow
+    #[error("Numerical underflow")]
+    NumericalUnderflow,
+    /// 29 - Invalid emission curve
+    #[error("Invalid emission curve")]
+    InvalidEmissionCurve,
+    /// 30 - Invalid loyalty schedule
+    #[error("Invalid loyalty schedule")]
+    InvalidLoyaltySchedule,
+    /// 31 - Bond is locked
+    #[error("Bond is locked")]
+    BondLocked,
+    /// 32 - Invalid lock tier
+    #[error("Invalid lock tier")]
+    InvalidLockTier,
+    /// 33 - Lock boost mismatch
+    #[error("Bonds with different lock boosts cannot be merged")]
+    LockBoostMismatch,
+    /// 34 - Invalid fixed term
+    #[error("Fixed-term bonds are unavailable or misconfigured")]
+    InvalidFixedTerm,
+    /// 35 - Bond is fixed-term
+    #[error("Bond is a fixed-term bond")]
+    BondIsFixedTerm,
+    /// 36 - Bond not mature
+    #[error("Bond is not a matured fixed-term bond")]
+    BondNotMature,
+    /// 37 - Distribution not due
+    #[error("Vault distribution is not due yet")]
+    DistributionNotDue,
+    /// 38 - Invalid vesting schedule
+    #[error("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+    /// 39 - Nothing to release
+    #[error("No vested tokens to release")]
+    NothingToRelease,
+    /// 40 - Invalid split config
+    #[error("Invalid deposit split config")]
+    InvalidSplitConfig,
+    /// 41 - Emission ceiling reached
+    #[error("Minting the rewards would exceed the emission ceiling")]
+    EmissionCeilingReached,
+    /// 42 - Invalid reward stream
+    #[error("Invalid reward stream")]
+    InvalidRewardStream,
+    /// 43 - Invalid referral
+    #[error("Invalid referrer or referral rate")]
+    InvalidReferral,
+    /// 44 - Invalid allowlist phase
+    #[error("Invalid allowlist phase")]
+    InvalidAllowlistPhase,
+    /// 45 - Not allowlisted
+    #[error("Wallet is not allowlisted or has used its allocation")]
+    NotAllowlisted,
+    /// 46 - Bond grant mismatch
+    #[error("Granted and bought bonds cannot be merged")]
+    BondGrantMismatch,
+    /// 47 - Price above maximum
+    #[error("Listing price is above the buyer's maximum price")]
+    PriceAboveMaximum,
+    /// 48 - Invalid grant source
+    #[error("A granted bond needs a grant source")]
+    InvalidGrantSource,
 }
 
 impl From<TestudoBondsError> for ProgramError {
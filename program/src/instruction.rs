@@ -1,7 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::{ShankContext, ShankInstruction};
 
-use crate::state::Admin;
+use crate::state::{Admin, AllowlistProof, GrantSource, SplitRecipient};
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, ShankContext, ShankInstruction)]
 #[rustfmt::skip]
@@ -18,6 +18,12 @@ pub enum TestudoBondsInstruction {
     #[account(8, name="system_program", desc = "The system program")]
     #[account(9, name="token_program", desc = "The token program")]
     #[account(10, name="associated_token_program", desc = "The associated token program")]
+    #[account(11, writable, name="global_state", desc = "The program derived address of the global state account to create (seeds: ['global_state'])")]
+    #[account(12, name="principal_vault", desc = "The principal vault holding bond deposits until they are distributed or mature (seeds: ['principal_vault'])")]
+    #[account(13, writable, name="principal_vault_ata", desc = "The token account of the principal vault")]
+    #[account(14, name="team_vesting", desc = "The team vesting escrow holding the team share of bond deposits until vested (seeds: ['team_vesting'])")]
+    #[account(15, writable, name="team_vesting_ata", desc = "The token account of the team vesting escrow")]
+    #[account(16, writable, name="split_config", desc = "The program derived address of the deposit split config to create (seeds: ['split_config'])")]
     InitializeAdmin,
 
     /// Creates a new user account.
@@ -26,52 +32,371 @@ pub enum TestudoBondsInstruction {
     #[account(2, name="system_program", desc = "The system program")]
     CreateUser,
 
-    /// Creates a new bond account.
+    /// Creates a new bond account, optionally locked up in one of the admin lock tiers for a higher emission rate, or as a fixed-term bond whose deposit is escrowed until maturity.
+    /// While the allowlist phase runs, the user's wallet must be proven to be on the allowlist.
+    /// The deposit is paid to the user's referrer, if any, and to the recipients of the split config; the token accounts of its `Account` recipients follow as remaining accounts, in config order.
     #[account(0, writable, name="bond", desc = "The program derived address of the bond account to create (seeds: ['bond', user_pda, bond_index])")]
     #[account(1, signer, name="user_wallet", desc = "The wallet of the user")]
     #[account(2, writable, name="user_pda", desc = "The user's pda")]
-    #[account(3, name="global_admin", desc = "The global admin account")]
-    #[account(4, writable, name="user_wallet_ata", desc = "The user's wallet token account")]
-    #[account(5, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
-    #[account(6, writable, name="treasury_ata", desc = "The token account of the treasury")]
-    #[account(7, writable, name="team_ata", desc = "The token account of the team")]
-    #[account(8, name="native_token_mint", desc = "The native token mint")]
-    #[account(9, name="system_program", desc = "The system program")]
-    #[account(10, name="token_program", desc = "The token program")]
-    InitializeBond,
+    #[account(3, writable, name="user_bond_page", desc = "The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index])")]
+    #[account(4, name="global_admin", desc = "The global admin account")]
+    #[account(5, writable, name="user_wallet_ata", desc = "The user's wallet token account")]
+    #[account(6, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
+    #[account(7, writable, name="principal_vault_ata", desc = "The token account of the principal vault, holding the treasury share until distributed")]
+    #[account(8, writable, name="team_vesting_ata", desc = "The token account of the team vesting escrow, receiving the team share")]
+    #[account(9, writable, name="native_token_mint", desc = "The native token mint, written when a deposit share is burned")]
+    #[account(10, name="system_program", desc = "The system program")]
+    #[account(11, name="token_program", desc = "The token program")]
+    #[account(12, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(13, name="split_config", desc = "The deposit split config (seeds: ['split_config'])")]
+    #[account(14, optional, writable, name="bond_mint", desc = "The bond token mint to create when tokenizing (seeds: ['bond_mint', bond])")]
+    #[account(15, optional, name="bond_mint_authority", desc = "The mint authority of bond tokens (seeds: ['bond_mint_authority'])")]
+    #[account(16, optional, writable, name="user_bond_token_account", desc = "The user's associated token account for the bond token")]
+    #[account(17, optional, name="associated_token_program", desc = "The associated token program")]
+    #[account(18, optional, name="referrer_pda", desc = "The user PDA of the referrer to set if the user has none yet, or the program ID")]
+    #[account(19, optional, writable, name="referrer_ata", desc = "The referrer's wallet token account, required once the user has a referrer")]
+    #[account(20, optional, writable, name="referrer_stats", desc = "The referrer's stats (seeds: ['referrer_stats', referrer_wallet]), created on the first referral")]
+    InitializeBond {
+        tokenize: bool,
+        lock_tier: Option<u8>,
+        fixed_term: bool,
+        allowlist_proof: Option<AllowlistProof>,
+    },
 
     /// Claims rewards from a bond.
+    /// The bond owner's referrer, if any, gets its share of the reward. Also pays the bond's rewards of every attached reward stream, and closes the bond's stream settlements when the bond closes. Remaining accounts: for each stream of the reward stream list, in order, the stream, its pool, its mint, the signer's token account for it and the bond's stream settlement (seeds: ['stream_settlement', reward_stream, bond]); then, when auto-compounding, the token accounts of the split config's `Account` recipients, in config order.
     #[account(0, writable, name="bond", desc = "The program derived address of the bond account to claim rewards from (seeds: ['bond', user_pda, bond_index])")]
-    #[account(1, signer, name="user_wallet", desc = "The wallet of the user")]
+    #[account(1, signer, writable, name="user_wallet", desc = "The wallet of the user, pays for the holder's bond page if a tokenized bond is moved to it")]
     #[account(2, writable, name="user_pda", desc = "The user's pda")]
-    #[account(3, writable, name="user_wallet_ata", desc = "The user's wallet token account")]
-    #[account(4, name="global_admin", desc = "The global admin account")]
-    #[account(5, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
-    #[account(6, writable, name="treasury_ata", desc = "The token account of the treasury")]
-    #[account(7, writable, name="team_ata", desc = "The token account of the team")]
-    #[account(8, writable, name="new_bond_pda", desc = "The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index])")]
-    #[account(9, name="native_token_mint", desc = "The native token mint")]
-    #[account(10, name="token_program", desc = "The token program")]
-    #[account(11, name="associated_token_program", desc = "The associated token program (for the rewards pool)")]
-    #[account(12, name="system_program", desc = "The system program")]
+    #[account(3, writable, name="user_bond_page", desc = "The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])")]
+    #[account(4, writable, name="user_wallet_ata", desc = "The user's wallet token account")]
+    #[account(5, name="global_admin", desc = "The global admin account")]
+    #[account(6, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
+    #[account(7, writable, name="treasury_ata", desc = "The token account of the treasury")]
+    #[account(8, writable, name="principal_vault_ata", desc = "The token account of the principal vault, receiving the treasury share of an auto-compounded bond")]
+    #[account(9, writable, name="team_vesting_ata", desc = "The token account of the team vesting escrow, receiving the team share of an auto-compounded bond")]
+    #[account(10, writable, name="new_bond_pda", desc = "The program derived address for the new bond to be auto-compounded (seeds: ['bond', user_pda, next_bond_index])")]
+    #[account(11, writable, name="new_bond_page", desc = "The user's bond page the auto-compounded bond is tracked on (seeds: ['bond_page', user_pda, page_index])")]
+    #[account(12, writable, name="native_token_mint", desc = "The native token mint, written when penalties are burned or rewards minted")]
+    #[account(13, name="token_program", desc = "The token program")]
+    #[account(14, name="associated_token_program", desc = "The associated token program (for the rewards pool)")]
+    #[account(15, name="system_program", desc = "The system program")]
+    #[account(16, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(17, name="split_config", desc = "The deposit split config (seeds: ['split_config'])")]
+    #[account(18, name="reward_streams", desc = "The reward stream list (seeds: ['reward_streams'])")]
+    #[account(19, optional, name="bond_token_account", desc = "The signer's token account holding the bond token, required for tokenized bonds")]
+    #[account(20, optional, writable, name="referrer_ata", desc = "The wallet token account of the bond owner's referrer, required when the owner has one")]
+    #[account(21, optional, writable, name="referrer_stats", desc = "The stats of the bond owner's referrer (seeds: ['referrer_stats', referrer_wallet])")]
+    #[account(22, optional, writable, name="holder_user_pda", desc = "The signer's user pda, required when the signer holds a tokenized bond it does not own")]
+    #[account(23, optional, writable, name="holder_bond_page", desc = "The holder's current bond page, or the next one if it is full (seeds: ['bond_page', holder_user_pda, page_index])")]
+    #[account(24, optional, writable, name="owner_wallet", desc = "The wallet of the bond owner, required with holder_user_pda and receives the rent of the owner's bond page if it is emptied")]
     ProcessClaim {
-        bond_index: u8,
+        bond_index: u16,
         auto_compound: bool,
     },
 
     /// Updates the admin data.
     #[account(0, writable, name="global_admin", desc = "The program derived address of the global admin account to update (seeds: ['global_admin'])")]
     #[account(1, signer, name="authority", desc = "The authority of the global admin")]
+    #[account(2, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
     UpdateAdmin,
+
+    /// Redeems a bond early, refunding part of its unearned principal from the rewards pool and treasury shares of its deposit and closing it.
+    /// Remaining accounts: the bond's stream settlement (seeds: ['stream_settlement', reward_stream, bond]) of each stream of the reward stream list, in order, closed with the bond.
+    #[account(0, writable, name="bond", desc = "The program derived address of the bond account to redeem (seeds: ['bond', user_pda, bond_index])")]
+    #[account(1, signer, writable, name="user_wallet", desc = "The wallet of the user")]
+    #[account(2, writable, name="user_pda", desc = "The user's pda")]
+    #[account(3, writable, name="user_bond_page", desc = "The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])")]
+    #[account(4, writable, name="user_wallet_ata", desc = "The user's wallet token account")]
+    #[account(5, name="global_admin", desc = "The global admin account")]
+    #[account(6, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
+    #[account(7, name="native_token_mint", desc = "The native token mint")]
+    #[account(8, name="token_program", desc = "The token program")]
+    #[account(9, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(10, name="split_config", desc = "The deposit split config (seeds: ['split_config'])")]
+    #[account(11, name="principal_vault", desc = "The principal vault (seeds: ['principal_vault'])")]
+    #[account(12, writable, name="principal_vault_ata", desc = "The token account of the principal vault, refunding the pending treasury share")]
+    #[account(13, name="reward_streams", desc = "The reward stream list (seeds: ['reward_streams'])")]
+    RedeemBond {
+        bond_index: u16,
+    },
+
+    /// Transfers a bond to another user's account.
+    /// The owner is first paid the bond's pending rewards and its rewards of every attached reward stream, unless the bond is locked or fixed-term. Remaining accounts: for each stream of the reward stream list, in order, the stream, its pool, its mint, the owner's token account for it and the bond's stream settlement (seeds: ['stream_settlement', reward_stream, bond]).
+    #[account(0, writable, name="bond", desc = "The bond account to transfer (seeds: ['bond', bond.creator, bond_index])")]
+    #[account(1, signer, writable, name="owner_wallet", desc = "The wallet of the current owner, pays for the recipient's bond page if one is opened")]
+    #[account(2, writable, name="owner_user_pda", desc = "The current owner's user pda")]
+    #[account(3, writable, name="owner_bond_page", desc = "The current owner's bond page the bond is tracked on (seeds: ['bond_page', owner_user_pda, bond.page_index])")]
+    #[account(4, writable, name="owner_wallet_ata", desc = "The owner's wallet token account, receives the pending rewards")]
+    #[account(5, writable, name="recipient_user_pda", desc = "The recipient's user pda")]
+    #[account(6, writable, name="recipient_bond_page", desc = "The recipient's current bond page, or the next one if it is full (seeds: ['bond_page', recipient_user_pda, page_index])")]
+    #[account(7, name="global_admin", desc = "The global admin account")]
+    #[account(8, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(9, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
+    #[account(10, writable, name="treasury_ata", desc = "The token account of the treasury")]
+    #[account(11, writable, name="native_token_mint", desc = "The native token mint, written when penalties are burned or rewards minted")]
+    #[account(12, name="system_program", desc = "The system program")]
+    #[account(13, name="token_program", desc = "The token program")]
+    #[account(14, name="reward_streams", desc = "The reward stream list (seeds: ['reward_streams'])")]
+    TransferBond {
+        bond_index: u16,
+    },
+
+    /// Lists a bond for sale, locking it until it is sold or the listing is cancelled.
+    #[account(0, writable, name="bond", desc = "The bond account to list (seeds: ['bond', bond.creator, bond_index])")]
+    #[account(1, signer, writable, name="seller_wallet", desc = "The wallet of the seller, pays for the listing")]
+    #[account(2, name="seller_user_pda", desc = "The seller's user pda")]
+    #[account(3, name="seller_bond_page", desc = "The seller's bond page the bond is tracked on (seeds: ['bond_page', seller_user_pda, bond.page_index])")]
+    #[account(4, writable, name="listing", desc = "The listing account to create (seeds: ['listing', bond])")]
+    #[account(5, name="global_admin", desc = "The global admin account")]
+    #[account(6, name="system_program", desc = "The system program")]
+    ListBond {
+        bond_index: u16,
+        price: u64,
+    },
+
+    /// Cancels a bond listing and unlocks the bond.
+    #[account(0, writable, name="bond", desc = "The listed bond account (seeds: ['bond', bond.creator, bond_index])")]
+    #[account(1, signer, writable, name="seller_wallet", desc = "The wallet of the seller, receives the listing rent")]
+    #[account(2, writable, name="listing", desc = "The listing account to close (seeds: ['listing', bond])")]
+    CancelListing {
+        bond_index: u16,
+    },
+
+    /// Buys a listed bond, paying the seller and the marketplace fee in native tokens.
+    /// The seller is first paid the bond's pending rewards and its rewards of every attached reward stream, unless the bond is locked or fixed-term. Remaining accounts: for each stream of the reward stream list, in order, the stream, its pool, its mint, the seller's token account for it and the bond's stream settlement (seeds: ['stream_settlement', reward_stream, bond]).
+    #[account(0, writable, name="bond", desc = "The listed bond account (seeds: ['bond', bond.creator, bond_index])")]
+    #[account(1, signer, writable, name="buyer_wallet", desc = "The wallet of the buyer, pays for the buyer's bond page if one is opened")]
+    #[account(2, writable, name="buyer_user_pda", desc = "The buyer's user pda")]
+    #[account(3, writable, name="buyer_bond_page", desc = "The buyer's current bond page, or the next one if it is full (seeds: ['bond_page', buyer_user_pda, page_index])")]
+    #[account(4, writable, name="buyer_wallet_ata", desc = "The buyer's wallet token account")]
+    #[account(5, writable, name="seller_wallet", desc = "The wallet of the seller, receives the listing rent")]
+    #[account(6, writable, name="seller_user_pda", desc = "The seller's user pda")]
+    #[account(7, writable, name="seller_bond_page", desc = "The seller's bond page the bond is tracked on (seeds: ['bond_page', seller_user_pda, bond.page_index])")]
+    #[account(8, writable, name="seller_wallet_ata", desc = "The seller's wallet token account, receives the proceeds and the pending rewards")]
+    #[account(9, writable, name="listing", desc = "The listing account to close (seeds: ['listing', bond])")]
+    #[account(10, name="global_admin", desc = "The global admin account")]
+    #[account(11, writable, name="treasury_ata", desc = "The token account of the treasury")]
+    #[account(12, writable, name="native_token_mint", desc = "The native token mint, written when penalties are burned or rewards minted")]
+    #[account(13, name="token_program", desc = "The token program")]
+    #[account(14, name="system_program", desc = "The system program")]
+    #[account(15, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(16, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
+    #[account(17, name="reward_streams", desc = "The reward stream list (seeds: ['reward_streams'])")]
+    BuyBond {
+        bond_index: u16,
+        max_price: u64,
+    },
+
+    /// Merges a source bond into a target bond after settling both, combining their weight and remaining emission headroom.
+    /// Both bonds are also paid their rewards of every attached reward stream. Remaining accounts: for each stream of the reward stream list, in order, the stream, its pool, its mint, the user's token account for it, the target bond's stream settlement and the source bond's stream settlement (seeds: ['stream_settlement', reward_stream, bond]), closed with the source bond.
+    #[account(0, writable, name="target_bond", desc = "The bond account to merge into (seeds: ['bond', bond.creator, target_bond_index])")]
+    #[account(1, writable, name="source_bond", desc = "The bond account to merge and close (seeds: ['bond', bond.creator, source_bond_index])")]
+    #[account(2, signer, writable, name="user_wallet", desc = "The wallet of the user, receives the source bond rent")]
+    #[account(3, writable, name="user_pda", desc = "The user's pda")]
+    #[account(4, name="target_bond_page", desc = "The user's bond page the target bond is tracked on (seeds: ['bond_page', user_pda, target_bond.page_index])")]
+    #[account(5, writable, name="source_bond_page", desc = "The user's bond page the source bond is tracked on (seeds: ['bond_page', user_pda, source_bond.page_index])")]
+    #[account(6, writable, name="user_wallet_ata", desc = "The user's wallet token account")]
+    #[account(7, name="global_admin", desc = "The global admin account")]
+    #[account(8, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
+    #[account(9, writable, name="native_token_mint", desc = "The native token mint, written when penalties are burned or rewards minted")]
+    #[account(10, name="token_program", desc = "The token program")]
+    #[account(11, writable, name="treasury_ata", desc = "The token account of the treasury")]
+    #[account(12, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(13, name="system_program", desc = "The system program")]
+    #[account(14, name="reward_streams", desc = "The reward stream list (seeds: ['reward_streams'])")]
+    MergeBonds {
+        target_bond_index: u16,
+        source_bond_index: u16,
+    },
+
+    /// Splits part of a bond's weight and remaining emission headroom into a new bond after settling it.
+    /// The bond is also paid its rewards of every attached reward stream. Remaining accounts: for each stream of the reward stream list, in order, the stream, its pool, its mint, the user's token account for it, the bond's stream settlement and the new bond's stream settlement to create (seeds: ['stream_settlement', reward_stream, bond]).
+    #[account(0, writable, name="bond", desc = "The bond account to split (seeds: ['bond', bond.creator, bond_index])")]
+    #[account(1, writable, name="new_bond", desc = "The bond account to create (seeds: ['bond', user_pda, next_bond_index])")]
+    #[account(2, signer, writable, name="user_wallet", desc = "The wallet of the user")]
+    #[account(3, writable, name="user_pda", desc = "The user's pda")]
+    #[account(4, name="bond_page", desc = "The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])")]
+    #[account(5, writable, name="new_bond_page", desc = "The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index])")]
+    #[account(6, writable, name="user_wallet_ata", desc = "The user's wallet token account")]
+    #[account(7, name="global_admin", desc = "The global admin account")]
+    #[account(8, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
+    #[account(9, writable, name="native_token_mint", desc = "The native token mint, written when penalties are burned or rewards minted")]
+    #[account(10, name="token_program", desc = "The token program")]
+    #[account(11, name="system_program", desc = "The system program")]
+    #[account(12, writable, name="treasury_ata", desc = "The token account of the treasury")]
+    #[account(13, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(14, name="reward_streams", desc = "The reward stream list (seeds: ['reward_streams'])")]
+    SplitBond {
+        bond_index: u16,
+        split_weight: u32,
+    },
+
+    /// Settles a matured fixed-term bond: pays its fixed reward, returns its principal share and closes it.
+    /// The retained principal is paid to the recipients of the split config; the token accounts of its `Account` recipients follow as remaining accounts, in config order.
+    #[account(0, writable, name="bond", desc = "The bond account to settle (seeds: ['bond', user_pda, bond_index])")]
+    #[account(1, signer, writable, name="user_wallet", desc = "The wallet of the user")]
+    #[account(2, writable, name="user_pda", desc = "The user's pda")]
+    #[account(3, writable, name="user_bond_page", desc = "The user's bond page the bond is tracked on (seeds: ['bond_page', user_pda, bond.page_index])")]
+    #[account(4, writable, name="user_wallet_ata", desc = "The user's wallet token account")]
+    #[account(5, name="global_admin", desc = "The global admin account")]
+    #[account(6, writable, name="rewards_pool_ata", desc = "The rewards pool (token account) of the global admin")]
+    #[account(7, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(8, name="principal_vault", desc = "The principal vault (seeds: ['principal_vault'])")]
+    #[account(9, writable, name="principal_vault_ata", desc = "The token account of the principal vault")]
+    #[account(10, writable, name="team_vesting_ata", desc = "The token account of the team vesting escrow, receiving the team share of the retained principal")]
+    #[account(11, writable, name="native_token_mint", desc = "The native token mint, written when a deposit share is burned or the reward minted")]
+    #[account(12, name="token_program", desc = "The token program")]
+    #[account(13, name="split_config", desc = "The deposit split config (seeds: ['split_config'])")]
+    MatureBond {
+        bond_index: u16,
+    },
+
+    /// Releases the treasury share of bond deposits held in the principal vault once the distribution interval has passed. Permissionless.
+    #[account(0, name="global_admin", desc = "The global admin account")]
+    #[account(1, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(2, name="principal_vault", desc = "The principal vault (seeds: ['principal_vault'])")]
+    #[account(3, writable, name="principal_vault_ata", desc = "The token account of the principal vault")]
+    #[account(4, writable, name="treasury_ata", desc = "The token account of the treasury")]
+    #[account(5, name="native_token_mint", desc = "The native token mint")]
+    #[account(6, name="token_program", desc = "The token program")]
+    DistributeVault,
+
+    /// Releases the vested part of the team share of bond deposits from the team vesting escrow to the team. Permissionless.
+    #[account(0, name="global_admin", desc = "The global admin account")]
+    #[account(1, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(2, name="team_vesting", desc = "The team vesting escrow (seeds: ['team_vesting'])")]
+    #[account(3, writable, name="team_vesting_ata", desc = "The token account of the team vesting escrow")]
+    #[account(4, writable, name="team_ata", desc = "The token account of the team")]
+    #[account(5, name="native_token_mint", desc = "The native token mint")]
+    #[account(6, name="token_program", desc = "The token program")]
+    ReleaseVested,
+
+    /// Replaces the recipients of the deposit split.
+    #[account(0, name="global_admin", desc = "The global admin account")]
+    #[account(1, signer, name="authority", desc = "The authority of the global admin")]
+    #[account(2, writable, name="split_config", desc = "The deposit split config (seeds: ['split_config'])")]
+    UpdateSplitConfig {
+        recipients: Vec<SplitRecipient>,
+    },
+
+    /// Attaches a reward stream emitting a partner token to active bonds. The stream pool is funded by transferring tokens to it.
+    #[account(0, name="global_admin", desc = "The global admin account")]
+    #[account(1, signer, writable, name="authority", desc = "The authority of the global admin, paying for the new accounts")]
+    #[account(2, writable, name="reward_streams", desc = "The reward stream list, created on the first stream (seeds: ['reward_streams'])")]
+    #[account(3, writable, name="reward_stream", desc = "The reward stream to create (seeds: ['reward_stream', stream_mint])")]
+    #[account(4, writable, name="stream_pool_ata", desc = "The token account of the reward stream holding its rewards (created if empty)")]
+    #[account(5, name="stream_mint", desc = "The mint of the partner token")]
+    #[account(6, name="system_program", desc = "The system program")]
+    #[account(7, name="token_program", desc = "The token program")]
+    #[account(8, name="associated_token_program", desc = "The associated token program")]
+    AttachRewardStream {
+        rate: u64,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    },
+
+    /// Grants a bond to a wallet without a deposit, creating its user PDA if needed. Limited to the grant authority, who pays for the new accounts.
+    #[account(0, name="global_admin", desc = "The global admin account")]
+    #[account(1, signer, writable, name="grant_authority", desc = "The grant authority of the global admin")]
+    #[account(2, name="user_wallet", desc = "The wallet receiving the bond")]
+    #[account(3, writable, name="user_pda", desc = "The user's pda, created if needed (seeds: ['user', user_wallet])")]
+    #[account(4, writable, name="user_bond_page", desc = "The user's current bond page, or the next one if it is full (seeds: ['bond_page', user_pda, page_index])")]
+    #[account(5, writable, name="bond", desc = "The program derived address of the bond account to create (seeds: ['bond', user_pda, bond_index])")]
+    #[account(6, writable, name="global_state", desc = "The global state account (seeds: ['global_state'])")]
+    #[account(7, name="system_program", desc = "The system program")]
+    GrantBond {
+        grant_source: GrantSource,
+    },
+
+    /// Detaches a reward stream that has ended from the reward stream list, sweeping its pool to the authority and closing the stream so its mint can be attached again. Rewards of the stream still owed to bonds are forfeited.
+    #[account(0, name="global_admin", desc = "The global admin account")]
+    #[account(1, signer, writable, name="authority", desc = "The authority of the global admin, receiving the rent of the stream")]
+    #[account(2, writable, name="reward_streams", desc = "The reward stream list (seeds: ['reward_streams'])")]
+    #[account(3, writable, name="reward_stream", desc = "The reward stream to detach (seeds: ['reward_stream', stream_mint])")]
+    #[account(4, writable, name="stream_pool_ata", desc = "The token account of the reward stream holding its rewards")]
+    #[account(5, name="stream_mint", desc = "The mint of the partner token")]
+    #[account(6, writable, name="authority_stream_ata", desc = "The authority's token account for the stream mint, receiving the rest of the pool")]
+    #[account(7, name="token_program", desc = "The token program")]
+    DetachRewardStream,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct InitializeBondPayload {
+    pub tokenize: bool,
+    pub lock_tier: Option<u8>, // Index into `Admin::lock_tiers`, or None for no lock-up.
+    pub fixed_term: bool, // Escrow the deposit for `Admin::fixed_term`.
+    pub allowlist_proof: Option<AllowlistProof>, // Required while `Admin::allowlist` is running.
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 pub struct ProcessClaimPayload {
-    pub bond_index: u8,
+    pub bond_index: u16,
     pub auto_compound: bool,
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct RedeemBondPayload {
+    pub bond_index: u16,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct TransferBondPayload {
+    pub bond_index: u16,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct ListBondPayload {
+    pub bond_index: u16,
+    pub price: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct CancelListingPayload {
+    pub bond_index: u16,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct BuyBondPayload {
+    pub bond_index: u16,
+    pub max_price: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct MergeBondsPayload {
+    pub target_bond_index: u16,
+    pub source_bond_index: u16,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct SplitBondPayload {
+    pub bond_index: u16,
+    pub split_weight: u32,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct MatureBondPayload {
+    pub bond_index: u16,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
 pub struct UpdateAdminPayload {
     pub new_admin_data: Admin,
 }
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct UpdateSplitConfigPayload {
+    pub recipients: Vec<SplitRecipient>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct AttachRewardStreamPayload {
+    pub rate: u64, // Base units of the stream token per second per standard bond.
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug)]
+pub struct GrantBondPayload {
+    pub grant_source: GrantSource,
+}
@@ -1,23 +1,59 @@
 use crate::assertions::{
-    assert_empty, assert_non_empty, assert_pda, assert_same_pubkeys,
-    assert_signer, assert_valid_associated_token_program,
-    assert_valid_bond, assert_valid_system_program,
-    assert_valid_token_account, assert_valid_token_program,
+    assert_bond_holder, assert_bond_not_tokenized,
+    assert_bond_perpetual, assert_bond_unlocked, assert_empty,
+    assert_non_empty, assert_pda, assert_program_owner,
+    assert_same_pubkeys, assert_signer, assert_valid_allowlist_phase,
+    assert_valid_associated_token_program, assert_valid_bond,
+    assert_valid_emission_curve, assert_valid_fixed_term,
+    assert_valid_lock_tiers, assert_valid_loyalty_schedule,
+    assert_valid_penalty_schedule, assert_valid_referral_rates,
+    assert_valid_reward_stream, assert_valid_split_config,
+    assert_valid_system_program, assert_valid_token_account,
+    assert_valid_token_program, assert_valid_vesting_schedule,
 };
 use crate::constants::{
-    CLAIM_PENALTY, DAILY_EMISSION_RATE, MAX_EMISSION_PER_BOND,
-    SHELLS_PER_TESTUDO,
+    BOND_PRICE, CLAIM_PENALTY, CLAIM_PENALTY_PERIOD,
+    DAILY_EMISSION_RATE, MARKETPLACE_FEE, MAX_EMISSION_PER_BOND,
+    REDEMPTION_RATE, SHELLS_PER_TESTUDO, STANDARD_BOND_WEIGHT,
 };
 use crate::error::TestudoBondsError;
-use crate::instruction::{ProcessClaimPayload, UpdateAdminPayload};
-use crate::state::{Admin, Bond, Serialization, UserAccount};
-use crate::utils::realloc_account;
+use crate::instruction::{
+    AttachRewardStreamPayload, BuyBondPayload, CancelListingPayload,
+    GrantBondPayload, InitializeBondPayload, ListBondPayload,
+    MatureBondPayload, MergeBondsPayload, ProcessClaimPayload,
+    RedeemBondPayload, SplitBondPayload, TransferBondPayload,
+    UpdateAdminPayload, UpdateSplitConfigPayload,
+};
+use crate::state::{
+    Admin, AllowlistPhase, AllowlistProof, Bond, EmissionCurve,
+    EmissionMode, FixedTermConfig, GlobalState, GrantSource, Listing,
+    LockTier, LoyaltyTier, PenaltyDestination, PenaltyTier,
+    ReferrerStats, RewardSource, RewardStream, RewardStreamList,
+    Serialization, SplitConfig, SplitDestination, SplitRecipient,
+    StreamSettlement, UserAccount, VestingSchedule, VestingTranche,
+};
 use crate::utils::{
     account_utils::{close_account, create_account},
+    bond_page_utils::{
+        load_bond_page, load_open_bond_page, move_bond,
+        remove_bond_from_page,
+    },
+    bond_utils::load_bond,
     calculation_utils::{
-        calculate_reward, calculate_token_deposit_split,
+        calculate_basis_points, calculate_bond_penalty_share,
+        calculate_bond_reward, calculate_marketplace_fee,
+        calculate_merged_creation_timestamp,
+        calculate_penalty_per_weight, calculate_redemption_amount,
+        calculate_redemption_sources, calculate_reward_per_weight,
+        calculate_stream_reward, calculate_team_vested_amount,
+        calculate_token_deposit_split, calculate_vested_amount,
+        calculate_weighted_amount, ClaimReward,
+    },
+    merkle_utils::{allowlist_leaf, verify_merkle_proof},
+    token_utils::{
+        burn_spl_tokens, create_ata, create_mint, mint_spl_tokens,
+        transfer_spl_tokens,
     },
-    token_utils::{create_ata, transfer_spl_tokens},
 };
 use borsh::BorshDeserialize;
 use solana_program::clock::Clock;
@@ -28,7 +64,7 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg,
     pubkey::Pubkey,
 };
-use spl_token::state::Account as TokenAccount;
+use spl_token::state::{Account as TokenAccount, Mint};
 
 pub fn process_instruction<'a>(
     program_id: &Pubkey,
@@ -54,8 +90,17 @@ pub fn process_instruction<'a>(
             initialize_user(program_id, accounts)
         }
         2 => {
+            let payload: InitializeBondPayload =
+                InitializeBondPayload::try_from_slice(rest)?;
             msg!("Instruction: InitializeBond");
-            initialize_bond(program_id, accounts)
+            initialize_bond(
+                program_id,
+                accounts,
+                payload.tokenize,
+                payload.lock_tier,
+                payload.fixed_term,
+                payload.allowlist_proof,
+            )
         }
         3 => {
             let payload: ProcessClaimPayload =
@@ -78,6 +123,114 @@ pub fn process_instruction<'a>(
                 payload.new_admin_data,
             )
         }
+        5 => {
+            let payload: RedeemBondPayload =
+                RedeemBondPayload::try_from_slice(rest)?;
+            msg!("Instruction: RedeemBond");
+            redeem_bond(program_id, accounts, payload.bond_index)
+        }
+        6 => {
+            let payload: TransferBondPayload =
+                TransferBondPayload::try_from_slice(rest)?;
+            msg!("Instruction: TransferBond");
+            transfer_bond(program_id, accounts, payload.bond_index)
+        }
+        7 => {
+            let payload: ListBondPayload =
+                ListBondPayload::try_from_slice(rest)?;
+            msg!("Instruction: ListBond");
+            list_bond(
+                program_id,
+                accounts,
+                payload.bond_index,
+                payload.price,
+            )
+        }
+        8 => {
+            let payload: CancelListingPayload =
+                CancelListingPayload::try_from_slice(rest)?;
+            msg!("Instruction: CancelListing");
+            cancel_listing(program_id, accounts, payload.bond_index)
+        }
+        9 => {
+            let payload: BuyBondPayload =
+                BuyBondPayload::try_from_slice(rest)?;
+            msg!("Instruction: BuyBond");
+            buy_bond(
+                program_id,
+                accounts,
+                payload.bond_index,
+                payload.max_price,
+            )
+        }
+        10 => {
+            let payload: MergeBondsPayload =
+                MergeBondsPayload::try_from_slice(rest)?;
+            msg!("Instruction: MergeBonds");
+            merge_bonds(
+                program_id,
+                accounts,
+                payload.target_bond_index,
+                payload.source_bond_index,
+            )
+        }
+        11 => {
+            let payload: SplitBondPayload =
+                SplitBondPayload::try_from_slice(rest)?;
+            msg!("Instruction: SplitBond");
+            split_bond(
+                program_id,
+                accounts,
+                payload.bond_index,
+                payload.split_weight,
+            )
+        }
+        12 => {
+            let payload: MatureBondPayload =
+                MatureBondPayload::try_from_slice(rest)?;
+            msg!("Instruction: MatureBond");
+            mature_bond(program_id, accounts, payload.bond_index)
+        }
+        13 => {
+            msg!("Instruction: DistributeVault");
+            distribute_vault(program_id, accounts)
+        }
+        14 => {
+            msg!("Instruction: ReleaseVested");
+            release_vested(program_id, accounts)
+        }
+        15 => {
+            let payload: UpdateSplitConfigPayload =
+                UpdateSplitConfigPayload::try_from_slice(rest)?;
+            msg!("Instruction: UpdateSplitConfig");
+            update_split_config(
+                program_id,
+                accounts,
+                payload.recipients,
+            )
+        }
+        16 => {
+            let payload: AttachRewardStreamPayload =
+                AttachRewardStreamPayload::try_from_slice(rest)?;
+            msg!("Instruction: AttachRewardStream");
+            attach_reward_stream(
+                program_id,
+                accounts,
+                payload.rate,
+                payload.start_timestamp,
+                payload.end_timestamp,
+            )
+        }
+        17 => {
+            let payload: GrantBondPayload =
+                GrantBondPayload::try_from_slice(rest)?;
+            msg!("Instruction: GrantBond");
+            grant_bond(program_id, accounts, payload.grant_source)
+        }
+        18 => {
+            msg!("Instruction: DetachRewardStream");
+            detach_reward_stream(program_id, accounts)
+        }
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -90,7 +243,7 @@ fn initialize_admin<'a>(
     claim_penalty: u16,
 ) -> ProgramResult {
     // Extract accounts
-    let [admin_pda, authority, rewards_pool_ata, treasury, treasury_ata, team, team_ata, native_token_mint, system_program, token_program, associated_token_program] =
+    let [admin_pda, authority, rewards_pool_ata, treasury, treasury_ata, team, team_ata, native_token_mint, system_program, token_program, associated_token_program, global_state, principal_vault, principal_vault_ata, team_vesting, team_vesting_ata, split_config] =
         &accounts
     else {
         return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
@@ -104,6 +257,32 @@ fn initialize_admin<'a>(
         &[b"global_admin"],
     )?;
     assert_empty("Global admin", admin_pda)?;
+    let global_state_bump: u8 = assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_empty("Global state", global_state)?;
+    assert_pda(
+        "Principal Vault PDA",
+        principal_vault,
+        program_id,
+        &[b"principal_vault"],
+    )?;
+    assert_pda(
+        "Team Vesting PDA",
+        team_vesting,
+        program_id,
+        &[b"team_vesting"],
+    )?;
+    let split_config_bump: u8 = assert_pda(
+        "Split Config PDA",
+        split_config,
+        program_id,
+        &[b"split_config"],
+    )?;
+    assert_empty("Split config", split_config)?;
 
     // Validate program accounts
     assert_valid_system_program(system_program.key)?;
@@ -125,7 +304,13 @@ fn initialize_admin<'a>(
         Some(&[&[b"global_admin", &[admin_bump]]]),
     )?;
 
-    // Initialize admin data
+    // Initialize admin data with a single penalty tier
+    let mut claim_penalty_schedule =
+        [PenaltyTier::default(); Admin::MAX_PENALTY_TIERS];
+    claim_penalty_schedule[0] = PenaltyTier {
+        max_elapsed_seconds: CLAIM_PENALTY_PERIOD,
+        penalty_bps: claim_penalty,
+    };
     let admin_data: Admin = Admin {
         authority: *authority.key,
         treasury: *treasury_ata.key,
@@ -135,13 +320,93 @@ fn initialize_admin<'a>(
         daily_emission_rate,
         max_emission_per_bond,
         max_bonds_per_wallet: 10,
-        token_deposit_split: [4000, 4000, 2000], // [rewards pool, treasury, team]
-        claim_penalty,
+        claim_penalty_schedule,
         pause_bond_operations: false,
+        redemption_rate: REDEMPTION_RATE,
+        marketplace_fee: MARKETPLACE_FEE,
+        penalty_destination: PenaltyDestination::RewardsPool,
+        emission_mode: EmissionMode::PerBond,
+        global_emission_rate: 0,
+        emission_curve: EmissionCurve {
+            start_timestamp: Clock::get()?.unix_timestamp,
+            period_seconds: 0,
+            decay_bps: 0,
+        },
+        loyalty_schedule: [LoyaltyTier::default();
+            Admin::MAX_LOYALTY_TIERS],
+        lock_tiers: [LockTier::default(); Admin::MAX_LOCK_TIERS],
+        fixed_term: FixedTermConfig::default(),
+        distribution_interval: 604_800, // 7 days
+        team_vesting: VestingSchedule {
+            start_timestamp: Clock::get()?.unix_timestamp,
+            cliff_seconds: 15_552_000, // 180 days
+            duration_seconds: 63_072_000, // 2 years
+        },
+        reward_source: RewardSource::Pool,
+        emission_ceiling: 0,
+        referral_deposit_bps: 0,
+        referral_claim_bps: 0,
+        allowlist: AllowlistPhase::default(),
+        grant_authority: *authority.key,
     };
 
     admin_data.serialize_account_data(admin_pda)?;
 
+    // Create global state account
+    create_account(
+        global_state,
+        authority,
+        system_program,
+        GlobalState::SIZE,
+        program_id,
+        Some(&[&[b"global_state", &[global_state_bump]]]),
+    )?;
+
+    let global_state_data: GlobalState = GlobalState {
+        total_penalties: 0,
+        total_active_weight: 0,
+        penalty_per_weight: 0,
+        reward_per_weight: 0,
+        last_emission_update: Clock::get()?.unix_timestamp,
+        pending_treasury: 0,
+        last_vault_distribution: Clock::get()?.unix_timestamp,
+        team_vesting_deposited: 0,
+        team_vesting_released: 0,
+        team_vesting_tranches: [VestingTranche::default();
+            GlobalState::MAX_VESTING_TRANCHES],
+        team_vesting_queued: 0,
+        total_burned: 0,
+        total_minted: 0,
+    };
+    global_state_data.serialize_account_data(global_state)?;
+
+    // Create the deposit split config: 40% rewards pool, 40% treasury
+    // and 20% team
+    create_account(
+        split_config,
+        authority,
+        system_program,
+        SplitConfig::SIZE,
+        program_id,
+        Some(&[&[b"split_config", &[split_config_bump]]]),
+    )?;
+
+    let split_config_data = SplitConfig {
+        recipients: [
+            (SplitDestination::RewardsPool, 4000),
+            (SplitDestination::Treasury, 4000),
+            (SplitDestination::Team, 2000),
+        ]
+        .into_iter()
+        .map(|(destination, bps)| SplitRecipient {
+            destination,
+            token_account: Pubkey::default(),
+            bps,
+        })
+        .collect(),
+    };
+    split_config_data.serialize_account_data(split_config)?;
+
     // Create associated token accounts if they don't exist
     if treasury_ata.data_len() != TokenAccount::LEN {
         create_ata(
@@ -180,6 +445,30 @@ fn initialize_admin<'a>(
         )?;
     }
     msg!("Created rewards pool ATA");
+    if principal_vault_ata.data_len() != TokenAccount::LEN {
+        create_ata(
+            authority,
+            system_program,
+            token_program,
+            native_token_mint,
+            principal_vault,
+            principal_vault_ata,
+            None,
+        )?;
+    }
+    msg!("Created principal vault ATA");
+    if team_vesting_ata.data_len() != TokenAccount::LEN {
+        create_ata(
+            authority,
+            system_program,
+            token_program,
+            native_token_mint,
+            team_vesting,
+            team_vesting_ata,
+            None,
+        )?;
+    }
+    msg!("Created team vesting ATA");
     Ok(())
 }
 
@@ -189,7 +478,7 @@ pub fn update_admin_data<'a>(
     new_admin_data: Admin,
 ) -> ProgramResult {
     // Extract accounts
-    let [admin_pda, authority] = &accounts else {
+    let [admin_pda, authority, global_state] = &accounts else {
         return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
     };
 
@@ -201,6 +490,13 @@ pub fn update_admin_data<'a>(
         &[b"global_admin"],
     )?;
     assert_non_empty("Admin PDA", admin_pda)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
 
     // Validate signers
     assert_signer("Authority", authority)?;
@@ -217,6 +513,36 @@ pub fn update_admin_data<'a>(
         &admin_data.authority,
     )?;
 
+    // Validate new admin data
+    assert_valid_penalty_schedule(
+        &new_admin_data.claim_penalty_schedule,
+    )?;
+    assert_valid_emission_curve(&new_admin_data.emission_curve)?;
+    assert_valid_loyalty_schedule(&new_admin_data.loyalty_schedule)?;
+    assert_valid_lock_tiers(&new_admin_data.lock_tiers)?;
+    assert_valid_fixed_term(&new_admin_data.fixed_term)?;
+    assert_valid_referral_rates(
+        new_admin_data.referral_deposit_bps,
+        new_admin_data.referral_claim_bps,
+    )?;
+    assert_valid_allowlist_phase(&new_admin_data.allowlist)?;
+    assert_valid_vesting_schedule(
+        &new_admin_data.team_vesting,
+        &admin_data.team_vesting,
+    )?;
+
+    // Close out the reward index under the old emission settings
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    update_reward_index(
+        &mut global_state_data,
+        &admin_data,
+        Clock::get()?.unix_timestamp,
+    )?;
+    global_state_data.serialize_account_data(global_state)?;
+
     // Update admin data
     new_admin_data.serialize_account_data(admin_pda)?;
 
@@ -249,7 +575,7 @@ fn initialize_user<'a>(
         user_pda,
         user_wallet,
         system_program,
-        UserAccount::INITIAL_SIZE,
+        UserAccount::SIZE,
         program_id,
         Some(&[&[b"user", user_wallet.key.as_ref(), &[bump]]]),
     )?;
@@ -260,7 +586,11 @@ fn initialize_user<'a>(
         bond_count: 0,
         total_accrued_rewards: 0,
         bond_index: 0,
-        active_bonds: Vec::new(),
+        page_count: 0,
+        total_penalties: 0,
+        referrer: Pubkey::default(),
+        allowlist_root: [0; 32],
+        allowlist_used: 0,
     };
     user_pda_data.serialize_account_data(user_pda)?;
 
@@ -275,10 +605,14 @@ fn initialize_user<'a>(
 fn initialize_bond<'a>(
     program_id: &Pubkey,
     accounts: &'a [AccountInfo<'a>],
+    tokenize: bool,
+    lock_tier: Option<u8>,
+    fixed_term: bool,
+    allowlist_proof: Option<AllowlistProof>,
 ) -> ProgramResult {
     msg!("Initializing bond");
     // Extract accounts
-    let [bond_pda, user_wallet, user_pda, global_admin, user_wallet_ata, rewards_pool_ata, treasury_ata, team_ata, native_token_mint, system_program, token_program] =
+    let [bond_pda, user_wallet, user_pda, user_bond_page, global_admin, user_wallet_ata, rewards_pool_ata, principal_vault_ata, team_vesting_ata, native_token_mint, system_program, token_program, global_state, split_config, bond_mint, bond_mint_authority, user_bond_token_account, associated_token_program, referrer_pda, referrer_ata, referrer_stats, recipient_accounts @ ..] =
         &accounts
     else {
         return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
@@ -304,7 +638,7 @@ fn initialize_bond<'a>(
     msg!("🔍 User PDA account size: {} bytes", user_pda.data_len());
     msg!(
         "🔍 Expected UserAccount::SIZE: {} bytes",
-        UserAccount::INITIAL_SIZE
+        UserAccount::SIZE
     );
 
     let mut user_pda_data = UserAccount::deserialize_account_data(
@@ -327,7 +661,7 @@ fn initialize_bond<'a>(
         &[
             b"bond",
             user_pda.key.as_ref(),
-            &[user_pda_data.bond_index],
+            &user_pda_data.bond_index.to_le_bytes(),
         ],
     )?;
     msg!("✅ Bond PDA address validated with bump: {}", bond_bump);
@@ -347,11 +681,28 @@ fn initialize_bond<'a>(
     assert_non_empty("Global Admin PDA", global_admin)?;
     msg!("✅ Global Admin PDA account exists and is non-empty");
 
+    // Validate global state
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    msg!("✅ Global State PDA validated");
+
     // Validate token accounts and mint
     let global_admin_data = Admin::deserialize_account_data(
         global_admin.data.borrow_mut().as_ref(),
     )?;
     msg!("✅ Global Admin data deserialized successfully");
+    let split_config_data =
+        load_split_config(program_id, split_config)?;
+    msg!("✅ Split Config data deserialized successfully");
     assert_same_pubkeys(
         "Rewards Pool ATA",
         rewards_pool_ata,
@@ -359,20 +710,6 @@ fn initialize_bond<'a>(
     )?;
     msg!("✅ Rewards Pool ATA address validated");
 
-    assert_same_pubkeys(
-        "Treasury ATA",
-        treasury_ata,
-        &global_admin_data.treasury,
-    )?;
-    msg!("✅ Treasury ATA address validated");
-
-    assert_same_pubkeys(
-        "Team ATA",
-        team_ata,
-        &global_admin_data.team,
-    )?;
-    msg!("✅ Team ATA address validated");
-
     assert_same_pubkeys(
         "Native Token Mint",
         native_token_mint,
@@ -380,6 +717,28 @@ fn initialize_bond<'a>(
     )?;
     msg!("✅ Native Token Mint address validated");
 
+    let (principal_vault, _) = Pubkey::find_program_address(
+        &[b"principal_vault"],
+        program_id,
+    );
+    assert_valid_token_account(
+        "Principal Vault ATA",
+        &principal_vault,
+        native_token_mint.key,
+        principal_vault_ata,
+    )?;
+    msg!("✅ Principal Vault ATA address validated");
+
+    let (team_vesting, _) =
+        Pubkey::find_program_address(&[b"team_vesting"], program_id);
+    assert_valid_token_account(
+        "Team Vesting ATA",
+        &team_vesting,
+        native_token_mint.key,
+        team_vesting_ata,
+    )?;
+    msg!("✅ Team Vesting ATA address validated");
+
     assert_valid_token_account(
         "User Wallet ATA",
         user_wallet.key,
@@ -388,6 +747,24 @@ fn initialize_bond<'a>(
     )?;
     msg!("✅ User Wallet ATA validated");
 
+    // Record the referrer on the first bond it is passed with
+    set_referrer(
+        program_id,
+        &mut user_pda_data,
+        referrer_pda,
+        referrer_stats,
+        user_wallet,
+        system_program,
+    )?;
+    let mut referrer_stats_data = load_referrer_stats(
+        program_id,
+        &user_pda_data,
+        referrer_ata,
+        referrer_stats,
+        native_token_mint,
+    )?;
+    msg!("✅ Referrer validated");
+
     // Assert Admin has not paused bond operations
     if global_admin_data.pause_bond_operations {
         msg!("❌ Bond operations are paused by admin");
@@ -395,6 +772,13 @@ fn initialize_bond<'a>(
     }
     msg!("✅ Bond operations are active (not paused)");
 
+    use_allowlist_allocation(
+        &global_admin_data.allowlist,
+        &mut user_pda_data,
+        allowlist_proof.as_ref(),
+        Clock::get()?.unix_timestamp,
+    )?;
+
     // Validate program accounts
     assert_valid_system_program(system_program.key)?;
     msg!("✅ System program validated");
@@ -402,14 +786,6 @@ fn initialize_bond<'a>(
     assert_valid_token_program(token_program.key)?;
     msg!("✅ Token program validated");
 
-    // Transfer tokens from User to reward_pool, treasury & team
-    let token_deposit_split: [u16; 3] =
-        global_admin_data.token_deposit_split;
-    msg!(
-        "✅ Token deposit split configuration loaded: {:?}",
-        token_deposit_split
-    );
-
     let user_balance = TokenAccount::unpack(
         user_wallet_ata.data.borrow_mut().as_ref(),
     )?;
@@ -451,58 +827,99 @@ fn initialize_bond<'a>(
         SHELLS_PER_TESTUDO * 10
     );
 
-    // Calculate token deposit split
-    let token_deposit_split =
-        calculate_token_deposit_split(token_deposit_split);
-    msg!("✅ Token amounts calculated - Rewards: {}, Treasury: {}, Team: {}", 
-        token_deposit_split[0], token_deposit_split[1], token_deposit_split[2]);
-
-    // Transfer tokens from User to reward_pool
-    transfer_spl_tokens(
-        token_program,
-        user_wallet_ata,
-        native_token_mint,
-        rewards_pool_ata,
-        user_wallet,
-        token_deposit_split[0],
-        9,
-        None,
-    )?;
-    msg!(
-        "✅ Transferred {} tokens from User to reward_pool",
-        token_deposit_split[0]
-    );
+    if fixed_term
+        && (global_admin_data.fixed_term.term_seconds == 0
+            || tokenize
+            || lock_tier.is_some())
+    {
+        msg!("❌ Fixed-term bonds are disabled or combined with tokenization or a lock-up");
+        return Err(TestudoBondsError::InvalidFixedTerm.into());
+    }
 
-    // Transfer tokens from User to treasury
-    transfer_spl_tokens(
-        token_program,
-        user_wallet_ata,
-        native_token_mint,
-        treasury_ata,
-        user_wallet,
-        token_deposit_split[1],
-        9,
-        None,
-    )?;
-    msg!(
-        "✅ Transferred {} tokens from User to treasury",
-        token_deposit_split[1]
-    );
+    // Fixed-term bonds escrow the whole deposit until maturity. Other
+    // bonds pay the referrer's share, if any, and the rest to the
+    // recipients of the split config.
+    if fixed_term {
+        transfer_spl_tokens(
+            token_program,
+            user_wallet_ata,
+            native_token_mint,
+            principal_vault_ata,
+            user_wallet,
+            BOND_PRICE,
+            9,
+            None,
+        )?;
+        msg!(
+            "✅ Transferred {} tokens from User to the principal vault",
+            BOND_PRICE
+        );
+    } else {
+        let mut split_amount = BOND_PRICE;
+        if let Some(referrer_stats_data) =
+            referrer_stats_data.as_mut()
+        {
+            let referral_share = calculate_basis_points(
+                BOND_PRICE,
+                global_admin_data.referral_deposit_bps,
+            )?;
+            if referral_share > 0 {
+                transfer_spl_tokens(
+                    token_program,
+                    user_wallet_ata,
+                    native_token_mint,
+                    referrer_ata,
+                    user_wallet,
+                    referral_share,
+                    9,
+                    None,
+                )?;
+                referrer_stats_data.total_deposit_rewards =
+                    referrer_stats_data
+                        .total_deposit_rewards
+                        .checked_add(referral_share)
+                        .ok_or(
+                            TestudoBondsError::NumericalOverflow,
+                        )?;
+                referrer_stats_data
+                    .serialize_account_data(referrer_stats)?;
+                split_amount -= referral_share;
+                msg!(
+                    "✅ Paid {} tokens of the deposit to the referrer",
+                    referral_share
+                );
+            }
+        }
+        pay_deposit_split(
+            &split_config_data,
+            split_amount,
+            user_wallet_ata,
+            user_wallet,
+            None,
+            rewards_pool_ata,
+            principal_vault_ata,
+            team_vesting_ata,
+            native_token_mint,
+            token_program,
+            recipient_accounts,
+            &global_admin_data.team_vesting,
+            &mut global_state_data,
+        )?;
+        msg!("✅ Deposit split paid from User");
+    }
 
-    // Transfer tokens from User to team
-    transfer_spl_tokens(
-        token_program,
-        user_wallet_ata,
-        native_token_mint,
-        team_ata,
+    // Load the bond page the new bond is tracked on
+    let mut user_bond_page_data = load_open_bond_page(
+        program_id,
+        user_pda,
+        &mut user_pda_data,
+        user_bond_page,
         user_wallet,
-        token_deposit_split[2],
-        9,
-        None,
+        system_program,
     )?;
     msg!(
-        "✅ Transferred {} tokens from User to team",
-        token_deposit_split[2]
+        "✅ User bond page [{}] loaded",
+        user_bond_page_data.page_index
     );
 
     // Create bond account
@@ -515,22 +932,154 @@ fn initialize_bond<'a>(
         Some(&[&[
             b"bond",
             user_pda.key.as_ref(),
-            &[user_pda_data.bond_index],
+            &user_pda_data.bond_index.to_le_bytes(),
             &[bond_bump],
         ]]),
     )?;
     msg!("✅ Bond account created with size: {} bytes", Bond::SIZE);
 
+    // Mint the one-of-one bond token if requested
+    let mut bond_mint_key = Pubkey::default();
+    if tokenize {
+        let bond_mint_bump = assert_pda(
+            "Bond Mint",
+            bond_mint,
+            program_id,
+            &[b"bond_mint", bond_pda.key.as_ref()],
+        )?;
+        assert_empty("Bond Mint", bond_mint)?;
+        let bond_mint_authority_bump = assert_pda(
+            "Bond Mint Authority",
+            bond_mint_authority,
+            program_id,
+            &[b"bond_mint_authority"],
+        )?;
+        assert_valid_token_account(
+            "User Bond Token Account",
+            user_wallet.key,
+            bond_mint.key,
+            user_bond_token_account,
+        )?;
+        assert_valid_associated_token_program(
+            associated_token_program.key,
+        )?;
+
+        create_mint(
+            user_wallet,
+            system_program,
+            token_program,
+            bond_mint,
+            bond_mint_authority.key,
+            0,
+            Some(&[&[
+                b"bond_mint",
+                bond_pda.key.as_ref(),
+                &[bond_mint_bump],
+            ]]),
+        )?;
+        create_ata(
+            user_wallet,
+            system_program,
+            token_program,
+            bond_mint,
+            user_wallet,
+            user_bond_token_account,
+            None,
+        )?;
+        mint_spl_tokens(
+            token_program,
+            bond_mint,
+            user_bond_token_account,
+            bond_mint_authority,
+            1,
+            0,
+            Some(&[&[
+                b"bond_mint_authority",
+                &[bond_mint_authority_bump],
+            ]]),
+        )?;
+        bond_mint_key = *bond_mint.key;
+        msg!("✅ Bond token minted: {}", bond_mint_key);
+    }
+
     // Initialize bond data with current timestamp
     let timestamp: i64 = Clock::get()?.unix_timestamp;
+    update_reward_index(
+        &mut global_state_data,
+        &global_admin_data,
+        timestamp,
+    )?;
+
+    // Resolve the optional lock-up chosen by the user. Global emission
+    // shares the emission by weight alone and would give no boost.
+    let (lock_until, lock_boost_bps) = match lock_tier {
+        Some(_)
+            if global_admin_data.emission_mode
+                == EmissionMode::Global =>
+        {
+            msg!("❌ Lock-up tiers are unavailable in global emission mode");
+            return Err(TestudoBondsError::InvalidLockTier.into());
+        }
+        Some(lock_tier) => {
+            let tier = global_admin_data
+                .lock_tiers
+                .get(lock_tier as usize)
+                .filter(|tier| tier.lock_seconds > 0)
+                .ok_or(TestudoBondsError::InvalidLockTier)?;
+            let lock_until = timestamp
+                .checked_add(tier.lock_seconds as i64)
+                .ok_or(TestudoBondsError::NumericalOverflow)?;
+            msg!(
+                "✅ Bond locked until {} for a {} bps boost",
+                lock_until,
+                tier.boost_bps
+            );
+            (lock_until, tier.boost_bps)
+        }
+        None => (0, 0),
+    };
+    let maturity_timestamp = if fixed_term {
+        timestamp
+            .checked_add(
+                global_admin_data.fixed_term.term_seconds as i64,
+            )
+            .ok_or(TestudoBondsError::NumericalOverflow)?
+    } else {
+        0
+    };
     let bond_pda_data: Bond = Bond {
         owner: *user_pda.key,
+        creator: *user_pda.key,
         bond_index: user_pda_data.bond_index,
+        page_index: user_bond_page_data.page_index,
         creation_timestamp: timestamp,
         last_claim_timestamp: timestamp,
         total_claimed: 0,
         // accrued_rewards: 0,
         is_active: true,
+        mint: bond_mint_key,
+        is_listed: false,
+        weight: STANDARD_BOND_WEIGHT,
+        penalty_per_weight_checkpoint: global_state_data
+            .penalty_per_weight,
+        reward_remainder: 0,
+        reward_per_weight_checkpoint: global_state_data
+            .reward_per_weight,
+        lock_until,
+        lock_boost_bps,
+        maturity_timestamp,
+        principal: if fixed_term { BOND_PRICE } else { 0 },
+        maturity_reward: if fixed_term {
+            global_admin_data.fixed_term.reward
+        } else {
+            0
+        },
+        principal_return_bps: if fixed_term {
+            global_admin_data.fixed_term.principal_return_bps
+        } else {
+            0
+        },
+        grant_source: GrantSource::None,
     };
     msg!(
         "✅ Bond data structure created - Index: {}, Timestamp: {}",
@@ -541,32 +1090,28 @@ fn initialize_bond<'a>(
     bond_pda_data.serialize_account_data(bond_pda)?;
     msg!("✅ Bond data serialized to account");
 
+    // Fixed-term bonds earn their fixed reward instead of emission
+    if !fixed_term {
+        global_state_data.total_active_weight = global_state_data
+            .total_active_weight
+            .checked_add(STANDARD_BOND_WEIGHT as u64)
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
+    }
+    global_state_data.serialize_account_data(global_state)?;
+
     // Update user data with new bond
-    user_pda_data
-        .active_bonds
-        .push((user_pda_data.bond_index, *bond_pda.key));
-    user_pda_data.bond_index += 1;
-    user_pda_data.bond_count += 1;
+    user_bond_page_data.bonds.push(*bond_pda.key);
+    user_bond_page_data.serialize_account_data(user_bond_page)?;
+    user_pda_data.bond_index = user_pda_data
+        .bond_index
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    user_pda_data.bond_count = user_pda_data
+        .bond_count
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
     msg!("✅ User data updated - New bond count: {}, Next bond index: {}", user_pda_data.bond_count, user_pda_data.bond_index);
 
-    let user_data_current_size = user_pda.data_len();
-    let user_data_new_size = user_pda_data.get_size();
-
-    if user_data_new_size > user_data_current_size {
-        msg!(
-            "🔍 User PDA data size increased from {} to {}",
-            user_data_current_size,
-            user_data_new_size
-        );
-        realloc_account(
-            user_pda,
-            user_wallet,
-            system_program,
-            user_data_new_size,
-            false,
-        )?;
-    }
-
     user_pda_data.serialize_account_data(user_pda)?;
     msg!("✅ User PDA data serialized successfully");
 
@@ -578,24 +1123,19 @@ fn initialize_bond<'a>(
 pub fn process_claim<'a>(
     program_id: &Pubkey,
     accounts: &'a [AccountInfo<'a>],
-    bond_index: u8,
+    bond_index: u16,
     auto_compound: bool,
 ) -> ProgramResult {
     // Extract accounts
-    let [bond_pda, user_wallet, user_pda, user_wallet_ata, global_admin, rewards_pool_ata, treasury_ata, team_ata, new_bond_pda, native_token_mint, token_program, associated_token_program, system_program] =
+    let [bond_pda, user_wallet, user_pda, user_bond_page, user_wallet_ata, global_admin, rewards_pool_ata, treasury_ata, principal_vault_ata, team_vesting_ata, new_bond_pda, new_bond_page, native_token_mint, token_program, associated_token_program, system_program, global_state, split_config, reward_streams, bond_token_account, referrer_ata, referrer_stats, holder_user_pda, holder_bond_page, owner_wallet, remaining_accounts @ ..] =
         &accounts
     else {
         return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
     };
 
     // Validate PDAs
-    assert_pda(
-        "User PDA",
-        user_pda,
-        program_id,
-        &[b"user", user_wallet.key.as_ref()],
-    )?;
     assert_non_empty("User PDA", user_pda)?;
+    assert_program_owner("User PDA", user_pda, program_id)?;
     let global_admin_bump: u8 = assert_pda(
         "Global Admin PDA",
         global_admin,
@@ -604,35 +1144,78 @@ pub fn process_claim<'a>(
     )?;
     assert_non_empty("Global Admin PDA", global_admin)?;
     assert_pda(
-        "Bond PDA",
-        bond_pda,
+        "Global State PDA",
+        global_state,
         program_id,
-        &[b"bond", user_pda.key.as_ref(), &[bond_index]],
+        &[b"global_state"],
     )?;
-    assert_non_empty("Bond PDA", bond_pda)?;
+    assert_non_empty("Global State PDA", global_state)?;
 
     // Load account data
-    let mut bond_pda_data = Bond::deserialize_account_data(
-        bond_pda.data.borrow_mut().as_ref(),
-    )?;
+    let mut bond_pda_data =
+        load_bond(program_id, bond_pda, bond_index)?;
     let mut user_pda_data = UserAccount::deserialize_account_data(
         user_pda.data.borrow_mut().as_ref(),
     )?;
     let global_admin_data = Admin::deserialize_account_data(
         global_admin.data.borrow_mut().as_ref(),
     )?;
+    let split_config_data =
+        load_split_config(program_id, split_config)?;
+    let reward_streams_data =
+        load_reward_stream_list(program_id, reward_streams)?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    let mut user_bond_page_data = load_bond_page(
+        program_id,
+        user_pda,
+        user_bond_page,
+        bond_pda_data.page_index,
+    )?;
+
+    // The accounts of every reward stream come first, then the split
+    // recipients
+    let stream_account_count = reward_streams_data.streams.len() * 5;
+    if remaining_accounts.len() < stream_account_count {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (stream_accounts, recipient_accounts) =
+        remaining_accounts.split_at(stream_account_count);
 
     // Assert Admin has not paused bond operations
     if global_admin_data.pause_bond_operations {
         return Err(TestudoBondsError::BondOperationsPaused.into());
     }
 
-    // Validate account relationships
-    assert_same_pubkeys(
-        "User PDA",
-        user_wallet,
-        &user_pda_data.user,
-    )?;
+    // Validate account relationships. Claim rights of a tokenized bond
+    // follow the token holder.
+    if bond_pda_data.mint == Pubkey::default() {
+        assert_pda(
+            "User PDA",
+            user_pda,
+            program_id,
+            &[b"user", user_wallet.key.as_ref()],
+        )?;
+        assert_same_pubkeys(
+            "User PDA",
+            user_wallet,
+            &user_pda_data.user,
+        )?;
+    } else {
+        assert_pda(
+            "User PDA",
+            user_pda,
+            program_id,
+            &[b"user", user_pda_data.user.as_ref()],
+        )?;
+        assert_bond_holder(
+            user_wallet.key,
+            &bond_pda_data.mint,
+            bond_token_account,
+        )?;
+    }
     assert_same_pubkeys("Bond PDA", user_pda, &bond_pda_data.owner)?;
     assert_same_pubkeys(
         "Native mint",
@@ -644,10 +1227,23 @@ pub fn process_claim<'a>(
         treasury_ata,
         &global_admin_data.treasury,
     )?;
-    assert_same_pubkeys(
-        "Team ATA",
-        team_ata,
-        &global_admin_data.team,
+    let (principal_vault, _) = Pubkey::find_program_address(
+        &[b"principal_vault"],
+        program_id,
+    );
+    assert_valid_token_account(
+        "Principal Vault ATA",
+        &principal_vault,
+        native_token_mint.key,
+        principal_vault_ata,
+    )?;
+    let (team_vesting, _) =
+        Pubkey::find_program_address(&[b"team_vesting"], program_id);
+    assert_valid_token_account(
+        "Team Vesting ATA",
+        &team_vesting,
+        native_token_mint.key,
+        team_vesting_ata,
     )?;
     assert_valid_token_account(
         "User Wallet ATA",
@@ -656,16 +1252,12 @@ pub fn process_claim<'a>(
         user_wallet_ata,
     )?;
 
-    // Validate bond index exists in user's active bonds
-    if !user_pda_data
-        .active_bonds
-        .iter()
-        .any(|(index, _)| *index == bond_index)
-    {
-        return Err(TestudoBondsError::InvalidBondIndex.into());
-    }
-    // Validate bond state
-    assert_valid_bond(&bond_pda_data, &user_pda_data)?;
+    // Validate bond state and that it is tracked on the user's page
+    assert_valid_bond(
+        bond_pda.key,
+        &bond_pda_data,
+        &user_bond_page_data,
+    )?;
 
     // Validate program accounts
     assert_valid_system_program(system_program.key)?;
@@ -677,47 +1269,216 @@ pub fn process_claim<'a>(
     // Validate signer
     assert_signer("User Wallet", user_wallet)?;
 
+    // A tokenized bond claimed by a holder other than its owner is
+    // first moved to the holder, so the claim, its penalty and the
+    // closing of the bond are all accounted on the holder's user PDA
+    let (
+        user_pda,
+        user_bond_page,
+        mut user_pda_data,
+        mut user_bond_page_data,
+    ) = if *user_wallet.key != user_pda_data.user {
+        assert_pda(
+            "Holder User PDA",
+            holder_user_pda,
+            program_id,
+            &[b"user", user_wallet.key.as_ref()],
+        )?;
+        assert_non_empty("Holder User PDA", holder_user_pda)?;
+        assert_program_owner(
+            "Holder User PDA",
+            holder_user_pda,
+            program_id,
+        )?;
+        assert_same_pubkeys(
+            "Owner Wallet",
+            owner_wallet,
+            &user_pda_data.user,
+        )?;
+        let mut holder_user_pda_data =
+            UserAccount::deserialize_account_data(
+                holder_user_pda.data.borrow().as_ref(),
+            )?;
+        if holder_user_pda_data.bond_count
+            >= global_admin_data.max_bonds_per_wallet
+        {
+            msg!(
+                "Holder has reached max bonds limit: {}/{}",
+                holder_user_pda_data.bond_count,
+                global_admin_data.max_bonds_per_wallet
+            );
+            return Err(TestudoBondsError::MaxBondsReached.into());
+        }
+        move_bond(
+            program_id,
+            bond_pda,
+            &mut bond_pda_data,
+            user_bond_page,
+            &mut user_bond_page_data,
+            &mut user_pda_data,
+            holder_user_pda,
+            &mut holder_user_pda_data,
+            holder_bond_page,
+            user_wallet,
+            owner_wallet,
+            system_program,
+        )?;
+        user_pda_data.serialize_account_data(user_pda)?;
+        let holder_bond_page_data = load_bond_page(
+            program_id,
+            holder_user_pda,
+            holder_bond_page,
+            bond_pda_data.page_index,
+        )?;
+        (
+            holder_user_pda,
+            holder_bond_page,
+            holder_user_pda_data,
+            holder_bond_page_data,
+        )
+    } else {
+        (user_pda, user_bond_page, user_pda_data, user_bond_page_data)
+    };
+    let mut referrer_stats_data = load_referrer_stats(
+        program_id,
+        &user_pda_data,
+        referrer_ata,
+        referrer_stats,
+        native_token_mint,
+    )?;
+
     // Calculate rewards
     let current_timestamp = Clock::get()?.unix_timestamp;
-    let mut reward = calculate_reward(
-        &bond_pda_data.last_claim_timestamp,
-        &current_timestamp,
-        global_admin_data.daily_emission_rate,
-        global_admin_data.claim_penalty,
+    assert_bond_perpetual(&bond_pda_data)?;
+    assert_bond_unlocked(&bond_pda_data, current_timestamp)?;
+    update_reward_index(
+        &mut global_state_data,
+        &global_admin_data,
+        current_timestamp,
+    )?;
+    let ClaimReward {
+        mut reward,
+        penalty,
+        remainder,
+    } = calculate_bond_reward(
+        &bond_pda_data,
+        &global_admin_data,
+        global_state_data.reward_per_weight,
+        current_timestamp,
+    )?;
+    let emission_cap = calculate_weighted_amount(
         global_admin_data.max_emission_per_bond,
-        bond_pda_data.total_claimed,
+        bond_pda_data.weight,
     )?;
 
-    let reward_pool_balance = TokenAccount::unpack(
-        rewards_pool_ata.data.borrow().as_ref(),
-    )?
-    .amount;
+    // Add the bond's share of redistributed penalties
+    reward = reward
+        .checked_add(calculate_bond_penalty_share(
+            &bond_pda_data,
+            &global_admin_data,
+            global_state_data.penalty_per_weight,
+            reward,
+        )?)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
 
-    if reward_pool_balance < reward {
-        msg!("Insufficient rewards");
-        return Err(TestudoBondsError::InsufficientRewards.into());
+    // The reward is capped at the emission headroom, so the bond is done
+    // once it is reached. This also closes bonds left above a lowered
+    // cap, which have nothing left to claim.
+    let should_close_bond = reward
+        >= emission_cap.saturating_sub(bond_pda_data.total_claimed);
+
+    if reward == 0 && !should_close_bond {
+        return Err(TestudoBondsError::NoRewardsToClaim.into());
     }
 
-    let should_close_bond = bond_pda_data.total_claimed + reward
-        >= global_admin_data.max_emission_per_bond;
+    assert_rewards_available(
+        &global_admin_data,
+        &global_state_data,
+        rewards_pool_ata,
+        reward,
+    )?;
+
+    // Route the penalty before the bond stops counting as active, so
+    // it is only shared with the other bonds.
+    route_claim_penalty(
+        penalty,
+        bond_pda_data.weight,
+        &global_admin_data,
+        &mut user_pda_data,
+        &mut global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        treasury_ata,
+        native_token_mint,
+        token_program,
+    )?;
+    bond_pda_data.penalty_per_weight_checkpoint =
+        global_state_data.penalty_per_weight;
+    bond_pda_data.reward_per_weight_checkpoint =
+        global_state_data.reward_per_weight;
+    bond_pda_data.reward_remainder = remainder;
 
     if should_close_bond {
-        reward = global_admin_data.max_emission_per_bond
-            - bond_pda_data.total_claimed;
         bond_pda_data.is_active = false;
-        user_pda_data
-            .active_bonds
-            .retain(|(index, _)| *index != bond_index);
-        user_pda_data.bond_count -= 1;
+        global_state_data.total_active_weight = global_state_data
+            .total_active_weight
+            .checked_sub(bond_pda_data.weight as u64)
+            .ok_or(TestudoBondsError::NumericalUnderflow)?;
+        remove_bond_from_page(
+            user_bond_page,
+            &mut user_bond_page_data,
+            &user_pda_data,
+            bond_pda.key,
+            user_wallet,
+        )?;
+        user_pda_data.bond_count = user_pda_data
+            .bond_count
+            .checked_sub(1)
+            .ok_or(TestudoBondsError::NumericalUnderflow)?;
+    }
+
+    // The referrer of the bond owner gets its share of the reward
+    let mut referral_share = 0;
+    if let Some(referrer_stats_data) = referrer_stats_data.as_mut() {
+        referral_share = calculate_basis_points(
+            reward,
+            global_admin_data.referral_claim_bps,
+        )?;
+        referrer_stats_data.total_claim_rewards = referrer_stats_data
+            .total_claim_rewards
+            .checked_add(referral_share)
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
+        referrer_stats_data.serialize_account_data(referrer_stats)?;
     }
+    pay_rewards(
+        referral_share,
+        referrer_ata,
+        &global_admin_data,
+        &mut global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        native_token_mint,
+        token_program,
+    )?;
 
     // Auto-compound logic
-    let mut amount_to_transfer = reward;
+    let mut amount_to_transfer =
+        reward
+            .checked_sub(referral_share)
+            .ok_or(TestudoBondsError::NumericalUnderflow)?;
 
+    // Compounding is paid out instead while the allowlist phase runs,
+    // as it would create a bond outside the wallet's allocation.
     if auto_compound
-        && reward >= SHELLS_PER_TESTUDO * 8
+        && amount_to_transfer >= SHELLS_PER_TESTUDO * 8
         && user_pda_data.bond_count
             < global_admin_data.max_bonds_per_wallet
+        && !is_allowlist_phase_open(
+            &global_admin_data.allowlist,
+            current_timestamp,
+        )
     {
         // 1. create the new bond PDA
         let new_bond_bump = assert_pda(
@@ -727,11 +1488,20 @@ pub fn process_claim<'a>(
             &[
                 b"bond",
                 user_pda.key.as_ref(),
-                &[user_pda_data.bond_index],
+                &user_pda_data.bond_index.to_le_bytes(),
             ],
         )?;
         assert_empty("New Bond PDA", new_bond_pda)?;
 
+        let mut new_bond_page_data = load_open_bond_page(
+            program_id,
+            user_pda,
+            &mut user_pda_data,
+            new_bond_page,
+            user_wallet,
+            system_program,
+        )?;
+
         create_account(
             new_bond_pda,
             user_wallet,
@@ -741,110 +1511,3514 @@ pub fn process_claim<'a>(
             Some(&[&[
                 b"bond",
                 user_pda.key.as_ref(),
-                &[user_pda_data.bond_index],
+                &user_pda_data.bond_index.to_le_bytes(),
                 &[new_bond_bump],
             ]]),
         )?;
 
         let new_bond_pda_data: Bond = Bond {
             owner: *user_pda.key,
+            creator: *user_pda.key,
             bond_index: user_pda_data.bond_index,
+            page_index: new_bond_page_data.page_index,
             creation_timestamp: current_timestamp,
             last_claim_timestamp: current_timestamp,
             total_claimed: 0,
             is_active: true,
+            mint: Pubkey::default(),
+            is_listed: false,
+            weight: STANDARD_BOND_WEIGHT,
+            penalty_per_weight_checkpoint: global_state_data
+                .penalty_per_weight,
+            reward_remainder: 0,
+            reward_per_weight_checkpoint: global_state_data
+                .reward_per_weight,
+            lock_until: 0,
+            lock_boost_bps: 0,
+            maturity_timestamp: 0,
+            principal: 0,
+            maturity_reward: 0,
+            principal_return_bps: 0,
+            grant_source: GrantSource::None,
         };
         new_bond_pda_data.serialize_account_data(new_bond_pda)?;
+        global_state_data.total_active_weight = global_state_data
+            .total_active_weight
+            .checked_add(STANDARD_BOND_WEIGHT as u64)
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
 
-        user_pda_data
-            .active_bonds
-            .push((user_pda_data.bond_index, *new_bond_pda.key));
-        user_pda_data.bond_index += 1;
-
-        // 2. split the 10-token deposit out of the rewards pool
-        let base_amount = SHELLS_PER_TESTUDO * 8;
-        let token_deposit_split = [
-            (base_amount
-                * global_admin_data.token_deposit_split[0] as u64)
-                / 10_000,
-            (base_amount
-                * global_admin_data.token_deposit_split[1] as u64)
-                / 10_000,
-            (base_amount
-                * global_admin_data.token_deposit_split[2] as u64)
-                / 10_000,
-        ];
-
-        // pool share stays in rewards_pool_ata (split[0])
-        transfer_spl_tokens(
-            token_program,
+        new_bond_page_data.bonds.push(*new_bond_pda.key);
+        new_bond_page_data.serialize_account_data(new_bond_page)?;
+        user_pda_data.bond_index = user_pda_data
+            .bond_index
+            .checked_add(1)
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
+        user_pda_data.bond_count = user_pda_data
+            .bond_count
+            .checked_add(1)
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+        // 2. split the 10-token deposit out of the rewards pool, the
+        // rewards pool share stays in rewards_pool_ata. Minted rewards
+        // are minted into the pool first.
+        pay_rewards(
+            SHELLS_PER_TESTUDO * 8,
             rewards_pool_ata,
-            native_token_mint,
-            treasury_ata,
+            &global_admin_data,
+            &mut global_state_data,
             global_admin,
-            token_deposit_split[1],
-            9,
-            Some(&[&[b"global_admin", &[global_admin_bump]]]),
-        )?;
-
-        transfer_spl_tokens(
-            token_program,
+            global_admin_bump,
             rewards_pool_ata,
             native_token_mint,
-            team_ata,
+            token_program,
+        )?;
+        pay_deposit_split(
+            &split_config_data,
+            SHELLS_PER_TESTUDO * 8,
+            rewards_pool_ata,
             global_admin,
-            token_deposit_split[2],
-            9,
             Some(&[&[b"global_admin", &[global_admin_bump]]]),
+            rewards_pool_ata,
+            principal_vault_ata,
+            team_vesting_ata,
+            native_token_mint,
+            token_program,
+            recipient_accounts,
+            &global_admin_data.team_vesting,
+            &mut global_state_data,
         )?;
 
         // 3. Reduce the amount we will send to the user.
-        amount_to_transfer -= SHELLS_PER_TESTUDO * 8;
+        amount_to_transfer = amount_to_transfer
+            .checked_sub(SHELLS_PER_TESTUDO * 8)
+            .ok_or(TestudoBondsError::NumericalUnderflow)?;
+    }
+
+    // Pay the remaining rewards to the user if any
+    pay_rewards(
+        amount_to_transfer,
+        user_wallet_ata,
+        &global_admin_data,
+        &mut global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        native_token_mint,
+        token_program,
+    )?;
+    pay_reward_streams(
+        program_id,
+        &reward_streams_data,
+        stream_accounts,
+        bond_pda,
+        &bond_pda_data,
+        user_wallet,
+        system_program,
+        token_program,
+        current_timestamp,
+        should_close_bond,
+    )?;
+
+    global_state_data.serialize_account_data(global_state)?;
+
+    bond_pda_data.last_claim_timestamp = current_timestamp;
+    bond_pda_data.total_claimed = bond_pda_data
+        .total_claimed
+        .checked_add(reward)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    if should_close_bond {
+        close_account(bond_pda, user_wallet)?;
+    } else {
+        bond_pda_data.serialize_account_data(bond_pda)?;
+    }
+
+    user_pda_data.total_accrued_rewards = user_pda_data
+        .total_accrued_rewards
+        .checked_add(reward)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    user_pda_data.serialize_account_data(user_pda)?;
+
+    Ok(())
+}
+
+pub fn redeem_bond<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    bond_index: u16,
+) -> ProgramResult {
+    // Extract accounts
+    let [bond_pda, user_wallet, user_pda, user_bond_page, user_wallet_ata, global_admin, rewards_pool_ata, native_token_mint, token_program, global_state, split_config, principal_vault, principal_vault_ata, reward_streams, stream_settlements @ ..] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    assert_signer("User Wallet", user_wallet)?;
+
+    // Validate PDAs
+    assert_pda(
+        "User PDA",
+        user_pda,
+        program_id,
+        &[b"user", user_wallet.key.as_ref()],
+    )?;
+    assert_non_empty("User PDA", user_pda)?;
+    let global_admin_bump: u8 = assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+    let principal_vault_bump: u8 = assert_pda(
+        "Principal Vault PDA",
+        principal_vault,
+        program_id,
+        &[b"principal_vault"],
+    )?;
+
+    // Load account data
+    let bond_pda_data = load_bond(program_id, bond_pda, bond_index)?;
+    assert_bond_not_tokenized(&bond_pda_data)?;
+    let split_config_data =
+        load_split_config(program_id, split_config)?;
+    let mut user_pda_data = UserAccount::deserialize_account_data(
+        user_pda.data.borrow().as_ref(),
+    )?;
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    let mut user_bond_page_data = load_bond_page(
+        program_id,
+        user_pda,
+        user_bond_page,
+        bond_pda_data.page_index,
+    )?;
+    let reward_streams_data =
+        load_reward_stream_list(program_id, reward_streams)?;
+    if stream_settlements.len() < reward_streams_data.streams.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Assert Admin has not paused bond operations
+    if global_admin_data.pause_bond_operations {
+        return Err(TestudoBondsError::BondOperationsPaused.into());
     }
 
-    // Transfer remaining rewards to the user if any
-    if amount_to_transfer > 0 {
+    // Locked bonds cannot be redeemed before their lock-up ends, and
+    // fixed-term bonds only settle at maturity
+    assert_bond_perpetual(&bond_pda_data)?;
+    assert_bond_unlocked(
+        &bond_pda_data,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "User PDA",
+        user_wallet,
+        &user_pda_data.user,
+    )?;
+    assert_same_pubkeys("Bond PDA", user_pda, &bond_pda_data.owner)?;
+    assert_same_pubkeys(
+        "Rewards Pool ATA",
+        rewards_pool_ata,
+        &global_admin_data.rewards_pool,
+    )?;
+    assert_same_pubkeys(
+        "Native mint",
+        native_token_mint,
+        &global_admin_data.native_token_mint,
+    )?;
+    assert_valid_token_account(
+        "User Wallet ATA",
+        user_wallet.key,
+        native_token_mint.key,
+        user_wallet_ata,
+    )?;
+    assert_valid_token_account(
+        "Principal Vault ATA",
+        principal_vault.key,
+        native_token_mint.key,
+        principal_vault_ata,
+    )?;
+    assert_valid_bond(
+        bond_pda.key,
+        &bond_pda_data,
+        &user_bond_page_data,
+    )?;
+
+    // Validate program accounts
+    assert_valid_token_program(token_program.key)?;
+
+    // Refund the unearned principal share. Rewards accrued since the
+    // last claim, stream rewards included, are forfeited to the pools.
+    // Granted bonds were never paid for, so they refund nothing.
+    let refund = if bond_pda_data.grant_source == GrantSource::None {
+        calculate_redemption_amount(
+            calculate_weighted_amount(
+                BOND_PRICE,
+                bond_pda_data.weight,
+            )?,
+            global_admin_data.redemption_rate,
+            calculate_weighted_amount(
+                global_admin_data.max_emission_per_bond,
+                bond_pda_data.weight,
+            )?,
+            bond_pda_data.total_claimed,
+        )?
+    } else {
+        0
+    };
+
+    // The refund is drawn from the rewards pool and treasury shares of
+    // the deposit, as the other shares were paid out for good. The
+    // treasury part is only refundable while it is still pending in
+    // the principal vault.
+    let (from_pool, from_treasury) = calculate_redemption_sources(
+        refund,
+        calculate_weighted_amount(BOND_PRICE, bond_pda_data.weight)?,
+        &split_config_data.recipients,
+    )?;
+    let from_treasury =
+        from_treasury.min(global_state_data.pending_treasury);
+    msg!(
+        "Redeeming bond [{}] for {} from the rewards pool and {} from the principal vault (total claimed: {})",
+        bond_index,
+        from_pool,
+        from_treasury,
+        bond_pda_data.total_claimed
+    );
+
+    if from_pool > 0 {
+        let reward_pool_balance = TokenAccount::unpack(
+            rewards_pool_ata.data.borrow().as_ref(),
+        )?
+        .amount;
+
+        if reward_pool_balance < from_pool {
+            msg!("Insufficient rewards");
+            return Err(TestudoBondsError::InsufficientRewards.into());
+        }
+
         transfer_spl_tokens(
             token_program,
             rewards_pool_ata,
             native_token_mint,
             user_wallet_ata,
             global_admin,
-            amount_to_transfer,
+            from_pool,
             9,
             Some(&[&[b"global_admin", &[global_admin_bump]]]),
         )?;
     }
-
-    bond_pda_data.last_claim_timestamp = current_timestamp;
-    bond_pda_data.total_claimed += reward;
-
-    if should_close_bond {
-        close_account(bond_pda, user_wallet)?;
-    } else {
-        bond_pda_data.serialize_account_data(bond_pda)?;
+    if from_treasury > 0 {
+        global_state_data.pending_treasury = global_state_data
+            .pending_treasury
+            .checked_sub(from_treasury)
+            .ok_or(TestudoBondsError::NumericalUnderflow)?;
+        transfer_spl_tokens(
+            token_program,
+            principal_vault_ata,
+            native_token_mint,
+            user_wallet_ata,
+            principal_vault,
+            from_treasury,
+            9,
+            Some(&[&[b"principal_vault", &[principal_vault_bump]]]),
+        )?;
     }
 
-    let user_data_current_size = user_pda.data_len();
-    let user_data_new_size = user_pda_data.get_size();
+    // Stop tracking the bond and close it
+    remove_bond_from_page(
+        user_bond_page,
+        &mut user_bond_page_data,
+        &user_pda_data,
+        bond_pda.key,
+        user_wallet,
+    )?;
+    user_pda_data.bond_count = user_pda_data
+        .bond_count
+        .checked_sub(1)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+    user_pda_data.serialize_account_data(user_pda)?;
+    update_reward_index(
+        &mut global_state_data,
+        &global_admin_data,
+        Clock::get()?.unix_timestamp,
+    )?;
+    global_state_data.total_active_weight = global_state_data
+        .total_active_weight
+        .checked_sub(bond_pda_data.weight as u64)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+    global_state_data.serialize_account_data(global_state)?;
 
-    if user_data_new_size > user_data_current_size {
-        msg!(
-            "🔍 User PDA data size increased from {} to {}",
-            user_data_current_size,
-            user_data_new_size
-        );
-        realloc_account(
-            user_pda,
+    for (stream_key, stream_settlement) in
+        reward_streams_data.streams.iter().zip(stream_settlements)
+    {
+        close_stream_settlement(
+            program_id,
+            stream_key,
+            stream_settlement,
+            bond_pda.key,
             user_wallet,
-            system_program,
-            user_data_new_size,
-            false,
         )?;
     }
+    close_account(bond_pda, user_wallet)?;
 
-    user_pda_data.total_accrued_rewards += reward;
-    user_pda_data.serialize_account_data(user_pda)?;
+    Ok(())
+}
+
+pub fn transfer_bond<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    bond_index: u16,
+) -> ProgramResult {
+    // Extract accounts
+    let [bond_pda, owner_wallet, owner_user_pda, owner_bond_page, owner_wallet_ata, recipient_user_pda, recipient_bond_page, global_admin, global_state, rewards_pool_ata, treasury_ata, native_token_mint, system_program, token_program, reward_streams, stream_accounts @ ..] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    assert_signer("Owner Wallet", owner_wallet)?;
+
+    // Validate PDAs
+    assert_pda(
+        "Owner User PDA",
+        owner_user_pda,
+        program_id,
+        &[b"user", owner_wallet.key.as_ref()],
+    )?;
+    assert_non_empty("Owner User PDA", owner_user_pda)?;
+    assert_non_empty("Recipient User PDA", recipient_user_pda)?;
+    assert_program_owner(
+        "Recipient User PDA",
+        recipient_user_pda,
+        program_id,
+    )?;
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+
+    // Load account data
+    let mut bond_pda_data =
+        load_bond(program_id, bond_pda, bond_index)?;
+    assert_bond_not_tokenized(&bond_pda_data)?;
+    let mut owner_user_pda_data =
+        UserAccount::deserialize_account_data(
+            owner_user_pda.data.borrow().as_ref(),
+        )?;
+    let mut recipient_user_pda_data =
+        UserAccount::deserialize_account_data(
+            recipient_user_pda.data.borrow().as_ref(),
+        )?;
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    let mut owner_bond_page_data = load_bond_page(
+        program_id,
+        owner_user_pda,
+        owner_bond_page,
+        bond_pda_data.page_index,
+    )?;
+    let reward_streams_data =
+        load_reward_stream_list(program_id, reward_streams)?;
+    if stream_accounts.len() < reward_streams_data.streams.len() * 5 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Assert Admin has not paused bond operations
+    if global_admin_data.pause_bond_operations {
+        return Err(TestudoBondsError::BondOperationsPaused.into());
+    }
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "Owner User PDA",
+        owner_wallet,
+        &owner_user_pda_data.user,
+    )?;
+    assert_pda(
+        "Recipient User PDA",
+        recipient_user_pda,
+        program_id,
+        &[b"user", recipient_user_pda_data.user.as_ref()],
+    )?;
+    assert_same_pubkeys(
+        "Bond PDA",
+        owner_user_pda,
+        &bond_pda_data.owner,
+    )?;
+    if owner_user_pda.key == recipient_user_pda.key {
+        msg!("Bond cannot be transferred to its current owner");
+        return Err(TestudoBondsError::AccountMismatch.into());
+    }
+    assert_same_pubkeys(
+        "Rewards Pool ATA",
+        rewards_pool_ata,
+        &global_admin_data.rewards_pool,
+    )?;
+    assert_same_pubkeys(
+        "Native mint",
+        native_token_mint,
+        &global_admin_data.native_token_mint,
+    )?;
+    assert_valid_token_account(
+        "Owner Wallet ATA",
+        owner_wallet.key,
+        native_token_mint.key,
+        owner_wallet_ata,
+    )?;
+    assert_valid_bond(
+        bond_pda.key,
+        &bond_pda_data,
+        &owner_bond_page_data,
+    )?;
+
+    // Validate program accounts
+    assert_valid_system_program(system_program.key)?;
+    assert_valid_token_program(token_program.key)?;
+
+    // Assert recipient has not reached max bonds
+    if recipient_user_pda_data.bond_count
+        >= global_admin_data.max_bonds_per_wallet
+    {
+        msg!(
+            "Recipient has reached max bonds limit: {}/{}",
+            recipient_user_pda_data.bond_count,
+            global_admin_data.max_bonds_per_wallet
+        );
+        return Err(TestudoBondsError::MaxBondsReached.into());
+    }
+
+    // Pay the owner what the bond earned so far, as the recipient only
+    // earns from the transfer on
+    settle_bond_for_new_owner(
+        program_id,
+        bond_pda,
+        &mut bond_pda_data,
+        &mut owner_user_pda_data,
+        owner_wallet,
+        owner_wallet_ata,
+        owner_wallet,
+        &global_admin_data,
+        &mut global_state_data,
+        global_admin,
+        rewards_pool_ata,
+        treasury_ata,
+        native_token_mint,
+        &reward_streams_data,
+        stream_accounts,
+        system_program,
+        token_program,
+    )?;
+
+    // Move the bond from the owner's page to the recipient's page
+    move_bond(
+        program_id,
+        bond_pda,
+        &mut bond_pda_data,
+        owner_bond_page,
+        &mut owner_bond_page_data,
+        &mut owner_user_pda_data,
+        recipient_user_pda,
+        &mut recipient_user_pda_data,
+        recipient_bond_page,
+        owner_wallet,
+        owner_wallet,
+        system_program,
+    )?;
+
+    bond_pda_data.serialize_account_data(bond_pda)?;
+    owner_user_pda_data.serialize_account_data(owner_user_pda)?;
+    recipient_user_pda_data
+        .serialize_account_data(recipient_user_pda)?;
+    global_state_data.serialize_account_data(global_state)?;
+
+    Ok(())
+}
+
+pub fn list_bond<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    bond_index: u16,
+    price: u64,
+) -> ProgramResult {
+    // Extract accounts
+    let [bond_pda, seller_wallet, seller_user_pda, seller_bond_page, listing, global_admin, system_program] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    assert_signer("Seller Wallet", seller_wallet)?;
+
+    // Validate PDAs
+    assert_pda(
+        "Seller User PDA",
+        seller_user_pda,
+        program_id,
+        &[b"user", seller_wallet.key.as_ref()],
+    )?;
+    assert_non_empty("Seller User PDA", seller_user_pda)?;
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    let listing_bump = assert_pda(
+        "Listing PDA",
+        listing,
+        program_id,
+        &[b"listing", bond_pda.key.as_ref()],
+    )?;
+    assert_empty("Listing PDA", listing)?;
+
+    // Load account data
+    let mut bond_pda_data =
+        load_bond(program_id, bond_pda, bond_index)?;
+    assert_bond_not_tokenized(&bond_pda_data)?;
+    let seller_user_pda_data = UserAccount::deserialize_account_data(
+        seller_user_pda.data.borrow().as_ref(),
+    )?;
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let seller_bond_page_data = load_bond_page(
+        program_id,
+        seller_user_pda,
+        seller_bond_page,
+        bond_pda_data.page_index,
+    )?;
+
+    // Assert Admin has not paused bond operations
+    if global_admin_data.pause_bond_operations {
+        return Err(TestudoBondsError::BondOperationsPaused.into());
+    }
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "Seller User PDA",
+        seller_wallet,
+        &seller_user_pda_data.user,
+    )?;
+    assert_same_pubkeys(
+        "Bond PDA",
+        seller_user_pda,
+        &bond_pda_data.owner,
+    )?;
+    assert_valid_bond(
+        bond_pda.key,
+        &bond_pda_data,
+        &seller_bond_page_data,
+    )?;
+
+    // Validate program accounts
+    assert_valid_system_program(system_program.key)?;
+
+    if price == 0 {
+        msg!("Listing price must be greater than zero");
+        return Err(TestudoBondsError::InvalidListingPrice.into());
+    }
+
+    // Create the listing and lock the bond
+    create_account(
+        listing,
+        seller_wallet,
+        system_program,
+        Listing::SIZE,
+        program_id,
+        Some(&[&[
+            b"listing",
+            bond_pda.key.as_ref(),
+            &[listing_bump],
+        ]]),
+    )?;
+
+    let listing_data = Listing {
+        bond: *bond_pda.key,
+        seller: *seller_user_pda.key,
+        seller_wallet: *seller_wallet.key,
+        price,
+        created_timestamp: Clock::get()?.unix_timestamp,
+    };
+    listing_data.serialize_account_data(listing)?;
+
+    bond_pda_data.is_listed = true;
+    bond_pda_data.serialize_account_data(bond_pda)?;
+    msg!("Bond [{}] listed for {}", bond_pda.key, price);
+
+    Ok(())
+}
+
+pub fn cancel_listing<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    bond_index: u16,
+) -> ProgramResult {
+    // Extract accounts
+    let [bond_pda, seller_wallet, listing] = &accounts else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    assert_signer("Seller Wallet", seller_wallet)?;
+
+    // Validate PDAs
+    assert_pda(
+        "Listing PDA",
+        listing,
+        program_id,
+        &[b"listing", bond_pda.key.as_ref()],
+    )?;
+    assert_non_empty("Listing PDA", listing)?;
+
+    // Load account data
+    let mut bond_pda_data =
+        load_bond(program_id, bond_pda, bond_index)?;
+    let listing_data = Listing::deserialize_account_data(
+        listing.data.borrow().as_ref(),
+    )?;
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "Seller Wallet",
+        seller_wallet,
+        &listing_data.seller_wallet,
+    )?;
+
+    // Unlock the bond and close the listing
+    bond_pda_data.is_listed = false;
+    bond_pda_data.serialize_account_data(bond_pda)?;
+    close_account(listing, seller_wallet)?;
+    msg!("Listing of bond [{}] cancelled", bond_pda.key);
+
+    Ok(())
+}
+
+pub fn buy_bond<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    bond_index: u16,
+    max_price: u64,
+) -> ProgramResult {
+    // Extract accounts
+    let [bond_pda, buyer_wallet, buyer_user_pda, buyer_bond_page, buyer_wallet_ata, seller_wallet, seller_user_pda, seller_bond_page, seller_wallet_ata, listing, global_admin, treasury_ata, native_token_mint, token_program, system_program, global_state, rewards_pool_ata, reward_streams, stream_accounts @ ..] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    assert_signer("Buyer Wallet", buyer_wallet)?;
+
+    // Validate PDAs
+    assert_pda(
+        "Buyer User PDA",
+        buyer_user_pda,
+        program_id,
+        &[b"user", buyer_wallet.key.as_ref()],
+    )?;
+    assert_non_empty("Buyer User PDA", buyer_user_pda)?;
+    assert_pda(
+        "Seller User PDA",
+        seller_user_pda,
+        program_id,
+        &[b"user", seller_wallet.key.as_ref()],
+    )?;
+    assert_non_empty("Seller User PDA", seller_user_pda)?;
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+    assert_pda(
+        "Listing PDA",
+        listing,
+        program_id,
+        &[b"listing", bond_pda.key.as_ref()],
+    )?;
+    assert_non_empty("Listing PDA", listing)?;
+
+    // Load account data
+    let mut bond_pda_data =
+        load_bond(program_id, bond_pda, bond_index)?;
+    let listing_data = Listing::deserialize_account_data(
+        listing.data.borrow().as_ref(),
+    )?;
+    let mut buyer_user_pda_data =
+        UserAccount::deserialize_account_data(
+            buyer_user_pda.data.borrow().as_ref(),
+        )?;
+    let mut seller_user_pda_data =
+        UserAccount::deserialize_account_data(
+            seller_user_pda.data.borrow().as_ref(),
+        )?;
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let mut seller_bond_page_data = load_bond_page(
+        program_id,
+        seller_user_pda,
+        seller_bond_page,
+        bond_pda_data.page_index,
+    )?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    let reward_streams_data =
+        load_reward_stream_list(program_id, reward_streams)?;
+    if stream_accounts.len() < reward_streams_data.streams.len() * 5 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Assert Admin has not paused bond operations
+    if global_admin_data.pause_bond_operations {
+        return Err(TestudoBondsError::BondOperationsPaused.into());
+    }
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "Seller Wallet",
+        seller_wallet,
+        &listing_data.seller_wallet,
+    )?;
+    assert_same_pubkeys(
+        "Seller User PDA",
+        seller_user_pda,
+        &listing_data.seller,
+    )?;
+    assert_same_pubkeys(
+        "Bond PDA",
+        seller_user_pda,
+        &bond_pda_data.owner,
+    )?;
+    if buyer_user_pda.key == seller_user_pda.key {
+        msg!("Seller cannot buy their own bond");
+        return Err(TestudoBondsError::AccountMismatch.into());
+    }
+    assert_same_pubkeys(
+        "Treasury ATA",
+        treasury_ata,
+        &global_admin_data.treasury,
+    )?;
+    assert_same_pubkeys(
+        "Native Token Mint",
+        native_token_mint,
+        &global_admin_data.native_token_mint,
+    )?;
+    assert_same_pubkeys(
+        "Rewards Pool ATA",
+        rewards_pool_ata,
+        &global_admin_data.rewards_pool,
+    )?;
+    assert_valid_token_account(
+        "Buyer Wallet ATA",
+        buyer_wallet.key,
+        native_token_mint.key,
+        buyer_wallet_ata,
+    )?;
+    assert_valid_token_account(
+        "Seller Wallet ATA",
+        seller_wallet.key,
+        native_token_mint.key,
+        seller_wallet_ata,
+    )?;
+    if !bond_pda_data.is_active || !bond_pda_data.is_listed {
+        msg!("Bond [{}] is not available for sale", bond_pda.key);
+        return Err(TestudoBondsError::BondNotActive.into());
+    }
+    if listing_data.price > max_price {
+        msg!(
+            "Listing price {} exceeds the maximum price {}",
+            listing_data.price,
+            max_price
+        );
+        return Err(TestudoBondsError::PriceAboveMaximum.into());
+    }
+
+    // Validate program accounts
+    assert_valid_token_program(token_program.key)?;
+    assert_valid_system_program(system_program.key)?;
+
+    // Assert buyer has not reached max bonds
+    if buyer_user_pda_data.bond_count
+        >= global_admin_data.max_bonds_per_wallet
+    {
+        msg!(
+            "Buyer has reached max bonds limit: {}/{}",
+            buyer_user_pda_data.bond_count,
+            global_admin_data.max_bonds_per_wallet
+        );
+        return Err(TestudoBondsError::MaxBondsReached.into());
+    }
+
+    // Pay the seller and the marketplace fee
+    let fee = calculate_marketplace_fee(
+        listing_data.price,
+        global_admin_data.marketplace_fee,
+    )?;
+    let seller_proceeds = listing_data
+        .price
+        .checked_sub(fee)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    if fee > 0 {
+        transfer_spl_tokens(
+            token_program,
+            buyer_wallet_ata,
+            native_token_mint,
+            treasury_ata,
+            buyer_wallet,
+            fee,
+            9,
+            None,
+        )?;
+    }
+    transfer_spl_tokens(
+        token_program,
+        buyer_wallet_ata,
+        native_token_mint,
+        seller_wallet_ata,
+        buyer_wallet,
+        seller_proceeds,
+        9,
+        None,
+    )?;
+    msg!(
+        "Bond [{}] sold for {} (fee: {})",
+        bond_pda.key,
+        listing_data.price,
+        fee
+    );
+
+    // Pay the seller what the bond earned so far, as the buyer only
+    // earns from the purchase on. The buyer pays the rent of any stream
+    // settlement opened on the way.
+    settle_bond_for_new_owner(
+        program_id,
+        bond_pda,
+        &mut bond_pda_data,
+        &mut seller_user_pda_data,
+        seller_wallet,
+        seller_wallet_ata,
+        buyer_wallet,
+        &global_admin_data,
+        &mut global_state_data,
+        global_admin,
+        rewards_pool_ata,
+        treasury_ata,
+        native_token_mint,
+        &reward_streams_data,
+        stream_accounts,
+        system_program,
+        token_program,
+    )?;
+
+    // Hand the bond over to the buyer and close the listing
+    move_bond(
+        program_id,
+        bond_pda,
+        &mut bond_pda_data,
+        seller_bond_page,
+        &mut seller_bond_page_data,
+        &mut seller_user_pda_data,
+        buyer_user_pda,
+        &mut buyer_user_pda_data,
+        buyer_bond_page,
+        buyer_wallet,
+        seller_wallet,
+        system_program,
+    )?;
+    bond_pda_data.is_listed = false;
+
+    bond_pda_data.serialize_account_data(bond_pda)?;
+    seller_user_pda_data.serialize_account_data(seller_user_pda)?;
+    buyer_user_pda_data.serialize_account_data(buyer_user_pda)?;
+    global_state_data.serialize_account_data(global_state)?;
+    close_account(listing, seller_wallet)?;
+
+    Ok(())
+}
+
+/// Load the deposit split config.
+fn load_split_config(
+    program_id: &Pubkey,
+    split_config: &AccountInfo,
+) -> Result<SplitConfig, ProgramError> {
+    assert_pda(
+        "Split Config PDA",
+        split_config,
+        program_id,
+        &[b"split_config"],
+    )?;
+    assert_non_empty("Split Config PDA", split_config)?;
+    SplitConfig::deserialize_account_data(
+        split_config.data.borrow().as_ref(),
+    )
+}
+
+/// Load the reward stream list, which is empty until the first stream
+/// is attached.
+fn load_reward_stream_list(
+    program_id: &Pubkey,
+    reward_streams: &AccountInfo,
+) -> Result<RewardStreamList, ProgramError> {
+    assert_pda(
+        "Reward Stream List PDA",
+        reward_streams,
+        program_id,
+        &[b"reward_streams"],
+    )?;
+    if reward_streams.data_is_empty() {
+        return Ok(RewardStreamList::default());
+    }
+    RewardStreamList::deserialize_account_data(
+        reward_streams.data.borrow().as_ref(),
+    )
+}
+
+/// Set the referrer of a user who has none yet from `referrer_pda`, the
+/// referrer's user PDA, and count the referral on the referrer's stats,
+/// created by `payer` for the first referral. Users without a referrer
+/// who pass the program ID as `referrer_pda` stay unreferred.
+#[allow(clippy::too_many_arguments)]
+fn set_referrer<'a>(
+    program_id: &Pubkey,
+    user_pda_data: &mut UserAccount,
+    referrer_pda: &'a AccountInfo<'a>,
+    referrer_stats: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    if user_pda_data.referrer != Pubkey::default()
+        || referrer_pda.key == program_id
+    {
+        return Ok(());
+    }
+
+    assert_program_owner("Referrer PDA", referrer_pda, program_id)?;
+    assert_non_empty("Referrer PDA", referrer_pda)?;
+    let referrer = UserAccount::deserialize_account_data(
+        referrer_pda.data.borrow().as_ref(),
+    )?
+    .user;
+    assert_pda(
+        "Referrer PDA",
+        referrer_pda,
+        program_id,
+        &[b"user", referrer.as_ref()],
+    )?;
+    if referrer == user_pda_data.user {
+        msg!("Users cannot refer themselves");
+        return Err(TestudoBondsError::InvalidReferral.into());
+    }
+
+    let referrer_stats_bump = assert_pda(
+        "Referrer Stats PDA",
+        referrer_stats,
+        program_id,
+        &[b"referrer_stats", referrer.as_ref()],
+    )?;
+    let mut referrer_stats_data = if referrer_stats.data_is_empty() {
+        create_account(
+            referrer_stats,
+            payer,
+            system_program,
+            ReferrerStats::SIZE,
+            program_id,
+            Some(&[&[
+                b"referrer_stats",
+                referrer.as_ref(),
+                &[referrer_stats_bump],
+            ]]),
+        )?;
+        ReferrerStats {
+            referrer,
+            referral_count: 0,
+            total_deposit_rewards: 0,
+            total_claim_rewards: 0,
+        }
+    } else {
+        ReferrerStats::deserialize_account_data(
+            referrer_stats.data.borrow().as_ref(),
+        )?
+    };
+    referrer_stats_data.referral_count = referrer_stats_data
+        .referral_count
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    referrer_stats_data.serialize_account_data(referrer_stats)?;
+
+    user_pda_data.referrer = referrer;
+    msg!("Referrer set to {}", referrer);
+    Ok(())
+}
+
+/// Whether the allowlist phase is running at `current_timestamp`.
+fn is_allowlist_phase_open(
+    allowlist: &AllowlistPhase,
+    current_timestamp: i64,
+) -> bool {
+    current_timestamp >= allowlist.start_timestamp
+        && current_timestamp < allowlist.end_timestamp
+}
+
+/// Check that the user's wallet is on the allowlist while its phase is
+/// running and take one bond of its allocation. The bonds used are
+/// counted per phase, keyed by its Merkle root.
+fn use_allowlist_allocation(
+    allowlist: &AllowlistPhase,
+    user_pda_data: &mut UserAccount,
+    allowlist_proof: Option<&AllowlistProof>,
+    current_timestamp: i64,
+) -> ProgramResult {
+    if !is_allowlist_phase_open(allowlist, current_timestamp) {
+        return Ok(());
+    }
+
+    let allowlist_proof =
+        allowlist_proof.ok_or(TestudoBondsError::NotAllowlisted)?;
+    if !verify_merkle_proof(
+        &allowlist_proof.proof,
+        &allowlist.merkle_root,
+        allowlist_leaf(
+            &user_pda_data.user,
+            allowlist_proof.allocation,
+        ),
+    ) {
+        msg!("Invalid allowlist proof for {}", user_pda_data.user);
+        return Err(TestudoBondsError::NotAllowlisted.into());
+    }
+
+    if user_pda_data.allowlist_root != allowlist.merkle_root {
+        user_pda_data.allowlist_root = allowlist.merkle_root;
+        user_pda_data.allowlist_used = 0;
+    }
+    if allowlist_proof.allocation > 0
+        && user_pda_data.allowlist_used >= allowlist_proof.allocation
+    {
+        msg!(
+            "Allowlist allocation of {} bonds used",
+            allowlist_proof.allocation
+        );
+        return Err(TestudoBondsError::NotAllowlisted.into());
+    }
+    user_pda_data.allowlist_used = user_pda_data
+        .allowlist_used
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    Ok(())
+}
+
+/// Load the stats of the user's referrer after checking the referrer's
+/// ATA, or None when the user has no referrer. Referrers whose ATA was
+/// closed are not paid, so they cannot block their referrals.
+fn load_referrer_stats(
+    program_id: &Pubkey,
+    user_pda_data: &UserAccount,
+    referrer_ata: &AccountInfo,
+    referrer_stats: &AccountInfo,
+    native_token_mint: &AccountInfo,
+) -> Result<Option<ReferrerStats>, ProgramError> {
+    if user_pda_data.referrer == Pubkey::default() {
+        return Ok(None);
+    }
+
+    assert_valid_token_account(
+        "Referrer ATA",
+        &user_pda_data.referrer,
+        native_token_mint.key,
+        referrer_ata,
+    )?;
+    assert_pda(
+        "Referrer Stats PDA",
+        referrer_stats,
+        program_id,
+        &[b"referrer_stats", user_pda_data.referrer.as_ref()],
+    )?;
+    assert_non_empty("Referrer Stats PDA", referrer_stats)?;
+    if referrer_ata.data_len() != TokenAccount::LEN {
+        msg!("Referrer ATA is closed, no referral paid");
+        return Ok(None);
+    }
+    ReferrerStats::deserialize_account_data(
+        referrer_stats.data.borrow().as_ref(),
+    )
+    .map(Some)
+}
+
+/// Pay a bond's rewards of every attached reward stream to the claimer.
+/// `stream_accounts` holds, for each stream of the list in order, the
+/// stream, its pool, its mint, the claimer's token account for it and
+/// the bond's settlement PDA. A stream whose pool cannot pay is skipped
+/// and its rewards stay owed to the bond. When the bond closes, its
+/// settlement PDAs are closed as well and any skipped rewards are
+/// forfeited.
+#[allow(clippy::too_many_arguments)]
+fn pay_reward_streams<'a>(
+    program_id: &Pubkey,
+    reward_streams_data: &RewardStreamList,
+    stream_accounts: &'a [AccountInfo<'a>],
+    bond_pda: &'a AccountInfo<'a>,
+    bond_pda_data: &Bond,
+    user_wallet: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    current_timestamp: i64,
+    close_settlements: bool,
+) -> ProgramResult {
+    for (stream_key, accounts) in reward_streams_data
+        .streams
+        .iter()
+        .zip(stream_accounts.chunks_exact(5))
+    {
+        settle_reward_stream(
+            program_id,
+            stream_key,
+            &accounts[..4],
+            &accounts[4],
+            bond_pda,
+            bond_pda_data,
+            user_wallet,
+            user_wallet,
+            system_program,
+            token_program,
+            current_timestamp,
+            true,
+        )?;
+        if close_settlements {
+            close_stream_settlement(
+                program_id,
+                stream_key,
+                &accounts[4],
+                bond_pda.key,
+                user_wallet,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Pay a bond's rewards of one reward stream to `user_wallet`, up to
+/// `current_timestamp`. `stream_accounts` holds the stream, its pool,
+/// its mint and the wallet's token account for it. A bond without a
+/// settlement PDA yet is settled from its creation, and the PDA is
+/// created by `payer`. If the pool cannot pay, the stream is
+/// skipped when `skip_unpaid` is set and its rewards stay owed to the
+/// bond; otherwise this fails with `InsufficientRewards`.
+#[allow(clippy::too_many_arguments)]
+fn settle_reward_stream<'a>(
+    program_id: &Pubkey,
+    stream_key: &Pubkey,
+    stream_accounts: &'a [AccountInfo<'a>],
+    stream_settlement: &'a AccountInfo<'a>,
+    bond_pda: &'a AccountInfo<'a>,
+    bond_pda_data: &Bond,
+    user_wallet: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    current_timestamp: i64,
+    skip_unpaid: bool,
+) -> ProgramResult {
+    let [reward_stream, stream_pool_ata, stream_mint, user_stream_ata] =
+        stream_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert_same_pubkeys("Reward Stream", reward_stream, stream_key)?;
+    assert_program_owner("Reward Stream", reward_stream, program_id)?;
+    let reward_stream_data = RewardStream::deserialize_account_data(
+        reward_stream.data.borrow().as_ref(),
+    )?;
+    let reward_stream_bump = assert_pda(
+        "Reward Stream PDA",
+        reward_stream,
+        program_id,
+        &[b"reward_stream", reward_stream_data.mint.as_ref()],
+    )?;
+    assert_same_pubkeys(
+        "Stream Pool ATA",
+        stream_pool_ata,
+        &reward_stream_data.pool,
+    )?;
+    assert_same_pubkeys(
+        "Stream Mint",
+        stream_mint,
+        &reward_stream_data.mint,
+    )?;
+    assert_valid_token_account(
+        "User Stream ATA",
+        user_wallet.key,
+        stream_mint.key,
+        user_stream_ata,
+    )?;
+
+    // Bonds are settled from their creation until their first claim
+    let settlement_bump = assert_pda(
+        "Stream Settlement PDA",
+        stream_settlement,
+        program_id,
+        &[
+            b"stream_settlement",
+            reward_stream.key.as_ref(),
+            bond_pda.key.as_ref(),
+        ],
+    )?;
+    let mut settlement_data = if stream_settlement.data_is_empty() {
+        create_account(
+            stream_settlement,
+            payer,
+            system_program,
+            StreamSettlement::SIZE,
+            program_id,
+            Some(&[&[
+                b"stream_settlement",
+                reward_stream.key.as_ref(),
+                bond_pda.key.as_ref(),
+                &[settlement_bump],
+            ]]),
+        )?;
+        StreamSettlement {
+            last_settled_timestamp: bond_pda_data.creation_timestamp,
+        }
+    } else {
+        StreamSettlement::deserialize_account_data(
+            stream_settlement.data.borrow().as_ref(),
+        )?
+    };
+
+    let reward = calculate_stream_reward(
+        &reward_stream_data,
+        bond_pda_data.weight,
+        settlement_data.last_settled_timestamp,
+        current_timestamp,
+    )?;
+    let pool_balance =
+        TokenAccount::unpack(stream_pool_ata.data.borrow().as_ref())?
+            .amount;
+
+    if pool_balance < reward {
+        if !skip_unpaid {
+            msg!(
+                "Insufficient rewards in reward stream [{}]",
+                reward_stream.key
+            );
+            return Err(TestudoBondsError::InsufficientRewards.into());
+        }
+        msg!(
+            "Insufficient rewards in reward stream [{}], skipped",
+            reward_stream.key
+        );
+    } else {
+        if reward > 0 {
+            transfer_spl_tokens(
+                token_program,
+                stream_pool_ata,
+                stream_mint,
+                user_stream_ata,
+                reward_stream,
+                reward,
+                reward_stream_data.decimals,
+                Some(&[&[
+                    b"reward_stream",
+                    reward_stream_data.mint.as_ref(),
+                    &[reward_stream_bump],
+                ]]),
+            )?;
+        }
+        settlement_data.last_settled_timestamp = current_timestamp;
+        msg!(
+            "Paid {} from reward stream [{}]",
+            reward,
+            reward_stream.key
+        );
+    }
+    settlement_data.serialize_account_data(stream_settlement)
+}
+
+/// Create a new bond's settlement PDA of a reward stream, settled at
+/// `current_timestamp`. A bond split off another one shares its
+/// creation timestamp, so it must not be settled from its creation.
+fn open_stream_settlement<'a>(
+    program_id: &Pubkey,
+    stream_key: &Pubkey,
+    stream_settlement: &'a AccountInfo<'a>,
+    bond_key: &Pubkey,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    current_timestamp: i64,
+) -> ProgramResult {
+    let settlement_bump = assert_pda(
+        "Stream Settlement PDA",
+        stream_settlement,
+        program_id,
+        &[
+            b"stream_settlement",
+            stream_key.as_ref(),
+            bond_key.as_ref(),
+        ],
+    )?;
+    assert_empty("Stream Settlement PDA", stream_settlement)?;
+    create_account(
+        stream_settlement,
+        payer,
+        system_program,
+        StreamSettlement::SIZE,
+        program_id,
+        Some(&[&[
+            b"stream_settlement",
+            stream_key.as_ref(),
+            bond_key.as_ref(),
+            &[settlement_bump],
+        ]]),
+    )?;
+    StreamSettlement {
+        last_settled_timestamp: current_timestamp,
+    }
+    .serialize_account_data(stream_settlement)
+}
+
+/// Close the settlement PDA of a reward stream of a bond being closed,
+/// if it has one, returning its rent to `rent_receiver`.
+fn close_stream_settlement<'a>(
+    program_id: &Pubkey,
+    stream_key: &Pubkey,
+    stream_settlement: &AccountInfo<'a>,
+    bond_key: &Pubkey,
+    rent_receiver: &AccountInfo<'a>,
+) -> ProgramResult {
+    assert_pda(
+        "Stream Settlement PDA",
+        stream_settlement,
+        program_id,
+        &[
+            b"stream_settlement",
+            stream_key.as_ref(),
+            bond_key.as_ref(),
+        ],
+    )?;
+    if stream_settlement.data_is_empty() {
+        return Ok(());
+    }
+    close_account(stream_settlement, rent_receiver)
+}
+
+/// Add a team share deposit of `amount` to the team vesting queue and
+/// move the queue into the tranches.
+fn add_team_vesting_deposit(
+    global_state_data: &mut GlobalState,
+    team_vesting: &VestingSchedule,
+    amount: u64,
+    current_timestamp: i64,
+) -> ProgramResult {
+    global_state_data.team_vesting_deposited = global_state_data
+        .team_vesting_deposited
+        .checked_add(amount)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    global_state_data.team_vesting_queued = global_state_data
+        .team_vesting_queued
+        .checked_add(amount)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    schedule_team_vesting_queue(
+        global_state_data,
+        team_vesting,
+        current_timestamp,
+    )
+}
+
+/// Move the queued team deposits into the team vesting tranches. They
+/// join the newest tranche if that one has not started vesting yet,
+/// otherwise they open a tranche starting one window from now, a window
+/// being `1 / (MAX_VESTING_TRANCHES - 1)` of the vesting duration, in a
+/// slot that is unused or fully vested. With every slot in use, they
+/// stay queued, so the start of deposits already in a tranche is never
+/// pushed back.
+fn schedule_team_vesting_queue(
+    global_state_data: &mut GlobalState,
+    team_vesting: &VestingSchedule,
+    current_timestamp: i64,
+) -> ProgramResult {
+    let queued = global_state_data.team_vesting_queued;
+    if queued == 0 {
+        return Ok(());
+    }
+
+    let tranches = &mut global_state_data.team_vesting_tranches;
+    let open = (0..tranches.len())
+        .filter(|&index| tranches[index].amount > 0)
+        .max_by_key(|&index| tranches[index].start_timestamp)
+        .filter(|&index| {
+            tranches[index]
+                .start_timestamp
+                .max(team_vesting.start_timestamp)
+                >= current_timestamp
+        });
+    let index = match open {
+        Some(index) => index,
+        None => {
+            let mut free = None;
+            for (index, tranche) in tranches.iter().enumerate() {
+                let vested = calculate_vested_amount(
+                    tranche.amount,
+                    tranche.start_timestamp,
+                    team_vesting,
+                    current_timestamp,
+                )?;
+                if vested == tranche.amount {
+                    free = Some(index);
+                    break;
+                }
+            }
+            let Some(index) = free else {
+                msg!(
+                    "Every vesting tranche is in use, {} team tokens stay queued",
+                    queued
+                );
+                return Ok(());
+            };
+            let window = team_vesting.duration_seconds as i64
+                / (GlobalState::MAX_VESTING_TRANCHES as i64 - 1);
+            tranches[index] = VestingTranche {
+                start_timestamp: current_timestamp
+                    .checked_add(window)
+                    .ok_or(TestudoBondsError::NumericalOverflow)?,
+                amount: 0,
+            };
+            index
+        }
+    };
+    tranches[index].amount = tranches[index]
+        .amount
+        .checked_add(queued)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    global_state_data.team_vesting_queued = 0;
+
+    Ok(())
+}
+
+/// Pay a deposit of `amount` held in `source` out to the recipients of
+/// the split config, in order. Treasury shares are recorded as pending
+/// in the principal vault, team shares as deposited for vesting and
+/// burned shares in `total_burned`; `Account` recipients are paid to
+/// `recipient_accounts`, which must list their token accounts in
+/// config order. Shares are rounded down and the rounding remainder
+/// stays in `source`, as do shares whose destination is `source`
+/// itself.
+#[allow(clippy::too_many_arguments)]
+fn pay_deposit_split<'a>(
+    split_config_data: &SplitConfig,
+    amount: u64,
+    source: &'a AccountInfo<'a>,
+    authority: &'a AccountInfo<'a>,
+    signer_seeds: Option<&[&[&[u8]]]>,
+    rewards_pool_ata: &'a AccountInfo<'a>,
+    principal_vault_ata: &'a AccountInfo<'a>,
+    team_vesting_ata: &'a AccountInfo<'a>,
+    native_token_mint: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    recipient_accounts: &'a [AccountInfo<'a>],
+    team_vesting: &VestingSchedule,
+    global_state_data: &mut GlobalState,
+) -> ProgramResult {
+    let shares = calculate_token_deposit_split(
+        amount,
+        &split_config_data.recipients,
+    )?;
+    let mut recipient_accounts = recipient_accounts.iter();
+    for (recipient, share) in
+        split_config_data.recipients.iter().zip(shares)
+    {
+        let destination = match recipient.destination {
+            SplitDestination::RewardsPool => rewards_pool_ata,
+            SplitDestination::Treasury => {
+                global_state_data.pending_treasury =
+                    global_state_data
+                        .pending_treasury
+                        .checked_add(share)
+                        .ok_or(
+                            TestudoBondsError::NumericalOverflow,
+                        )?;
+                principal_vault_ata
+            }
+            SplitDestination::Team => {
+                add_team_vesting_deposit(
+                    global_state_data,
+                    team_vesting,
+                    share,
+                    Clock::get()?.unix_timestamp,
+                )?;
+                team_vesting_ata
+            }
+            SplitDestination::Burn => {
+                global_state_data.total_burned = global_state_data
+                    .total_burned
+                    .checked_add(share)
+                    .ok_or(TestudoBondsError::NumericalOverflow)?;
+                if share > 0 {
+                    burn_spl_tokens(
+                        token_program,
+                        source,
+                        native_token_mint,
+                        authority,
+                        share,
+                        9,
+                        signer_seeds,
+                    )?;
+                }
+                msg!("Burned {} tokens of the deposit", share);
+                continue;
+            }
+            SplitDestination::Account => {
+                let account = recipient_accounts
+                    .next()
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                assert_same_pubkeys(
+                    "Split Recipient",
+                    account,
+                    &recipient.token_account,
+                )?;
+                account
+            }
+        };
+        if share > 0 && destination.key != source.key {
+            transfer_spl_tokens(
+                token_program,
+                source,
+                native_token_mint,
+                destination,
+                authority,
+                share,
+                9,
+                signer_seeds,
+            )?;
+        }
+        msg!(
+            "Paid {} tokens of the deposit to {}",
+            share,
+            destination.key
+        );
+    }
+    Ok(())
+}
+
+/// Bring the global reward index up to `current_timestamp`. Must run
+/// before the active weight changes or a bond is settled against it.
+fn update_reward_index(
+    global_state_data: &mut GlobalState,
+    global_admin_data: &Admin,
+    current_timestamp: i64,
+) -> ProgramResult {
+    global_state_data.reward_per_weight =
+        calculate_reward_per_weight(
+            global_admin_data,
+            global_state_data,
+            current_timestamp,
+        )?;
+    global_state_data.last_emission_update = current_timestamp;
+    Ok(())
+}
+
+/// Check that `amount` of rewards can be paid: from the rewards pool
+/// balance, or within the emission ceiling when rewards are minted.
+fn assert_rewards_available(
+    global_admin_data: &Admin,
+    global_state_data: &GlobalState,
+    rewards_pool_ata: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    let available = match global_admin_data.reward_source {
+        RewardSource::Pool => {
+            TokenAccount::unpack(
+                rewards_pool_ata.data.borrow().as_ref(),
+            )?
+            .amount
+        }
+        RewardSource::Mint => global_admin_data
+            .emission_ceiling
+            .saturating_sub(global_state_data.total_minted),
+    };
+
+    if available < amount {
+        msg!("Insufficient rewards");
+        return Err(match global_admin_data.reward_source {
+            RewardSource::Pool => {
+                TestudoBondsError::InsufficientRewards
+            }
+            RewardSource::Mint => {
+                TestudoBondsError::EmissionCeilingReached
+            }
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Pay `amount` of rewards to `destination` from the configured reward
+/// source: transferred from the rewards pool, or minted by the global
+/// admin PDA and added to `total_minted`.
+#[allow(clippy::too_many_arguments)]
+fn pay_rewards<'a>(
+    amount: u64,
+    destination: &'a AccountInfo<'a>,
+    global_admin_data: &Admin,
+    global_state_data: &mut GlobalState,
+    global_admin: &'a AccountInfo<'a>,
+    global_admin_bump: u8,
+    rewards_pool_ata: &'a AccountInfo<'a>,
+    native_token_mint: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    match global_admin_data.reward_source {
+        RewardSource::Pool => {
+            if destination.key == rewards_pool_ata.key {
+                return Ok(());
+            }
+            transfer_spl_tokens(
+                token_program,
+                rewards_pool_ata,
+                native_token_mint,
+                destination,
+                global_admin,
+                amount,
+                9,
+                Some(&[&[b"global_admin", &[global_admin_bump]]]),
+            )
+        }
+        RewardSource::Mint => {
+            assert_rewards_available(
+                global_admin_data,
+                global_state_data,
+                rewards_pool_ata,
+                amount,
+            )?;
+            global_state_data.total_minted = global_state_data
+                .total_minted
+                .checked_add(amount)
+                .ok_or(TestudoBondsError::NumericalOverflow)?;
+            mint_spl_tokens(
+                token_program,
+                native_token_mint,
+                destination,
+                global_admin,
+                amount,
+                9,
+                Some(&[&[b"global_admin", &[global_admin_bump]]]),
+            )
+        }
+    }
+}
+
+/// Route the penalty withheld on a claim to the configured destination
+/// and record it on the user and globally. Redistributed penalties are
+/// shared by the active bonds other than the claimed one, whose weight
+/// is `claimer_weight`. In mint mode a penalty sent to the treasury is
+/// minted to it, and a burned penalty is simply never minted.
+#[allow(clippy::too_many_arguments)]
+fn route_claim_penalty<'a>(
+    penalty: u64,
+    claimer_weight: u32,
+    global_admin_data: &Admin,
+    user_pda_data: &mut UserAccount,
+    global_state_data: &mut GlobalState,
+    global_admin: &'a AccountInfo<'a>,
+    global_admin_bump: u8,
+    rewards_pool_ata: &'a AccountInfo<'a>,
+    treasury_ata: &'a AccountInfo<'a>,
+    native_token_mint: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    if penalty == 0 {
+        return Ok(());
+    }
+
+    match global_admin_data.penalty_destination {
+        PenaltyDestination::RewardsPool => {}
+        PenaltyDestination::Treasury => {
+            assert_same_pubkeys(
+                "Treasury ATA",
+                treasury_ata,
+                &global_admin_data.treasury,
+            )?;
+            pay_rewards(
+                penalty,
+                treasury_ata,
+                global_admin_data,
+                global_state_data,
+                global_admin,
+                global_admin_bump,
+                rewards_pool_ata,
+                native_token_mint,
+                token_program,
+            )?;
+        }
+        PenaltyDestination::Burn => {
+            // Minted rewards are minted net of the penalty, so there
+            // is nothing to burn
+            if global_admin_data.reward_source == RewardSource::Pool {
+                global_state_data.total_burned = global_state_data
+                    .total_burned
+                    .checked_add(penalty)
+                    .ok_or(TestudoBondsError::NumericalOverflow)?;
+                burn_spl_tokens(
+                    token_program,
+                    rewards_pool_ata,
+                    native_token_mint,
+                    global_admin,
+                    penalty,
+                    9,
+                    Some(&[&[b"global_admin", &[global_admin_bump]]]),
+                )?;
+            }
+        }
+        PenaltyDestination::Redistribute => {
+            // The penalty stays in the pool, owed to the other bonds
+            let eligible_weight = global_state_data
+                .total_active_weight
+                .saturating_sub(claimer_weight as u64);
+            global_state_data.penalty_per_weight = global_state_data
+                .penalty_per_weight
+                .checked_add(calculate_penalty_per_weight(
+                    penalty,
+                    eligible_weight,
+                )?)
+                .ok_or(TestudoBondsError::NumericalOverflow)?;
+        }
+    }
+
+    user_pda_data.total_penalties = user_pda_data
+        .total_penalties
+        .checked_add(penalty)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    global_state_data.total_penalties = global_state_data
+        .total_penalties
+        .checked_add(penalty)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    msg!(
+        "Withheld claim penalty of {} ({:?})",
+        penalty,
+        global_admin_data.penalty_destination
+    );
+
+    Ok(())
+}
+
+/// Pay out the rewards accrued on a bond since its last claim, so its
+/// weight and claimed amount can be changed without creating or losing
+/// value. Returns the amount paid.
+#[allow(clippy::too_many_arguments)]
+fn settle_bond_rewards<'a>(
+    bond_pda_data: &mut Bond,
+    user_pda_data: &mut UserAccount,
+    global_admin_data: &Admin,
+    global_state_data: &mut GlobalState,
+    global_admin: &'a AccountInfo<'a>,
+    global_admin_bump: u8,
+    rewards_pool_ata: &'a AccountInfo<'a>,
+    treasury_ata: &'a AccountInfo<'a>,
+    native_token_mint: &'a AccountInfo<'a>,
+    user_wallet_ata: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    current_timestamp: i64,
+) -> Result<u64, ProgramError> {
+    update_reward_index(
+        global_state_data,
+        global_admin_data,
+        current_timestamp,
+    )?;
+    let ClaimReward {
+        mut reward,
+        penalty,
+        remainder,
+    } = calculate_bond_reward(
+        bond_pda_data,
+        global_admin_data,
+        global_state_data.reward_per_weight,
+        current_timestamp,
+    )?;
+    reward = reward
+        .checked_add(calculate_bond_penalty_share(
+            bond_pda_data,
+            global_admin_data,
+            global_state_data.penalty_per_weight,
+            reward,
+        )?)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    assert_rewards_available(
+        global_admin_data,
+        global_state_data,
+        rewards_pool_ata,
+        reward,
+    )?;
+    pay_rewards(
+        reward,
+        user_wallet_ata,
+        global_admin_data,
+        global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        native_token_mint,
+        token_program,
+    )?;
+
+    route_claim_penalty(
+        penalty,
+        bond_pda_data.weight,
+        global_admin_data,
+        user_pda_data,
+        global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        treasury_ata,
+        native_token_mint,
+        token_program,
+    )?;
+
+    bond_pda_data.penalty_per_weight_checkpoint =
+        global_state_data.penalty_per_weight;
+    bond_pda_data.reward_per_weight_checkpoint =
+        global_state_data.reward_per_weight;
+    bond_pda_data.reward_remainder = remainder;
+    bond_pda_data.last_claim_timestamp = current_timestamp;
+    bond_pda_data.total_claimed = bond_pda_data
+        .total_claimed
+        .checked_add(reward)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    user_pda_data.total_accrued_rewards = user_pda_data
+        .total_accrued_rewards
+        .checked_add(reward)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    msg!(
+        "Settled {} rewards on bond index [{}]",
+        reward,
+        bond_pda_data.bond_index
+    );
+
+    Ok(reward)
+}
+
+/// Pay the owner of a bond changing hands its pending rewards and its
+/// rewards of every attached reward stream, so the new owner only earns
+/// from the change on. `stream_accounts` holds, for each stream, the
+/// stream, its pool, its mint, the owner's token account for it and the
+/// bond's settlement PDA, created by `payer` if missing. Locked and
+/// fixed-term bonds cannot be claimed yet, so they are not settled and
+/// what they earned moves with them.
+#[allow(clippy::too_many_arguments)]
+fn settle_bond_for_new_owner<'a>(
+    program_id: &Pubkey,
+    bond_pda: &'a AccountInfo<'a>,
+    bond_pda_data: &mut Bond,
+    owner_user_pda_data: &mut UserAccount,
+    owner_wallet: &'a AccountInfo<'a>,
+    owner_wallet_ata: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    global_admin_data: &Admin,
+    global_state_data: &mut GlobalState,
+    global_admin: &'a AccountInfo<'a>,
+    rewards_pool_ata: &'a AccountInfo<'a>,
+    treasury_ata: &'a AccountInfo<'a>,
+    native_token_mint: &'a AccountInfo<'a>,
+    reward_streams_data: &RewardStreamList,
+    stream_accounts: &'a [AccountInfo<'a>],
+    system_program: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+) -> ProgramResult {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    if bond_pda_data.maturity_timestamp != 0
+        || bond_pda_data.lock_until > current_timestamp
+    {
+        return Ok(());
+    }
+
+    let global_admin_bump = assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    settle_bond_rewards(
+        bond_pda_data,
+        owner_user_pda_data,
+        global_admin_data,
+        global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        treasury_ata,
+        native_token_mint,
+        owner_wallet_ata,
+        token_program,
+        current_timestamp,
+    )?;
+    for (stream_key, accounts) in reward_streams_data
+        .streams
+        .iter()
+        .zip(stream_accounts.chunks_exact(5))
+    {
+        settle_reward_stream(
+            program_id,
+            stream_key,
+            &accounts[..4],
+            &accounts[4],
+            bond_pda,
+            bond_pda_data,
+            owner_wallet,
+            payer,
+            system_program,
+            token_program,
+            current_timestamp,
+            false,
+        )?;
+    }
+    Ok(())
+}
+
+pub fn merge_bonds<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    target_bond_index: u16,
+    source_bond_index: u16,
+) -> ProgramResult {
+    // Extract accounts
+    let [target_bond, source_bond, user_wallet, user_pda, target_bond_page, source_bond_page, user_wallet_ata, global_admin, rewards_pool_ata, native_token_mint, token_program, treasury_ata, global_state, system_program, reward_streams, stream_accounts @ ..] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    assert_signer("User Wallet", user_wallet)?;
+
+    // Validate PDAs
+    assert_pda(
+        "User PDA",
+        user_pda,
+        program_id,
+        &[b"user", user_wallet.key.as_ref()],
+    )?;
+    assert_non_empty("User PDA", user_pda)?;
+    let global_admin_bump: u8 = assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+    if target_bond.key == source_bond.key {
+        msg!("A bond cannot be merged into itself");
+        return Err(TestudoBondsError::AccountMismatch.into());
+    }
+
+    // Load account data
+    let mut target_bond_data =
+        load_bond(program_id, target_bond, target_bond_index)?;
+    assert_bond_not_tokenized(&target_bond_data)?;
+    let mut source_bond_data =
+        load_bond(program_id, source_bond, source_bond_index)?;
+    assert_bond_not_tokenized(&source_bond_data)?;
+    let mut user_pda_data = UserAccount::deserialize_account_data(
+        user_pda.data.borrow().as_ref(),
+    )?;
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    let target_bond_page_data = load_bond_page(
+        program_id,
+        user_pda,
+        target_bond_page,
+        target_bond_data.page_index,
+    )?;
+    let mut source_bond_page_data = load_bond_page(
+        program_id,
+        user_pda,
+        source_bond_page,
+        source_bond_data.page_index,
+    )?;
+    let reward_streams_data =
+        load_reward_stream_list(program_id, reward_streams)?;
+    if stream_accounts.len() < reward_streams_data.streams.len() * 6 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Assert Admin has not paused bond operations
+    if global_admin_data.pause_bond_operations {
+        return Err(TestudoBondsError::BondOperationsPaused.into());
+    }
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "User PDA",
+        user_wallet,
+        &user_pda_data.user,
+    )?;
+    assert_same_pubkeys(
+        "Target Bond PDA",
+        user_pda,
+        &target_bond_data.owner,
+    )?;
+    assert_same_pubkeys(
+        "Source Bond PDA",
+        user_pda,
+        &source_bond_data.owner,
+    )?;
+    assert_valid_bond(
+        target_bond.key,
+        &target_bond_data,
+        &target_bond_page_data,
+    )?;
+    assert_valid_bond(
+        source_bond.key,
+        &source_bond_data,
+        &source_bond_page_data,
+    )?;
+    assert_same_pubkeys(
+        "Rewards Pool ATA",
+        rewards_pool_ata,
+        &global_admin_data.rewards_pool,
+    )?;
+    assert_same_pubkeys(
+        "Native mint",
+        native_token_mint,
+        &global_admin_data.native_token_mint,
+    )?;
+    assert_valid_token_account(
+        "User Wallet ATA",
+        user_wallet.key,
+        native_token_mint.key,
+        user_wallet_ata,
+    )?;
+
+    // Validate program accounts
+    assert_valid_token_program(token_program.key)?;
+    assert_valid_system_program(system_program.key)?;
+
+    // Settle both bonds so merging starts from zero pending rewards
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    assert_bond_perpetual(&target_bond_data)?;
+    assert_bond_perpetual(&source_bond_data)?;
+    assert_bond_unlocked(&target_bond_data, current_timestamp)?;
+    assert_bond_unlocked(&source_bond_data, current_timestamp)?;
+    if target_bond_data.lock_boost_bps
+        != source_bond_data.lock_boost_bps
+    {
+        msg!(
+            "Lock boosts differ: {} and {}",
+            target_bond_data.lock_boost_bps,
+            source_bond_data.lock_boost_bps
+        );
+        return Err(TestudoBondsError::LockBoostMismatch.into());
+    }
+    // A granted bond merged into a bought one would be refunded on
+    // redemption
+    if (target_bond_data.grant_source == GrantSource::None)
+        != (source_bond_data.grant_source == GrantSource::None)
+    {
+        return Err(TestudoBondsError::BondGrantMismatch.into());
+    }
+    for bond_data in [&mut target_bond_data, &mut source_bond_data] {
+        settle_bond_rewards(
+            bond_data,
+            &mut user_pda_data,
+            &global_admin_data,
+            &mut global_state_data,
+            global_admin,
+            global_admin_bump,
+            rewards_pool_ata,
+            treasury_ata,
+            native_token_mint,
+            user_wallet_ata,
+            token_program,
+            current_timestamp,
+        )?;
+    }
+    for (stream_key, accounts) in reward_streams_data
+        .streams
+        .iter()
+        .zip(stream_accounts.chunks_exact(6))
+    {
+        for (bond, bond_data, stream_settlement) in [
+            (target_bond, &target_bond_data, &accounts[4]),
+            (source_bond, &source_bond_data, &accounts[5]),
+        ] {
+            settle_reward_stream(
+                program_id,
+                stream_key,
+                &accounts[..4],
+                stream_settlement,
+                bond,
+                bond_data,
+                user_wallet,
+                user_wallet,
+                system_program,
+                token_program,
+                current_timestamp,
+                false,
+            )?;
+        }
+        close_stream_settlement(
+            program_id,
+            stream_key,
+            &accounts[5],
+            source_bond.key,
+            user_wallet,
+        )?;
+    }
+
+    // Settling the source bond may have redistributed a penalty to the
+    // target, so the target is settled again to collect it before it
+    // takes the merged weight.
+    settle_bond_rewards(
+        &mut target_bond_data,
+        &mut user_pda_data,
+        &global_admin_data,
+        &mut global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        treasury_ata,
+        native_token_mint,
+        user_wallet_ata,
+        token_program,
+        current_timestamp,
+    )?;
+
+    // The merged bond is only as old as its weight on average, so
+    // merging a new bond into an old one does not give it the old
+    // bond's loyalty boost.
+    target_bond_data.creation_timestamp =
+        calculate_merged_creation_timestamp(
+            target_bond_data.creation_timestamp,
+            target_bond_data.weight,
+            source_bond_data.creation_timestamp,
+            source_bond_data.weight,
+        )?;
+
+    // Adding up weights and claimed amounts adds up the remaining
+    // emission headroom of both bonds.
+    target_bond_data.weight = target_bond_data
+        .weight
+        .checked_add(source_bond_data.weight)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    target_bond_data.total_claimed = target_bond_data
+        .total_claimed
+        .checked_add(source_bond_data.total_claimed)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    target_bond_data.reward_remainder = target_bond_data
+        .reward_remainder
+        .checked_add(source_bond_data.reward_remainder)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    msg!(
+        "Merged bond index [{}] into [{}], new weight: {}",
+        source_bond_index,
+        target_bond_index,
+        target_bond_data.weight
+    );
+
+    // Stop tracking the source bond and close it
+    remove_bond_from_page(
+        source_bond_page,
+        &mut source_bond_page_data,
+        &user_pda_data,
+        source_bond.key,
+        user_wallet,
+    )?;
+    user_pda_data.bond_count = user_pda_data
+        .bond_count
+        .checked_sub(1)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+
+    target_bond_data.serialize_account_data(target_bond)?;
+    user_pda_data.serialize_account_data(user_pda)?;
+    global_state_data.serialize_account_data(global_state)?;
+    close_account(source_bond, user_wallet)?;
+
+    Ok(())
+}
+
+pub fn split_bond<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    bond_index: u16,
+    split_weight: u32,
+) -> ProgramResult {
+    // Extract accounts
+    let [bond_pda, new_bond_pda, user_wallet, user_pda, bond_page, new_bond_page, user_wallet_ata, global_admin, rewards_pool_ata, native_token_mint, token_program, system_program, treasury_ata, global_state, reward_streams, stream_accounts @ ..] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    assert_signer("User Wallet", user_wallet)?;
+
+    // Validate PDAs
+    assert_pda(
+        "User PDA",
+        user_pda,
+        program_id,
+        &[b"user", user_wallet.key.as_ref()],
+    )?;
+    assert_non_empty("User PDA", user_pda)?;
+    let global_admin_bump: u8 = assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+
+    // Load account data
+    let mut bond_pda_data =
+        load_bond(program_id, bond_pda, bond_index)?;
+    assert_bond_not_tokenized(&bond_pda_data)?;
+    let mut user_pda_data = UserAccount::deserialize_account_data(
+        user_pda.data.borrow().as_ref(),
+    )?;
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    let bond_page_data = load_bond_page(
+        program_id,
+        user_pda,
+        bond_page,
+        bond_pda_data.page_index,
+    )?;
+    let reward_streams_data =
+        load_reward_stream_list(program_id, reward_streams)?;
+    if stream_accounts.len() < reward_streams_data.streams.len() * 6 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Assert Admin has not paused bond operations
+    if global_admin_data.pause_bond_operations {
+        return Err(TestudoBondsError::BondOperationsPaused.into());
+    }
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "User PDA",
+        user_wallet,
+        &user_pda_data.user,
+    )?;
+    assert_same_pubkeys("Bond PDA", user_pda, &bond_pda_data.owner)?;
+    assert_valid_bond(bond_pda.key, &bond_pda_data, &bond_page_data)?;
+    assert_same_pubkeys(
+        "Rewards Pool ATA",
+        rewards_pool_ata,
+        &global_admin_data.rewards_pool,
+    )?;
+    assert_same_pubkeys(
+        "Native mint",
+        native_token_mint,
+        &global_admin_data.native_token_mint,
+    )?;
+    assert_valid_token_account(
+        "User Wallet ATA",
+        user_wallet.key,
+        native_token_mint.key,
+        user_wallet_ata,
+    )?;
+
+    // Validate program accounts
+    assert_valid_token_program(token_program.key)?;
+    assert_valid_system_program(system_program.key)?;
+
+    // Both bonds must keep some weight
+    if split_weight == 0 || split_weight >= bond_pda_data.weight {
+        msg!(
+            "Split weight [{}] must be between 0 and the bond weight [{}]",
+            split_weight,
+            bond_pda_data.weight
+        );
+        return Err(TestudoBondsError::InvalidBondWeight.into());
+    }
+
+    // Assert User has not reached max bonds
+    if user_pda_data.bond_count
+        >= global_admin_data.max_bonds_per_wallet
+    {
+        msg!(
+            "User has reached max bonds limit: {}/{}",
+            user_pda_data.bond_count,
+            global_admin_data.max_bonds_per_wallet
+        );
+        return Err(TestudoBondsError::MaxBondsReached.into());
+    }
+
+    // Settle the bond so splitting starts from zero pending rewards
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    assert_bond_perpetual(&bond_pda_data)?;
+    assert_bond_unlocked(&bond_pda_data, current_timestamp)?;
+    settle_bond_rewards(
+        &mut bond_pda_data,
+        &mut user_pda_data,
+        &global_admin_data,
+        &mut global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        treasury_ata,
+        native_token_mint,
+        user_wallet_ata,
+        token_program,
+        current_timestamp,
+    )?;
+    for (stream_key, accounts) in reward_streams_data
+        .streams
+        .iter()
+        .zip(stream_accounts.chunks_exact(6))
+    {
+        settle_reward_stream(
+            program_id,
+            stream_key,
+            &accounts[..4],
+            &accounts[4],
+            bond_pda,
+            &bond_pda_data,
+            user_wallet,
+            user_wallet,
+            system_program,
+            token_program,
+            current_timestamp,
+            false,
+        )?;
+    }
+
+    // Move the claimed amount over in proportion to the weight, which
+    // divides the remaining emission headroom the same way.
+    let split_claimed = u64::try_from(
+        (bond_pda_data.total_claimed as u128)
+            .checked_mul(split_weight as u128)
+            .map(|product| product / bond_pda_data.weight as u128)
+            .ok_or(TestudoBondsError::NumericalOverflow)?,
+    )
+    .map_err(|_| TestudoBondsError::NumericalOverflow)?;
+
+    // Create the new bond
+    let new_bond_bump = assert_pda(
+        "New Bond PDA",
+        new_bond_pda,
+        program_id,
+        &[
+            b"bond",
+            user_pda.key.as_ref(),
+            &user_pda_data.bond_index.to_le_bytes(),
+        ],
+    )?;
+    assert_empty("New Bond PDA", new_bond_pda)?;
+
+    let mut new_bond_page_data = load_open_bond_page(
+        program_id,
+        user_pda,
+        &mut user_pda_data,
+        new_bond_page,
+        user_wallet,
+        system_program,
+    )?;
+
+    create_account(
+        new_bond_pda,
+        user_wallet,
+        system_program,
+        Bond::SIZE,
+        program_id,
+        Some(&[&[
+            b"bond",
+            user_pda.key.as_ref(),
+            &user_pda_data.bond_index.to_le_bytes(),
+            &[new_bond_bump],
+        ]]),
+    )?;
+
+    let new_bond_pda_data: Bond = Bond {
+        owner: *user_pda.key,
+        creator: *user_pda.key,
+        bond_index: user_pda_data.bond_index,
+        page_index: new_bond_page_data.page_index,
+        creation_timestamp: bond_pda_data.creation_timestamp,
+        last_claim_timestamp: current_timestamp,
+        total_claimed: split_claimed,
+        is_active: true,
+        mint: Pubkey::default(),
+        is_listed: false,
+        weight: split_weight,
+        penalty_per_weight_checkpoint: global_state_data
+            .penalty_per_weight,
+        reward_remainder: 0,
+        reward_per_weight_checkpoint: global_state_data
+            .reward_per_weight,
+        lock_until: bond_pda_data.lock_until,
+        lock_boost_bps: bond_pda_data.lock_boost_bps,
+        maturity_timestamp: 0,
+        principal: 0,
+        maturity_reward: 0,
+        principal_return_bps: 0,
+        grant_source: bond_pda_data.grant_source,
+    };
+    new_bond_pda_data.serialize_account_data(new_bond_pda)?;
+
+    // The new bond keeps the original creation timestamp, so it is
+    // settled on every stream from now on
+    for (stream_key, accounts) in reward_streams_data
+        .streams
+        .iter()
+        .zip(stream_accounts.chunks_exact(6))
+    {
+        open_stream_settlement(
+            program_id,
+            stream_key,
+            &accounts[5],
+            new_bond_pda.key,
+            user_wallet,
+            system_program,
+            current_timestamp,
+        )?;
+    }
+
+    bond_pda_data.weight = bond_pda_data
+        .weight
+        .checked_sub(split_weight)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+    bond_pda_data.total_claimed = bond_pda_data
+        .total_claimed
+        .checked_sub(split_claimed)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+    bond_pda_data.serialize_account_data(bond_pda)?;
+    msg!(
+        "Split weight {} of bond index [{}] into bond index [{}]",
+        split_weight,
+        bond_index,
+        user_pda_data.bond_index
+    );
+
+    new_bond_page_data.bonds.push(*new_bond_pda.key);
+    new_bond_page_data.serialize_account_data(new_bond_page)?;
+    user_pda_data.bond_index = user_pda_data
+        .bond_index
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    user_pda_data.bond_count = user_pda_data
+        .bond_count
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    user_pda_data.serialize_account_data(user_pda)?;
+    global_state_data.serialize_account_data(global_state)?;
+
+    Ok(())
+}
+
+/// Settle a fixed-term bond at maturity: pay its fixed reward from the
+/// rewards pool, return `principal_return_bps` of the escrowed principal
+/// from the principal vault, split the rest of the principal like a bond
+/// deposit and close the bond.
+pub fn mature_bond<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    bond_index: u16,
+) -> ProgramResult {
+    // Extract accounts
+    let [bond_pda, user_wallet, user_pda, user_bond_page, user_wallet_ata, global_admin, rewards_pool_ata, global_state, principal_vault, principal_vault_ata, team_vesting_ata, native_token_mint, token_program, split_config, recipient_accounts @ ..] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate signer
+    assert_signer("User Wallet", user_wallet)?;
+
+    // Validate PDAs
+    assert_pda(
+        "User PDA",
+        user_pda,
+        program_id,
+        &[b"user", user_wallet.key.as_ref()],
+    )?;
+    assert_non_empty("User PDA", user_pda)?;
+    let global_admin_bump: u8 = assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    let principal_vault_bump: u8 = assert_pda(
+        "Principal Vault PDA",
+        principal_vault,
+        program_id,
+        &[b"principal_vault"],
+    )?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+
+    // Load account data
+    let bond_pda_data = load_bond(program_id, bond_pda, bond_index)?;
+    assert_bond_not_tokenized(&bond_pda_data)?;
+    let mut user_pda_data = UserAccount::deserialize_account_data(
+        user_pda.data.borrow().as_ref(),
+    )?;
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let split_config_data =
+        load_split_config(program_id, split_config)?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    let mut user_bond_page_data = load_bond_page(
+        program_id,
+        user_pda,
+        user_bond_page,
+        bond_pda_data.page_index,
+    )?;
+
+    // Assert Admin has not paused bond operations
+    if global_admin_data.pause_bond_operations {
+        return Err(TestudoBondsError::BondOperationsPaused.into());
+    }
+
+    // Only fixed-term bonds past their maturity can be settled
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    if bond_pda_data.maturity_timestamp == 0
+        || current_timestamp < bond_pda_data.maturity_timestamp
+    {
+        msg!(
+            "Bond matures at {}, now {}",
+            bond_pda_data.maturity_timestamp,
+            current_timestamp
+        );
+        return Err(TestudoBondsError::BondNotMature.into());
+    }
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "User PDA",
+        user_wallet,
+        &user_pda_data.user,
+    )?;
+    assert_same_pubkeys("Bond PDA", user_pda, &bond_pda_data.owner)?;
+    assert_same_pubkeys(
+        "Rewards Pool ATA",
+        rewards_pool_ata,
+        &global_admin_data.rewards_pool,
+    )?;
+    assert_same_pubkeys(
+        "Native mint",
+        native_token_mint,
+        &global_admin_data.native_token_mint,
+    )?;
+    assert_valid_token_account(
+        "User Wallet ATA",
+        user_wallet.key,
+        native_token_mint.key,
+        user_wallet_ata,
+    )?;
+    assert_valid_token_account(
+        "Principal Vault ATA",
+        principal_vault.key,
+        native_token_mint.key,
+        principal_vault_ata,
+    )?;
+    let (team_vesting, _) =
+        Pubkey::find_program_address(&[b"team_vesting"], program_id);
+    assert_valid_token_account(
+        "Team Vesting ATA",
+        &team_vesting,
+        native_token_mint.key,
+        team_vesting_ata,
+    )?;
+    assert_valid_bond(
+        bond_pda.key,
+        &bond_pda_data,
+        &user_bond_page_data,
+    )?;
+
+    // Validate program accounts
+    assert_valid_token_program(token_program.key)?;
+
+    // Pay the fixed reward from the reward source
+    let reward = bond_pda_data.maturity_reward;
+    assert_rewards_available(
+        &global_admin_data,
+        &global_state_data,
+        rewards_pool_ata,
+        reward,
+    )?;
+    pay_rewards(
+        reward,
+        user_wallet_ata,
+        &global_admin_data,
+        &mut global_state_data,
+        global_admin,
+        global_admin_bump,
+        rewards_pool_ata,
+        native_token_mint,
+        token_program,
+    )?;
+
+    // Return the principal share and split the rest like a deposit
+    let returned = calculate_basis_points(
+        bond_pda_data.principal,
+        bond_pda_data.principal_return_bps,
+    )?;
+    let retained = bond_pda_data
+        .principal
+        .checked_sub(returned)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+    msg!(
+        "Maturing bond [{}] - Reward: {}, Returned: {}, Retained: {}",
+        bond_index,
+        reward,
+        returned,
+        retained
+    );
+
+    let principal_vault_seeds: &[&[u8]] =
+        &[b"principal_vault", &[principal_vault_bump]];
+    if returned > 0 {
+        transfer_spl_tokens(
+            token_program,
+            principal_vault_ata,
+            native_token_mint,
+            user_wallet_ata,
+            principal_vault,
+            returned,
+            9,
+            Some(&[principal_vault_seeds]),
+        )?;
+    }
+    // The treasury share stays in the vault
+    pay_deposit_split(
+        &split_config_data,
+        retained,
+        principal_vault_ata,
+        principal_vault,
+        Some(&[principal_vault_seeds]),
+        rewards_pool_ata,
+        principal_vault_ata,
+        team_vesting_ata,
+        native_token_mint,
+        token_program,
+        recipient_accounts,
+        &global_admin_data.team_vesting,
+        &mut global_state_data,
+    )?;
+    global_state_data.serialize_account_data(global_state)?;
+
+    // Stop tracking the bond and close it
+    remove_bond_from_page(
+        user_bond_page,
+        &mut user_bond_page_data,
+        &user_pda_data,
+        bond_pda.key,
+        user_wallet,
+    )?;
+    user_pda_data.bond_count = user_pda_data
+        .bond_count
+        .checked_sub(1)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+    user_pda_data.total_accrued_rewards = user_pda_data
+        .total_accrued_rewards
+        .checked_add(reward)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    user_pda_data.serialize_account_data(user_pda)?;
+
+    close_account(bond_pda, user_wallet)?;
+
+    Ok(())
+}
+
+/// Release the treasury share of bond deposits held in the principal
+/// vault. Anyone can call it once `distribution_interval` has
+/// passed since the last distribution.
+pub fn distribute_vault<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    // Extract accounts
+    let [global_admin, global_state, principal_vault, principal_vault_ata, treasury_ata, native_token_mint, token_program] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate PDAs
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+    let principal_vault_bump: u8 = assert_pda(
+        "Principal Vault PDA",
+        principal_vault,
+        program_id,
+        &[b"principal_vault"],
+    )?;
+
+    // Load account data
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "Treasury ATA",
+        treasury_ata,
+        &global_admin_data.treasury,
+    )?;
+    assert_same_pubkeys(
+        "Native mint",
+        native_token_mint,
+        &global_admin_data.native_token_mint,
+    )?;
+    assert_valid_token_account(
+        "Principal Vault ATA",
+        principal_vault.key,
+        native_token_mint.key,
+        principal_vault_ata,
+    )?;
+
+    // Validate program accounts
+    assert_valid_token_program(token_program.key)?;
+
+    // Assert the distribution schedule allows a release
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let next_distribution = global_state_data
+        .last_vault_distribution
+        .checked_add(global_admin_data.distribution_interval as i64)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    if current_timestamp < next_distribution {
+        msg!("Next vault distribution at {}", next_distribution);
+        return Err(TestudoBondsError::DistributionNotDue.into());
+    }
+
+    let amount = global_state_data.pending_treasury;
+    msg!("Distributing vault - Treasury: {}", amount);
+    if amount > 0 {
+        transfer_spl_tokens(
+            token_program,
+            principal_vault_ata,
+            native_token_mint,
+            treasury_ata,
+            principal_vault,
+            amount,
+            9,
+            Some(&[&[b"principal_vault", &[principal_vault_bump]]]),
+        )?;
+    }
+
+    global_state_data.pending_treasury = 0;
+    global_state_data.last_vault_distribution = current_timestamp;
+    global_state_data.serialize_account_data(global_state)?;
+
+    Ok(())
+}
+
+/// Release the vested part of the team share of bond deposits from the
+/// team vesting escrow to the team. Anyone can call it; the tokens only
+/// ever go to the team ATA.
+pub fn release_vested<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    // Extract accounts
+    let [global_admin, global_state, team_vesting, team_vesting_ata, team_ata, native_token_mint, token_program] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate PDAs
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+    let team_vesting_bump: u8 = assert_pda(
+        "Team Vesting PDA",
+        team_vesting,
+        program_id,
+        &[b"team_vesting"],
+    )?;
+
+    // Load account data
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+
+    // Validate account relationships
+    assert_same_pubkeys(
+        "Team ATA",
+        team_ata,
+        &global_admin_data.team,
+    )?;
+    assert_same_pubkeys(
+        "Native mint",
+        native_token_mint,
+        &global_admin_data.native_token_mint,
+    )?;
+    assert_valid_token_account(
+        "Team Vesting ATA",
+        team_vesting.key,
+        native_token_mint.key,
+        team_vesting_ata,
+    )?;
+
+    // Validate program accounts
+    assert_valid_token_program(token_program.key)?;
+
+    // Give queued deposits a tranche if one has freed up, then release
+    // what has vested and was not released yet
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    schedule_team_vesting_queue(
+        &mut global_state_data,
+        &global_admin_data.team_vesting,
+        current_timestamp,
+    )?;
+    let vested = calculate_team_vested_amount(
+        global_state_data.team_vesting_deposited,
+        &global_state_data.team_vesting_tranches,
+        global_state_data.team_vesting_queued,
+        &global_admin_data.team_vesting,
+        current_timestamp,
+    )?;
+    let amount = vested
+        .saturating_sub(global_state_data.team_vesting_released);
+    if amount == 0 {
+        msg!(
+            "Nothing to release - Vested: {}, Released: {}",
+            vested,
+            global_state_data.team_vesting_released
+        );
+        return Err(TestudoBondsError::NothingToRelease.into());
+    }
+    msg!("Releasing {} vested team tokens", amount);
+
+    transfer_spl_tokens(
+        token_program,
+        team_vesting_ata,
+        native_token_mint,
+        team_ata,
+        team_vesting,
+        amount,
+        9,
+        Some(&[&[b"team_vesting", &[team_vesting_bump]]]),
+    )?;
+
+    global_state_data.team_vesting_released = global_state_data
+        .team_vesting_released
+        .checked_add(amount)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    global_state_data.serialize_account_data(global_state)?;
+
+    Ok(())
+}
+
+/// Replace the recipients of the deposit split. Only the admin authority
+/// can call it.
+pub fn update_split_config<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    recipients: Vec<SplitRecipient>,
+) -> ProgramResult {
+    // Extract accounts
+    let [global_admin, authority, split_config] = &accounts else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate PDAs and account states
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    load_split_config(program_id, split_config)?;
+
+    // Validate signers
+    assert_signer("Authority", authority)?;
+
+    // Assert valid authority
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    assert_same_pubkeys(
+        "Authority",
+        authority,
+        &global_admin_data.authority,
+    )?;
+
+    // Validate and store the new split
+    assert_valid_split_config(&recipients)?;
+    let split_config_data = SplitConfig { recipients };
+    split_config_data.serialize_account_data(split_config)?;
+
+    Ok(())
+}
+
+pub fn attach_reward_stream<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    rate: u64,
+    start_timestamp: i64,
+    end_timestamp: i64,
+) -> ProgramResult {
+    // Extract accounts
+    let [global_admin, authority, reward_streams, reward_stream, stream_pool_ata, stream_mint, system_program, token_program, associated_token_program] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate PDAs and account states
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    let reward_streams_bump = assert_pda(
+        "Reward Stream List PDA",
+        reward_streams,
+        program_id,
+        &[b"reward_streams"],
+    )?;
+    let reward_stream_bump = assert_pda(
+        "Reward Stream PDA",
+        reward_stream,
+        program_id,
+        &[b"reward_stream", stream_mint.key.as_ref()],
+    )?;
+    assert_empty("Reward Stream PDA", reward_stream)?;
+    assert_program_owner("Stream Mint", stream_mint, &spl_token::ID)?;
+
+    // Validate program accounts
+    assert_valid_system_program(system_program.key)?;
+    assert_valid_token_program(token_program.key)?;
+    assert_valid_associated_token_program(
+        associated_token_program.key,
+    )?;
+
+    // Validate signers
+    assert_signer("Authority", authority)?;
+
+    // Assert valid authority
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    assert_same_pubkeys(
+        "Authority",
+        authority,
+        &global_admin_data.authority,
+    )?;
+
+    assert_valid_reward_stream(
+        rate,
+        start_timestamp,
+        end_timestamp,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Add the stream to the list, created with the first stream
+    let mut reward_streams_data =
+        load_reward_stream_list(program_id, reward_streams)?;
+    if reward_streams_data.streams.len()
+        >= RewardStreamList::MAX_STREAMS
+    {
+        msg!(
+            "At most {} reward streams can be attached",
+            RewardStreamList::MAX_STREAMS
+        );
+        return Err(TestudoBondsError::InvalidRewardStream.into());
+    }
+    if reward_streams.data_is_empty() {
+        create_account(
+            reward_streams,
+            authority,
+            system_program,
+            RewardStreamList::SIZE,
+            program_id,
+            Some(&[&[b"reward_streams", &[reward_streams_bump]]]),
+        )?;
+    }
+    reward_streams_data.streams.push(*reward_stream.key);
+    reward_streams_data.serialize_account_data(reward_streams)?;
+
+    // Create the stream and its pool, owned by the stream PDA
+    create_account(
+        reward_stream,
+        authority,
+        system_program,
+        RewardStream::SIZE,
+        program_id,
+        Some(&[&[
+            b"reward_stream",
+            stream_mint.key.as_ref(),
+            &[reward_stream_bump],
+        ]]),
+    )?;
+    assert_valid_token_account(
+        "Stream Pool ATA",
+        reward_stream.key,
+        stream_mint.key,
+        stream_pool_ata,
+    )?;
+    if stream_pool_ata.data_len() != TokenAccount::LEN {
+        create_ata(
+            authority,
+            system_program,
+            token_program,
+            stream_mint,
+            reward_stream,
+            stream_pool_ata,
+            None,
+        )?;
+    }
+
+    let reward_stream_data = RewardStream {
+        mint: *stream_mint.key,
+        pool: *stream_pool_ata.key,
+        decimals: Mint::unpack(stream_mint.data.borrow().as_ref())?
+            .decimals,
+        rate,
+        start_timestamp,
+        end_timestamp,
+    };
+    reward_stream_data.serialize_account_data(reward_stream)?;
+    msg!(
+        "Attached reward stream [{}] of {} per second until {}",
+        reward_stream.key,
+        rate,
+        end_timestamp
+    );
+
+    Ok(())
+}
+
+pub fn detach_reward_stream<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    // Extract accounts
+    let [global_admin, authority, reward_streams, reward_stream, stream_pool_ata, stream_mint, authority_stream_ata, token_program] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate PDAs and account states
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_non_empty("Reward Stream List PDA", reward_streams)?;
+    let reward_stream_bump = assert_pda(
+        "Reward Stream PDA",
+        reward_stream,
+        program_id,
+        &[b"reward_stream", stream_mint.key.as_ref()],
+    )?;
+    assert_non_empty("Reward Stream PDA", reward_stream)?;
+
+    // Validate program accounts
+    assert_valid_token_program(token_program.key)?;
+
+    // Validate signers
+    assert_signer("Authority", authority)?;
+
+    // Assert valid authority
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    assert_same_pubkeys(
+        "Authority",
+        authority,
+        &global_admin_data.authority,
+    )?;
+
+    let reward_stream_data = RewardStream::deserialize_account_data(
+        reward_stream.data.borrow().as_ref(),
+    )?;
+    assert_same_pubkeys(
+        "Stream Pool ATA",
+        stream_pool_ata,
+        &reward_stream_data.pool,
+    )?;
+    assert_valid_token_account(
+        "Authority Stream ATA",
+        authority.key,
+        stream_mint.key,
+        authority_stream_ata,
+    )?;
+
+    // Only ended streams can be detached, so no bond earns from them
+    if reward_stream_data.end_timestamp > Clock::get()?.unix_timestamp
+    {
+        msg!(
+            "Reward stream [{}] runs until {}",
+            reward_stream.key,
+            reward_stream_data.end_timestamp
+        );
+        return Err(TestudoBondsError::InvalidRewardStream.into());
+    }
+
+    // Remove the stream from the list
+    let mut reward_streams_data =
+        load_reward_stream_list(program_id, reward_streams)?;
+    let Some(position) = reward_streams_data
+        .streams
+        .iter()
+        .position(|stream| stream == reward_stream.key)
+    else {
+        msg!("Reward stream [{}] is not attached", reward_stream.key);
+        return Err(TestudoBondsError::InvalidRewardStream.into());
+    };
+    reward_streams_data.streams.remove(position);
+    reward_streams_data.serialize_account_data(reward_streams)?;
+
+    // Sweep what is left in the pool and close the stream
+    let pool_balance =
+        TokenAccount::unpack(stream_pool_ata.data.borrow().as_ref())?
+            .amount;
+    if pool_balance > 0 {
+        transfer_spl_tokens(
+            token_program,
+            stream_pool_ata,
+            stream_mint,
+            authority_stream_ata,
+            reward_stream,
+            pool_balance,
+            reward_stream_data.decimals,
+            Some(&[&[
+                b"reward_stream",
+                reward_stream_data.mint.as_ref(),
+                &[reward_stream_bump],
+            ]]),
+        )?;
+    }
+    close_account(reward_stream, authority)?;
+    msg!(
+        "Detached reward stream [{}], returning {} to the authority",
+        reward_stream.key,
+        pool_balance
+    );
+
+    Ok(())
+}
+
+pub fn grant_bond<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    grant_source: GrantSource,
+) -> ProgramResult {
+    // Extract accounts
+    let [global_admin, grant_authority, user_wallet, user_pda, user_bond_page, bond_pda, global_state, system_program] =
+        &accounts
+    else {
+        return Err(solana_program::program_error::ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate PDAs and account states
+    assert_pda(
+        "Global Admin PDA",
+        global_admin,
+        program_id,
+        &[b"global_admin"],
+    )?;
+    assert_non_empty("Global Admin PDA", global_admin)?;
+    assert_pda(
+        "Global State PDA",
+        global_state,
+        program_id,
+        &[b"global_state"],
+    )?;
+    assert_non_empty("Global State PDA", global_state)?;
+    let user_bump = assert_pda(
+        "User PDA",
+        user_pda,
+        program_id,
+        &[b"user", user_wallet.key.as_ref()],
+    )?;
+
+    // Validate program accounts
+    assert_valid_system_program(system_program.key)?;
+
+    // Validate signers
+    assert_signer("Grant Authority", grant_authority)?;
+
+    // Assert valid grant authority
+    let global_admin_data = Admin::deserialize_account_data(
+        global_admin.data.borrow().as_ref(),
+    )?;
+    assert_same_pubkeys(
+        "Grant Authority",
+        grant_authority,
+        &global_admin_data.grant_authority,
+    )?;
+
+    // Assert Admin has not paused bond operations
+    if global_admin_data.pause_bond_operations {
+        return Err(TestudoBondsError::BondOperationsPaused.into());
+    }
+
+    if grant_source == GrantSource::None {
+        msg!("A granted bond needs a grant source");
+        return Err(TestudoBondsError::InvalidGrantSource.into());
+    }
+
+    // Create the user PDA for wallets that have none yet
+    let mut user_pda_data = if user_pda.data_is_empty() {
+        create_account(
+            user_pda,
+            grant_authority,
+            system_program,
+            UserAccount::SIZE,
+            program_id,
+            Some(&[&[
+                b"user",
+                user_wallet.key.as_ref(),
+                &[user_bump],
+            ]]),
+        )?;
+        UserAccount {
+            user: *user_wallet.key,
+            bond_count: 0,
+            total_accrued_rewards: 0,
+            bond_index: 0,
+            page_count: 0,
+            total_penalties: 0,
+            referrer: Pubkey::default(),
+            allowlist_root: [0; 32],
+            allowlist_used: 0,
+        }
+    } else {
+        assert_program_owner("User PDA", user_pda, program_id)?;
+        UserAccount::deserialize_account_data(
+            user_pda.data.borrow().as_ref(),
+        )?
+    };
+
+    // Assert User has not reached max bonds
+    if user_pda_data.bond_count
+        >= global_admin_data.max_bonds_per_wallet
+    {
+        msg!(
+            "User has reached max bonds limit: {}/{}",
+            user_pda_data.bond_count,
+            global_admin_data.max_bonds_per_wallet
+        );
+        return Err(TestudoBondsError::MaxBondsReached.into());
+    }
+
+    // Validate bond PDA and account state
+    let bond_bump = assert_pda(
+        "Bond PDA",
+        bond_pda,
+        program_id,
+        &[
+            b"bond",
+            user_pda.key.as_ref(),
+            &user_pda_data.bond_index.to_le_bytes(),
+        ],
+    )?;
+    assert_empty("Bond PDA", bond_pda)?;
+
+    let mut user_bond_page_data = load_open_bond_page(
+        program_id,
+        user_pda,
+        &mut user_pda_data,
+        user_bond_page,
+        grant_authority,
+        system_program,
+    )?;
+
+    create_account(
+        bond_pda,
+        grant_authority,
+        system_program,
+        Bond::SIZE,
+        program_id,
+        Some(&[&[
+            b"bond",
+            user_pda.key.as_ref(),
+            &user_pda_data.bond_index.to_le_bytes(),
+            &[bond_bump],
+        ]]),
+    )?;
+
+    let mut global_state_data =
+        GlobalState::deserialize_account_data(
+            global_state.data.borrow().as_ref(),
+        )?;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    update_reward_index(
+        &mut global_state_data,
+        &global_admin_data,
+        current_timestamp,
+    )?;
+
+    let bond_pda_data: Bond = Bond {
+        owner: *user_pda.key,
+        creator: *user_pda.key,
+        bond_index: user_pda_data.bond_index,
+        page_index: user_bond_page_data.page_index,
+        creation_timestamp: current_timestamp,
+        last_claim_timestamp: current_timestamp,
+        total_claimed: 0,
+        is_active: true,
+        mint: Pubkey::default(),
+        is_listed: false,
+        weight: STANDARD_BOND_WEIGHT,
+        penalty_per_weight_checkpoint: global_state_data
+            .penalty_per_weight,
+        reward_remainder: 0,
+        reward_per_weight_checkpoint: global_state_data
+            .reward_per_weight,
+        lock_until: 0,
+        lock_boost_bps: 0,
+        maturity_timestamp: 0,
+        principal: 0,
+        maturity_reward: 0,
+        principal_return_bps: 0,
+        grant_source,
+    };
+    bond_pda_data.serialize_account_data(bond_pda)?;
+
+    global_state_data.total_active_weight = global_state_data
+        .total_active_weight
+        .checked_add(STANDARD_BOND_WEIGHT as u64)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    global_state_data.serialize_account_data(global_state)?;
+
+    user_bond_page_data.bonds.push(*bond_pda.key);
+    user_bond_page_data.serialize_account_data(user_bond_page)?;
+    user_pda_data.bond_index = user_pda_data
+        .bond_index
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    user_pda_data.bond_count = user_pda_data
+        .bond_count
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    user_pda_data.serialize_account_data(user_pda)?;
+    msg!(
+        "Granted bond [{}] to {} ({:?})",
+        bond_pda_data.bond_index,
+        user_wallet.key,
+        grant_source
+    );
 
     Ok(())
 }
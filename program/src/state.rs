@@ -1,5 +1,5 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use shank::ShankAccount;
+use shank::{ShankAccount, ShankType};
 use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
@@ -26,15 +26,59 @@ pub struct Admin {
     pub native_token_mint: Pubkey,
     pub daily_emission_rate: u64, // used as lamports would be used. e.g. 55_000_000 (0.055 tokens in lamports)
     pub max_emission_per_bond: u64, // 20_000_000_000 (20 tokens)
-    pub max_bonds_per_wallet: u8,
-    pub token_deposit_split: [u16; 3], // [rewards pool, treasury, team] in basis points
-    pub claim_penalty: u16,            // basis points. e.g. 500 = 5%
+    pub max_bonds_per_wallet: u16,
+    pub claim_penalty_schedule: [PenaltyTier; 4], // Admin::MAX_PENALTY_TIERS
     pub pause_bond_operations: bool,
+    pub redemption_rate: u16, // basis points of unearned principal refunded on early redemption
+    pub marketplace_fee: u16, // basis points of a bond sale sent to the treasury
+    pub penalty_destination: PenaltyDestination,
+    pub emission_mode: EmissionMode,
+    pub global_emission_rate: u64, // shells per second shared by all active bonds in global mode
+    pub emission_curve: EmissionCurve,
+    pub loyalty_schedule: [LoyaltyTier; 4], // Admin::MAX_LOYALTY_TIERS
+    pub lock_tiers: [LockTier; 4],          // Admin::MAX_LOCK_TIERS
+    pub fixed_term: FixedTermConfig,
+    pub distribution_interval: u32, // seconds between releases of the vault's treasury share
+    pub team_vesting: VestingSchedule,
+    pub reward_source: RewardSource,
+    pub emission_ceiling: u64, // total shells the program may ever mint as rewards in mint mode
+    pub referral_deposit_bps: u16, // basis points of a referred user's bond deposits paid to the referrer
+    pub referral_claim_bps: u16, // basis points of a referred user's claims paid to the referrer
+    pub allowlist: AllowlistPhase,
+    pub grant_authority: Pubkey, // may grant bonds without a deposit
 }
 
 impl Admin {
-    pub const SIZE: usize =
-        32 + 32 + 32 + 32 + 32 + 8 + 8 + 1 + (3 * 2) + 2 + 1;
+    pub const MAX_PENALTY_TIERS: usize = 4;
+    pub const MAX_LOYALTY_TIERS: usize = 4;
+    pub const MAX_LOCK_TIERS: usize = 4;
+    pub const SIZE: usize = 32
+        + 32
+        + 32
+        + 32
+        + 32
+        + 8
+        + 8
+        + 2
+        + (Admin::MAX_PENALTY_TIERS * PenaltyTier::SIZE)
+        + 1
+        + 2
+        + 2
+        + 1
+        + 1
+        + 8
+        + EmissionCurve::SIZE
+        + (Admin::MAX_LOYALTY_TIERS * LoyaltyTier::SIZE)
+        + (Admin::MAX_LOCK_TIERS * LockTier::SIZE)
+        + FixedTermConfig::SIZE
+        + 4
+        + VestingSchedule::SIZE
+        + 1
+        + 8
+        + 2
+        + 2
+        + AllowlistPhase::SIZE
+        + 32;
 }
 
 impl Serialization<Admin> for Admin {
@@ -55,21 +99,279 @@ impl Serialization<Admin> for Admin {
     }
 }
 
+/// Claim penalty applied when less than `max_elapsed_seconds` have
+/// passed since the last claim. The first matching tier of the
+/// schedule applies, and tiers with `max_elapsed_seconds` set to 0 are
+/// unused.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    ShankType,
+)]
+pub struct PenaltyTier {
+    pub max_elapsed_seconds: u32,
+    pub penalty_bps: u16, // basis points. e.g. 500 = 5%
+}
+
+impl PenaltyTier {
+    pub const SIZE: usize = 4 + 2;
+}
+
+/// Emission boost for bonds at least `min_age_seconds` old. The last
+/// matching tier of the schedule applies, and tiers with
+/// `min_age_seconds` set to 0 are unused.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    ShankType,
+)]
+pub struct LoyaltyTier {
+    pub min_age_seconds: u32,
+    pub boost_bps: u16, // basis points added to the emission rate. e.g. 1000 = +10%
+}
+
+impl LoyaltyTier {
+    pub const SIZE: usize = 4 + 2;
+}
+
+/// Lock-up a bond can opt into at creation: the bond cannot be claimed
+/// for `lock_seconds`, and earns `boost_bps` more emission for its whole
+/// life. Tiers with `lock_seconds` set to 0 are unused.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    ShankType,
+)]
+pub struct LockTier {
+    pub lock_seconds: u32,
+    pub boost_bps: u16, // basis points added to the emission rate. e.g. 2500 = +25%
+}
+
+impl LockTier {
+    pub const SIZE: usize = 4 + 2;
+}
+
+/// Terms of fixed-term bonds. The deposit is escrowed in the principal
+/// vault for `term_seconds`, after which the bond pays `reward` and
+/// returns `principal_return_bps` of the deposit. A `term_seconds` of 0
+/// disables fixed-term bonds.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    ShankType,
+)]
+pub struct FixedTermConfig {
+    pub term_seconds: u32,
+    pub reward: u64,               // 9 decimals
+    pub principal_return_bps: u16, // basis points of the deposit returned at maturity
+}
+
+impl FixedTermConfig {
+    pub const SIZE: usize = 4 + 8 + 2;
+}
+
+/// Private sale phase. Between `start_timestamp` and `end_timestamp`,
+/// only wallets proven to be in the Merkle tree of `merkle_root` can
+/// create bonds, up to their allocation. Bond sales are public outside
+/// the window, and an `end_timestamp` of 0 disables the phase.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    ShankType,
+)]
+pub struct AllowlistPhase {
+    pub merkle_root: [u8; 32],
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+}
+
+impl AllowlistPhase {
+    pub const SIZE: usize = 32 + 8 + 8;
+}
+
+/// Proof that a wallet is on the allowlist with `allocation` bonds, 0
+/// for no limit beyond `max_bonds_per_wallet`.
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Debug, ShankType,
+)]
+pub struct AllowlistProof {
+    pub allocation: u16,
+    pub proof: Vec<[u8; 32]>, // Sibling hashes from the wallet's leaf up to the root.
+}
+
+/// Vesting of the team share of bond deposits held in the team vesting
+/// escrow. Nothing vests before `cliff_seconds` after
+/// `start_timestamp`; from then on deposits vest linearly until fully
+/// vested `duration_seconds` after the start.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    ShankType,
+)]
+pub struct VestingSchedule {
+    pub start_timestamp: i64,
+    pub cliff_seconds: u32,
+    pub duration_seconds: u32,
+}
+
+impl VestingSchedule {
+    pub const SIZE: usize = 8 + 4 + 4;
+}
+
+/// Team share of the deposits made within one tranche window. They vest
+/// together under the team vesting schedule from `start_timestamp`, the
+/// end of the window, so no deposit vests before its own cliff. Tranches
+/// with `amount` set to 0 are unused.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    ShankType,
+)]
+pub struct VestingTranche {
+    pub start_timestamp: i64,
+    pub amount: u64, // 9 decimals
+}
+
+impl VestingTranche {
+    pub const SIZE: usize = 8 + 8;
+}
+
+/// Where claim penalties withheld from the rewards pool are routed.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    ShankType,
+)]
+pub enum PenaltyDestination {
+    RewardsPool,  // Left in the rewards pool.
+    Treasury,     // Sent to the treasury.
+    Burn,         // Burned from the rewards pool.
+    Redistribute, // Shared by weight with the other active bonds.
+}
+
+/// Decay of the emission rates over time. The rates apply unchanged
+/// until `period_seconds` after `start_timestamp`, then drop by
+/// `decay_bps` at the end of every period. A `decay_bps` of 5000 halves
+/// emissions every period, and a `decay_bps` of 0 disables the decay.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    ShankType,
+)]
+pub struct EmissionCurve {
+    pub start_timestamp: i64,
+    pub period_seconds: u32,
+    pub decay_bps: u16, // basis points of the rate removed each period
+}
+
+impl EmissionCurve {
+    pub const SIZE: usize = 8 + 4 + 2;
+}
+
+/// How bond rewards accrue.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    ShankType,
+)]
+pub enum EmissionMode {
+    PerBond, // Each bond earns the daily emission rate scaled by its weight.
+    Global, // Bonds share the global emission rate pro-rata by weight.
+}
+
+/// Where bond rewards are paid from.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    ShankType,
+)]
+pub enum RewardSource {
+    Pool, // Rewards are transferred from the pre-funded rewards pool.
+    Mint, // Rewards are minted by the global admin PDA, up to the emission ceiling.
+}
+
+/// Why a bond was granted by the admin instead of bought.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    ShankType,
+)]
+pub enum GrantSource {
+    None,        // Bought with a deposit, or compounded from rewards.
+    Contributor, // Granted to a contributor.
+    Incident,    // Granted to a user affected by an incident.
+    Airdrop,     // Granted in an airdrop.
+}
+
 #[derive(
     BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
 )]
 pub struct UserAccount {
     pub user: Pubkey,
-    pub bond_count: u8, // Number of bonds the user currently has.
+    pub bond_count: u16, // Number of bonds the user currently has.
     pub total_accrued_rewards: u64, // 9 decimals
-    pub bond_index: u8, // Index of the next bond to be created.
-    pub active_bonds: Vec<(u8, Pubkey)>, // (bond_index, bond_pda)
+    pub bond_index: u16, // Index of the next bond to be created.
+    pub page_count: u16, // Number of bond pages opened. New bonds go on the last one.
+    pub total_penalties: u64, // Claim penalties withheld, 9 decimals
+    pub referrer: Pubkey, // Wallet of the user who referred this one, or the default pubkey.
+    pub allowlist_root: [u8; 32], // Merkle root of the allowlist phase `allowlist_used` counts bonds of.
+    pub allowlist_used: u16, // Bonds created during that allowlist phase.
 }
 
 impl UserAccount {
-    pub const MAX_SIZE: usize = 32 + 1 + 8 + 1 + 4 + (10 * (1 + 32));
-    pub const INITIAL_SIZE: usize = 32 + 1 + 8 + 1 + 4;
-    pub const BOND_ENTRY_SIZE: usize = 1 + 32; // bond_index(1) + bond_pda(32)
+    pub const SIZE: usize = 32 + 2 + 8 + 2 + 2 + 8 + 32 + 32 + 2;
 }
 
 impl Serialization<UserAccount> for UserAccount {
@@ -84,19 +386,41 @@ impl Serialization<UserAccount> for UserAccount {
     fn deserialize_account_data(
         data: &[u8],
     ) -> Result<UserAccount, ProgramError> {
-        // Standard deserialization - works for both new (INITIAL_SIZE) and old accounts
         UserAccount::try_from_slice(data)
             .map_err(|_| ProgramError::InvalidAccountData)
     }
 }
 
-impl UserAccount {
-    pub fn get_size(&self) -> usize {
-        let mut size = UserAccount::INITIAL_SIZE;
-        for (_bond_index, _) in &self.active_bonds {
-            size += 1 + 32;
-        }
-        size
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
+)]
+pub struct UserBondPage {
+    pub owner: Pubkey, // User PDA the page belongs to.
+    pub page_index: u16,
+    pub bonds: Vec<Pubkey>, // Bond PDAs tracked on this page.
+}
+
+impl UserBondPage {
+    pub const MAX_BONDS: usize = 32;
+    pub const SIZE: usize = 32 + 2 + 4 + (Self::MAX_BONDS * 32);
+}
+
+impl Serialization<UserBondPage> for UserBondPage {
+    fn serialize_account_data(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        self.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    fn deserialize_account_data(
+        data: &[u8],
+    ) -> Result<UserBondPage, ProgramError> {
+        // Pages are allocated at full capacity, so the trailing bytes
+        // past the bonds vector are ignored.
+        UserBondPage::deserialize(&mut &data[..])
+            .map_err(|_| ProgramError::InvalidAccountData)
     }
 }
 
@@ -104,17 +428,52 @@ impl UserAccount {
     BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
 )]
 pub struct Bond {
-    pub owner: Pubkey,
-    pub bond_index: u8,
+    pub owner: Pubkey, // User PDA that currently owns the bond.
+    pub creator: Pubkey, // User PDA the bond PDA was derived from.
+    pub bond_index: u16,
+    pub page_index: u16, // Bond page of the owner this bond is tracked on.
     pub creation_timestamp: i64,
     pub last_claim_timestamp: i64,
     pub total_claimed: u64, // Total amount claimed from the bond.
     // pub accrued_rewards: u64, // Rewards that have been accrued since last claim.
     pub is_active: bool, // If the bond is active, it can be claimed.
+    pub mint: Pubkey, // One-of-one bond token mint, default pubkey if not tokenized.
+    pub is_listed: bool, // Listed bonds are locked until sold or the listing is cancelled.
+    pub weight: u32, // Scales emission rate and cap. 10_000 = one standard bond.
+    pub penalty_per_weight_checkpoint: u128, // Penalty accumulator value at the last claim.
+    pub reward_remainder: u64, // Accrued fraction of a shell carried to the next claim.
+    pub reward_per_weight_checkpoint: u128, // Global reward index value at the last claim.
+    pub lock_until: i64, // The bond cannot be claimed before this timestamp. 0 if never locked.
+    pub lock_boost_bps: u16, // Emission boost earned by the lock-up, in basis points.
+    pub maturity_timestamp: i64, // Maturity of a fixed-term bond. 0 for perpetual bonds.
+    pub principal: u64, // Deposit escrowed in the principal vault for a fixed-term bond.
+    pub maturity_reward: u64, // Reward paid at maturity of a fixed-term bond.
+    pub principal_return_bps: u16, // Basis points of the principal returned at maturity.
+    pub grant_source: GrantSource, // Why the bond was granted by the admin, `None` for bought bonds.
 }
 
 impl Bond {
-    pub const SIZE: usize = 32 + 1 + 8 + 8 + 8 + 1;
+    pub const SIZE: usize = 32
+        + 32
+        + 2
+        + 2
+        + 8
+        + 8
+        + 8
+        + 1
+        + 32
+        + 1
+        + 4
+        + 16
+        + 8
+        + 16
+        + 8
+        + 2
+        + 8
+        + 8
+        + 8
+        + 2
+        + 1;
 }
 
 impl Serialization<Bond> for Bond {
@@ -134,3 +493,299 @@ impl Serialization<Bond> for Bond {
         Ok(data)
     }
 }
+
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
+)]
+pub struct Listing {
+    pub bond: Pubkey,
+    pub seller: Pubkey, // User PDA of the seller.
+    pub seller_wallet: Pubkey,
+    pub price: u64, // Asking price in native tokens (9 decimals).
+    pub created_timestamp: i64,
+}
+
+impl Listing {
+    pub const SIZE: usize = 32 + 32 + 32 + 8 + 8;
+}
+
+impl Serialization<Listing> for Listing {
+    fn serialize_account_data(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        self.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    fn deserialize_account_data(
+        data: &[u8],
+    ) -> Result<Listing, ProgramError> {
+        let data: Listing = Listing::try_from_slice(data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(data)
+    }
+}
+
+/// Protocol-wide counters updated by the program. Kept apart from
+/// `Admin`, which is overwritten as a whole on `UpdateAdmin`.
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
+)]
+pub struct GlobalState {
+    pub total_penalties: u64, // Claim penalties withheld, 9 decimals
+    pub total_active_weight: u64, // Sum of the weights of active bonds.
+    pub penalty_per_weight: u128, // Redistributed penalties per weight unit, scaled by PENALTY_PER_WEIGHT_PRECISION.
+    pub reward_per_weight: u128, // Global emission per weight unit, scaled by REWARD_PER_WEIGHT_PRECISION.
+    pub last_emission_update: i64, // Timestamp the global reward index was last brought up to.
+    pub pending_treasury: u64, // Treasury share of deposits held in the principal vault.
+    pub last_vault_distribution: i64, // Timestamp of the last DistributeVault.
+    pub team_vesting_deposited: u64, // Team share of deposits paid into the team vesting escrow.
+    pub team_vesting_released: u64, // Vested team tokens released to the team.
+    pub team_vesting_tranches: [VestingTranche; 16], // GlobalState::MAX_VESTING_TRANCHES
+    pub team_vesting_queued: u64, // Team deposits waiting for a free tranche, not vesting yet.
+    pub total_burned: u64, // Deposit shares and penalties burned, 9 decimals
+    pub total_minted: u64, // Rewards minted in mint mode, 9 decimals
+}
+
+impl GlobalState {
+    pub const MAX_VESTING_TRANCHES: usize = 16;
+    pub const SIZE: usize = 8
+        + 8
+        + 16
+        + 16
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + (GlobalState::MAX_VESTING_TRANCHES * VestingTranche::SIZE)
+        + 8
+        + 8
+        + 8;
+}
+
+impl Serialization<GlobalState> for GlobalState {
+    fn serialize_account_data(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        self.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    fn deserialize_account_data(
+        data: &[u8],
+    ) -> Result<GlobalState, ProgramError> {
+        let data: GlobalState = GlobalState::try_from_slice(data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(data)
+    }
+}
+
+/// Destination kinds of a share of each bond deposit.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    ShankType,
+)]
+pub enum SplitDestination {
+    RewardsPool, // Paid to the rewards pool.
+    Treasury,    // Held in the principal vault until DistributeVault.
+    Team,        // Paid into the team vesting escrow.
+    Burn,        // Burned from the deposit.
+    Account,     // Paid to `token_account`.
+}
+
+/// One recipient of the deposit split.
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Copy, Debug, ShankType,
+)]
+pub struct SplitRecipient {
+    pub destination: SplitDestination,
+    pub token_account: Pubkey, // Destination token account, only used by `Account` recipients.
+    pub bps: u16,              // basis points of the deposit
+}
+
+impl SplitRecipient {
+    pub const SIZE: usize = 1 + 32 + 2;
+}
+
+/// Recipients of the shares of each bond deposit, paid in order. Their
+/// basis points add up to 10000.
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
+)]
+pub struct SplitConfig {
+    pub recipients: Vec<SplitRecipient>,
+}
+
+impl SplitConfig {
+    pub const MAX_RECIPIENTS: usize = 8;
+    pub const SIZE: usize =
+        4 + (Self::MAX_RECIPIENTS * SplitRecipient::SIZE);
+}
+
+impl Serialization<SplitConfig> for SplitConfig {
+    fn serialize_account_data(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        self.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    fn deserialize_account_data(
+        data: &[u8],
+    ) -> Result<SplitConfig, ProgramError> {
+        // The account is allocated for `MAX_RECIPIENTS`, so the trailing
+        // bytes past the recipients vector are ignored.
+        SplitConfig::deserialize(&mut &data[..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// A partner token emitted to active bonds next to native rewards.
+/// Each bond earns `rate` per second, scaled by its weight, between
+/// `start_timestamp` and `end_timestamp`, paid from `pool`, the token
+/// account of the reward stream PDA.
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
+)]
+pub struct RewardStream {
+    pub mint: Pubkey,
+    pub pool: Pubkey,
+    pub decimals: u8,
+    pub rate: u64, // base units of the stream token per second per standard bond
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+}
+
+impl RewardStream {
+    pub const SIZE: usize = 32 + 32 + 1 + 8 + 8 + 8;
+}
+
+impl Serialization<RewardStream> for RewardStream {
+    fn serialize_account_data(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        self.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    fn deserialize_account_data(
+        data: &[u8],
+    ) -> Result<RewardStream, ProgramError> {
+        let data: RewardStream =
+            RewardStream::try_from_slice(data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(data)
+    }
+}
+
+/// Reward streams attached by the admin, all paid on every claim.
+#[derive(
+    BorshDeserialize,
+    BorshSerialize,
+    Clone,
+    Debug,
+    Default,
+    ShankAccount,
+)]
+pub struct RewardStreamList {
+    pub streams: Vec<Pubkey>,
+}
+
+impl RewardStreamList {
+    pub const MAX_STREAMS: usize = 4;
+    pub const SIZE: usize = 4 + (Self::MAX_STREAMS * 32);
+}
+
+impl Serialization<RewardStreamList> for RewardStreamList {
+    fn serialize_account_data(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        self.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    fn deserialize_account_data(
+        data: &[u8],
+    ) -> Result<RewardStreamList, ProgramError> {
+        // The account is allocated for `MAX_STREAMS`, so the trailing
+        // bytes past the streams vector are ignored.
+        RewardStreamList::deserialize(&mut &data[..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Point up to which a bond was paid the rewards of a reward stream.
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
+)]
+pub struct StreamSettlement {
+    pub last_settled_timestamp: i64,
+}
+
+impl StreamSettlement {
+    pub const SIZE: usize = 8;
+}
+
+impl Serialization<StreamSettlement> for StreamSettlement {
+    fn serialize_account_data(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        self.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    fn deserialize_account_data(
+        data: &[u8],
+    ) -> Result<StreamSettlement, ProgramError> {
+        let data: StreamSettlement =
+            StreamSettlement::try_from_slice(data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(data)
+    }
+}
+
+/// Totals of the referral rewards paid to one referrer.
+#[derive(
+    BorshDeserialize, BorshSerialize, Clone, Debug, ShankAccount,
+)]
+pub struct ReferrerStats {
+    pub referrer: Pubkey,    // Wallet of the referrer.
+    pub referral_count: u32, // Users who set this referrer.
+    pub total_deposit_rewards: u64, // Deposit shares paid, 9 decimals
+    pub total_claim_rewards: u64, // Claim shares paid, 9 decimals
+}
+
+impl ReferrerStats {
+    pub const SIZE: usize = 32 + 4 + 8 + 8;
+}
+
+impl Serialization<ReferrerStats> for ReferrerStats {
+    fn serialize_account_data(
+        &self,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        self.serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    fn deserialize_account_data(
+        data: &[u8],
+    ) -> Result<ReferrerStats, ProgramError> {
+        let data: ReferrerStats = ReferrerStats::try_from_slice(data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        Ok(data)
+    }
+}
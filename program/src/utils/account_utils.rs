@@ -91,7 +91,7 @@ pub fn close_account<'a>(
     **receiving_account.lamports.borrow_mut() =
         dest_starting_lamports
             .checked_add(target_account.lamports())
-            .unwrap();
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
     **target_account.lamports.borrow_mut() = 0;
 
     target_account.assign(&system_program_id);
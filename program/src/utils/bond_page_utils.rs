@@ -0,0 +1,193 @@
+use crate::assertions::{assert_empty, assert_non_empty, assert_pda};
+use crate::error::TestudoBondsError;
+use crate::state::{Bond, Serialization, UserAccount, UserBondPage};
+use crate::utils::account_utils::{close_account, create_account};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+/// Load the bond page at `page_index` of the given user.
+pub fn load_bond_page(
+    program_id: &Pubkey,
+    user_pda: &AccountInfo,
+    bond_page: &AccountInfo,
+    page_index: u16,
+) -> Result<UserBondPage, ProgramError> {
+    assert_pda(
+        "User Bond Page",
+        bond_page,
+        program_id,
+        &[
+            b"bond_page",
+            user_pda.key.as_ref(),
+            &page_index.to_le_bytes(),
+        ],
+    )?;
+    assert_non_empty("User Bond Page", bond_page)?;
+
+    UserBondPage::deserialize_account_data(
+        bond_page.data.borrow().as_ref(),
+    )
+}
+
+/// Load the page new bonds of the user are tracked on. The given
+/// account must either be the user's current page with room left, or
+/// the next page, which is created here.
+pub fn load_open_bond_page<'a>(
+    program_id: &Pubkey,
+    user_pda: &AccountInfo<'a>,
+    user_pda_data: &mut UserAccount,
+    bond_page: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<UserBondPage, ProgramError> {
+    if let Some(current_page_index) =
+        user_pda_data.page_count.checked_sub(1)
+    {
+        let (current_page, _) = Pubkey::find_program_address(
+            &[
+                b"bond_page",
+                user_pda.key.as_ref(),
+                &current_page_index.to_le_bytes(),
+            ],
+            program_id,
+        );
+
+        if current_page == *bond_page.key {
+            let bond_page_data =
+                UserBondPage::deserialize_account_data(
+                    bond_page.data.borrow().as_ref(),
+                )?;
+            if bond_page_data.bonds.len() >= UserBondPage::MAX_BONDS {
+                msg!(
+                    "Bond page [{}] is full, the next page must be provided",
+                    current_page_index
+                );
+                return Err(TestudoBondsError::BondPageFull.into());
+            }
+            return Ok(bond_page_data);
+        }
+    }
+
+    // Open the next page
+    let page_index = user_pda_data.page_count;
+    let bump = assert_pda(
+        "User Bond Page",
+        bond_page,
+        program_id,
+        &[
+            b"bond_page",
+            user_pda.key.as_ref(),
+            &page_index.to_le_bytes(),
+        ],
+    )?;
+    assert_empty("User Bond Page", bond_page)?;
+
+    create_account(
+        bond_page,
+        payer,
+        system_program,
+        UserBondPage::SIZE,
+        program_id,
+        Some(&[&[
+            b"bond_page",
+            user_pda.key.as_ref(),
+            &page_index.to_le_bytes(),
+            &[bump],
+        ]]),
+    )?;
+    msg!("Opened bond page [{}]", page_index);
+
+    user_pda_data.page_count = page_index
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    Ok(UserBondPage {
+        owner: *user_pda.key,
+        page_index,
+        bonds: Vec::new(),
+    })
+}
+
+/// Remove a bond from its page. Emptied pages are closed unless they
+/// are the user's current page, which new bonds keep filling.
+pub fn remove_bond_from_page<'a>(
+    bond_page: &AccountInfo<'a>,
+    bond_page_data: &mut UserBondPage,
+    user_pda_data: &UserAccount,
+    bond: &Pubkey,
+    rent_receiver: &AccountInfo<'a>,
+) -> ProgramResult {
+    bond_page_data.bonds.retain(|key| key != bond);
+
+    let is_current_page =
+        bond_page_data.page_index + 1 == user_pda_data.page_count;
+    if bond_page_data.bonds.is_empty() && !is_current_page {
+        msg!(
+            "Closing empty bond page [{}]",
+            bond_page_data.page_index
+        );
+        close_account(bond_page, rent_receiver)
+    } else {
+        bond_page_data.serialize_account_data(bond_page)
+    }
+}
+
+/// Move a bond from its owner's page onto the recipient's open page and
+/// hand it over to the recipient. The caller serializes the bond and
+/// both user accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn move_bond<'a>(
+    program_id: &Pubkey,
+    bond_pda: &AccountInfo<'a>,
+    bond_pda_data: &mut Bond,
+    owner_bond_page: &AccountInfo<'a>,
+    owner_bond_page_data: &mut UserBondPage,
+    owner_user_pda_data: &mut UserAccount,
+    recipient_user_pda: &AccountInfo<'a>,
+    recipient_user_pda_data: &mut UserAccount,
+    recipient_bond_page: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    rent_receiver: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    remove_bond_from_page(
+        owner_bond_page,
+        owner_bond_page_data,
+        owner_user_pda_data,
+        bond_pda.key,
+        rent_receiver,
+    )?;
+    owner_user_pda_data.bond_count = owner_user_pda_data
+        .bond_count
+        .checked_sub(1)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+
+    let mut recipient_bond_page_data = load_open_bond_page(
+        program_id,
+        recipient_user_pda,
+        recipient_user_pda_data,
+        recipient_bond_page,
+        payer,
+        system_program,
+    )?;
+    recipient_bond_page_data.bonds.push(*bond_pda.key);
+    recipient_bond_page_data
+        .serialize_account_data(recipient_bond_page)?;
+    recipient_user_pda_data.bond_count = recipient_user_pda_data
+        .bond_count
+        .checked_add(1)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    bond_pda_data.owner = *recipient_user_pda.key;
+    bond_pda_data.page_index = recipient_bond_page_data.page_index;
+    msg!(
+        "Bond [{}] moved to [{}] on page [{}]",
+        bond_pda.key,
+        recipient_user_pda.key,
+        bond_pda_data.page_index
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::assertions::{
+    assert_non_empty, assert_pda, assert_program_owner,
+};
+use crate::error::TestudoBondsError;
+use crate::state::{Bond, Serialization};
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Load a bond account. The PDA is derived from the `creator` stored on
+/// the bond rather than its current owner, since ownership can move to
+/// another user after the bond is created.
+pub fn load_bond(
+    program_id: &Pubkey,
+    bond_pda: &AccountInfo,
+    bond_index: u16,
+) -> Result<Bond, ProgramError> {
+    assert_non_empty("Bond PDA", bond_pda)?;
+    assert_program_owner("Bond PDA", bond_pda, program_id)?;
+
+    let bond_pda_data = Bond::deserialize_account_data(
+        bond_pda.data.borrow().as_ref(),
+    )?;
+    if bond_pda_data.bond_index != bond_index {
+        msg!(
+            "Bond index [{}] does not match the bond account [{}]",
+            bond_index,
+            bond_pda_data.bond_index
+        );
+        return Err(TestudoBondsError::InvalidBondIndex.into());
+    }
+
+    assert_pda(
+        "Bond PDA",
+        bond_pda,
+        program_id,
+        &[
+            b"bond",
+            bond_pda_data.creator.as_ref(),
+            &bond_index.to_le_bytes(),
+        ],
+    )?;
+
+    Ok(bond_pda_data)
+}
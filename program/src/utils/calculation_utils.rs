@@ -1,65 +1,1050 @@
 use solana_program::program_error::ProgramError;
 
-use crate::{constants::SHELLS_PER_TESTUDO, error::TestudoBondsError};
+use crate::{
+    constants::{PENALTY_PER_WEIGHT_PRECISION, REWARD_PER_WEIGHT_PRECISION, STANDARD_BOND_WEIGHT},
+    error::TestudoBondsError,
+    state::{
+        Admin, Bond, EmissionCurve, EmissionMode, GlobalState, LoyaltyTier, PenaltyTier,
+        RewardStream, SplitDestination, SplitRecipient, VestingSchedule, VestingTranche,
+    },
+    utils::fixed_point_utils::{accrue_emission, integrate_emission, Accrual},
+};
 
+/// Reward payable on a claim, the claim penalty withheld from it and the accrual remainder to
+/// store on the bond.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClaimReward {
+    pub reward: u64,
+    pub penalty: u64,
+    pub remainder: u64,
+}
+
+/// Reward claimable on a bond at `current_timestamp`. In per bond mode the emission rate, decayed
+/// by the emission curve and boosted by the loyalty schedule and the bond's lock-up, is scaled by
+/// the bond's weight; in global mode the bond earns its weight's share of the global reward index
+/// since its checkpoint. The claim penalty and the weighted cap apply in both modes, and the
+/// reward is zero when less than a whole shell has accrued.
+pub fn calculate_bond_reward(
+    bond: &Bond,
+    admin: &Admin,
+    reward_per_weight: u128,
+    current_timestamp: i64,
+) -> Result<ClaimReward, ProgramError> {
+    let emission_cap = calculate_weighted_amount(admin.max_emission_per_bond, bond.weight)?;
+    match admin.emission_mode {
+        EmissionMode::PerBond => calculate_reward(
+            &bond.last_claim_timestamp,
+            &current_timestamp,
+            &bond.creation_timestamp,
+            admin.daily_emission_rate,
+            &admin.emission_curve,
+            &admin.loyalty_schedule,
+            bond.lock_boost_bps,
+            bond.weight,
+            bond.reward_remainder,
+            &admin.claim_penalty_schedule,
+            emission_cap,
+            bond.total_claimed,
+        ),
+        EmissionMode::Global => {
+            let reward = calculate_index_share(
+                bond.weight,
+                bond.reward_per_weight_checkpoint,
+                reward_per_weight,
+                REWARD_PER_WEIGHT_PRECISION,
+            )?;
+            calculate_claim(
+                reward,
+                bond.reward_remainder,
+                &bond.last_claim_timestamp,
+                &current_timestamp,
+                &admin.claim_penalty_schedule,
+                emission_cap,
+                bond.total_claimed,
+            )
+        }
+    }
+}
+
+/// Amount owed to `weight` for the growth of a per weight index since `checkpoint`.
+pub fn calculate_index_share(
+    weight: u32,
+    checkpoint: u128,
+    index: u128,
+    precision: u128,
+) -> Result<u64, ProgramError> {
+    let share = index
+        .checked_sub(checkpoint)
+        .and_then(|delta| delta.checked_mul(weight as u128))
+        .map(|product| product / precision)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    u64::try_from(share).map_err(|_| TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Global reward index at `current_timestamp`. The global emission since the last update, decayed
+/// by the emission curve, is added per unit of active weight; the index only moves in global mode
+/// while bonds are active.
+pub fn calculate_reward_per_weight(
+    admin: &Admin,
+    global_state: &GlobalState,
+    current_timestamp: i64,
+) -> Result<u128, ProgramError> {
+    if admin.emission_mode != EmissionMode::Global || global_state.total_active_weight == 0 {
+        return Ok(global_state.reward_per_weight);
+    }
+    let emission = integrate_emission(
+        admin.global_emission_rate,
+        &admin.emission_curve,
+        global_state.last_emission_update,
+        current_timestamp,
+    )?;
+
+    let increase = emission
+        .checked_mul(REWARD_PER_WEIGHT_PRECISION)
+        .map(|product| product / global_state.total_active_weight as u128)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    global_state
+        .reward_per_weight
+        .checked_add(increase)
+        .ok_or(TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Scale a per standard bond amount by a bond weight, where `STANDARD_BOND_WEIGHT` is one bond.
+pub fn calculate_weighted_amount(amount: u64, weight: u32) -> Result<u64, ProgramError> {
+    let weighted = (amount as u128)
+        .checked_mul(weight as u128)
+        .map(|product| product / STANDARD_BOND_WEIGHT as u128)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    u64::try_from(weighted).map_err(|_| TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Creation timestamp of a bond merged from two bonds: the average of both creation timestamps
+/// weighted by the bond weights, so the merged bond's age-based loyalty boost reflects how much of
+/// its weight is old.
+pub fn calculate_merged_creation_timestamp(
+    target_timestamp: i64,
+    target_weight: u32,
+    source_timestamp: i64,
+    source_weight: u32,
+) -> Result<i64, ProgramError> {
+    let total_weight = target_weight as i128 + source_weight as i128;
+    let weighted_sum = target_timestamp as i128 * target_weight as i128
+        + source_timestamp as i128 * source_weight as i128;
+
+    weighted_sum
+        .checked_div(total_weight)
+        .and_then(|timestamp| i64::try_from(timestamp).ok())
+        .ok_or(TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Redistributed penalties owed to a bond since its accumulator checkpoint, capped by the emission
+/// headroom left on the bond once `reward` is paid.
+pub fn calculate_bond_penalty_share(
+    bond: &Bond,
+    admin: &Admin,
+    penalty_per_weight: u128,
+    reward: u64,
+) -> Result<u64, ProgramError> {
+    let share = calculate_index_share(
+        bond.weight,
+        bond.penalty_per_weight_checkpoint,
+        penalty_per_weight,
+        PENALTY_PER_WEIGHT_PRECISION,
+    )?;
+
+    let headroom = calculate_weighted_amount(admin.max_emission_per_bond, bond.weight)?
+        .saturating_sub(bond.total_claimed)
+        .saturating_sub(reward);
+
+    Ok(share.min(headroom))
+}
+
+/// Increase of the penalty per weight accumulator when `penalty` is shared across `total_weight`.
+pub fn calculate_penalty_per_weight(penalty: u64, total_weight: u64) -> Result<u128, ProgramError> {
+    if total_weight == 0 {
+        return Ok(0);
+    }
+    (penalty as u128)
+        .checked_mul(PENALTY_PER_WEIGHT_PRECISION)
+        .map(|product| product / total_weight as u128)
+        .ok_or(TestudoBondsError::NumericalOverflow.into())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_reward(
     previous_claim_timestamp: &i64,
     current_timestamp: &i64,
+    creation_timestamp: &i64,
     daily_emission: u64,
-    claim_penalty: u16,
+    emission_curve: &EmissionCurve,
+    loyalty_schedule: &[LoyaltyTier],
+    lock_boost_bps: u16,
+    weight: u32,
+    reward_remainder: u64,
+    penalty_schedule: &[PenaltyTier],
     max_emission_per_bond: u64,
     total_claimed: u64,
-) -> Result<u64, ProgramError> {
-    let seconds_elapsed = (current_timestamp - previous_claim_timestamp) as u64;
-    let seconds_per_day = 86400u64;
+) -> Result<ClaimReward, ProgramError> {
+    calculate_elapsed_seconds(*previous_claim_timestamp, *current_timestamp)?;
+
+    // Integrate the decaying, boosted daily emission over the claim
+    // interval and accrue it in fixed point, carrying the fraction of a
+    // shell to the next claim instead of truncating it
+    let emission = integrate_boosted_emission(
+        daily_emission,
+        emission_curve,
+        *creation_timestamp,
+        loyalty_schedule,
+        lock_boost_bps,
+        *previous_claim_timestamp,
+        *current_timestamp,
+    )?;
+    let Accrual {
+        amount: reward,
+        remainder,
+    } = accrue_emission(emission, weight, reward_remainder)?;
+
+    calculate_claim(
+        reward,
+        remainder,
+        previous_claim_timestamp,
+        current_timestamp,
+        penalty_schedule,
+        max_emission_per_bond,
+        total_claimed,
+    )
+}
+
+/// Emission of `daily_emission` decayed by `emission_curve` from `from_timestamp` to `to_timestamp`,
+/// scaled by `10000 + loyalty boost + lock_boost_bps` basis points. The interval is split wherever
+/// the bond crosses a loyalty tier, so each part is boosted by the tier the bond was in at the
+/// time.
+fn integrate_boosted_emission(
+    daily_emission: u64,
+    emission_curve: &EmissionCurve,
+    creation_timestamp: i64,
+    loyalty_schedule: &[LoyaltyTier],
+    lock_boost_bps: u16,
+    from_timestamp: i64,
+    to_timestamp: i64,
+) -> Result<u128, ProgramError> {
+    let mut emission: u128 = 0;
+    let mut timestamp = from_timestamp;
+    loop {
+        let age = calculate_elapsed_seconds(creation_timestamp, timestamp).unwrap_or(0);
+        let boost_bps = calculate_loyalty_boost(loyalty_schedule, age);
+
+        // The next tier the bond reaches ends this part of the interval
+        let segment_end = loyalty_schedule
+            .iter()
+            .filter(|tier| tier.min_age_seconds as u64 > age)
+            .map(|tier| creation_timestamp.saturating_add(tier.min_age_seconds as i64))
+            .min()
+            .map_or(to_timestamp, |tier_start| tier_start.min(to_timestamp));
+
+        emission = integrate_emission(daily_emission, emission_curve, timestamp, segment_end)?
+            .checked_mul(10_000 + boost_bps as u128 + lock_boost_bps as u128)
+            .and_then(|segment| emission.checked_add(segment))
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+        if segment_end >= to_timestamp {
+            return Ok(emission);
+        }
+        timestamp = segment_end;
+    }
+}
 
-    // Calculate reward based on seconds elapsed with full precision
-    // Formula: (daily_emission * seconds_elapsed) / seconds_per_day
-    let reward = daily_emission
-        .checked_mul(seconds_elapsed)
-        .and_then(|product| product.checked_div(seconds_per_day))
-        .unwrap_or(0);
+/// Loyalty boost in basis points for a bond `age_seconds` old: the boost of the last tier of the
+/// schedule the bond is old enough for, or 0.
+pub fn calculate_loyalty_boost(loyalty_schedule: &[LoyaltyTier], age_seconds: u64) -> u16 {
+    loyalty_schedule
+        .iter()
+        .rfind(|tier| tier.min_age_seconds > 0 && age_seconds >= tier.min_age_seconds as u64)
+        .map_or(0, |tier| tier.boost_bps)
+}
 
+/// Apply the claim penalty and the emission cap to the `reward` accrued since the last claim.
+fn calculate_claim(
+    reward: u64,
+    remainder: u64,
+    previous_claim_timestamp: &i64,
+    current_timestamp: &i64,
+    penalty_schedule: &[PenaltyTier],
+    max_emission_per_bond: u64,
+    total_claimed: u64,
+) -> Result<ClaimReward, ProgramError> {
     if reward == 0 {
-        return Err(TestudoBondsError::NoRewardsToClaim.into());
+        return Ok(ClaimReward {
+            remainder,
+            ..ClaimReward::default()
+        });
     }
-    let mut reward_with_penalty = calculate_claim_penalty(
+    let reward_with_penalty = calculate_claim_penalty(
         previous_claim_timestamp,
         current_timestamp,
-        &claim_penalty,
+        penalty_schedule,
         &reward,
     )?;
 
-    // Assert reward is not greater than max emission per bond
-    if reward_with_penalty + total_claimed > max_emission_per_bond {
-        reward_with_penalty = max_emission_per_bond - total_claimed;
+    let penalty = reward
+        .checked_sub(reward_with_penalty)
+        .ok_or(TestudoBondsError::NumericalUnderflow)?;
+
+    // Cap the reward at the emission headroom left on the bond, which is
+    // zero if the cap was lowered below what the bond already claimed.
+    // The penalty only applies to emission within the headroom.
+    let headroom = max_emission_per_bond.saturating_sub(total_claimed);
+    let reward = reward_with_penalty.min(headroom);
+    let penalty_headroom = headroom
+        .checked_sub(reward)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    Ok(ClaimReward {
+        reward,
+        penalty: penalty.min(penalty_headroom),
+        remainder,
+    })
+}
+
+/// Seconds elapsed between two timestamps. Fails if the clock went backwards.
+pub fn calculate_elapsed_seconds(
+    previous_timestamp: i64,
+    current_timestamp: i64,
+) -> Result<u64, ProgramError> {
+    current_timestamp
+        .checked_sub(previous_timestamp)
+        .and_then(|elapsed| u64::try_from(elapsed).ok())
+        .ok_or(TestudoBondsError::InvalidTimestamp.into())
+}
+
+/// `basis_points` of `amount`, rounded down.
+pub fn calculate_basis_points(amount: u64, basis_points: u16) -> Result<u64, ProgramError> {
+    let share = (amount as u128)
+        .checked_mul(basis_points as u128)
+        .map(|product| product / 10_000)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    u64::try_from(share).map_err(|_| TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Split a deposit of `base_amount` into the shares of the split `recipients`, in order.
+pub fn calculate_token_deposit_split(
+    base_amount: u64,
+    recipients: &[SplitRecipient],
+) -> Result<Vec<u64>, ProgramError> {
+    recipients
+        .iter()
+        .map(|recipient| calculate_basis_points(base_amount, recipient.bps))
+        .collect()
+}
+
+/// Share of the bond principal refunded on early redemption. The unearned principal shrinks
+/// linearly with the emission headroom left on the bond, and `redemption_rate` (basis points) of
+/// it is paid back.
+pub fn calculate_redemption_amount(
+    principal: u64,
+    redemption_rate: u16,
+    emission_cap: u64,
+    total_claimed: u64,
+) -> Result<u64, ProgramError> {
+    if emission_cap == 0 {
+        return Ok(0);
+    }
+    let headroom = emission_cap.saturating_sub(total_claimed) as u128;
+
+    let unearned_principal = (principal as u128)
+        .checked_mul(headroom)
+        .map(|product| product / emission_cap as u128)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    let refund = unearned_principal
+        .checked_mul(redemption_rate as u128)
+        .map(|product| product / 10_000)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    u64::try_from(refund).map_err(|_| TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Amounts of a redemption `refund` drawn from the rewards pool and from the treasury share held in
+/// the principal vault, for a bond whose `deposit` was split among `recipients`. The refund is
+/// drawn from both in proportion to their shares of the deposit and is capped at their sum, as the
+/// other shares were paid out for good.
+pub fn calculate_redemption_sources(
+    refund: u64,
+    deposit: u64,
+    recipients: &[SplitRecipient],
+) -> Result<(u64, u64), ProgramError> {
+    let shares = calculate_token_deposit_split(deposit, recipients)?;
+    let mut pool_share: u64 = 0;
+    let mut treasury_share: u64 = 0;
+    for (recipient, share) in recipients.iter().zip(shares) {
+        let total = match recipient.destination {
+            SplitDestination::RewardsPool => &mut pool_share,
+            SplitDestination::Treasury => &mut treasury_share,
+            _ => continue,
+        };
+        *total = total.checked_add(share).ok_or(TestudoBondsError::NumericalOverflow)?;
+    }
+
+    let recoverable = pool_share
+        .checked_add(treasury_share)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    let refund = refund.min(recoverable);
+    if refund == 0 {
+        return Ok((0, 0));
+    }
+    let from_treasury = (refund as u128)
+        .checked_mul(treasury_share as u128)
+        .map(|product| product / recoverable as u128)
+        .ok_or(TestudoBondsError::NumericalOverflow)? as u64;
+    let from_pool = refund
+        .checked_sub(from_treasury)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    Ok((from_pool, from_treasury))
+}
+
+/// Part of a tranche of `amount` starting at `start_timestamp` vested under `schedule` at
+/// `current_timestamp`: nothing before the cliff, then a linear share of the vesting duration
+/// elapsed since the start, and everything once the duration has passed. No tranche starts before
+/// the schedule's start.
+pub fn calculate_vested_amount(
+    amount: u64,
+    start_timestamp: i64,
+    schedule: &VestingSchedule,
+    current_timestamp: i64,
+) -> Result<u64, ProgramError> {
+    let start_timestamp = start_timestamp.max(schedule.start_timestamp);
+    let elapsed = current_timestamp.saturating_sub(start_timestamp);
+    if elapsed < schedule.cliff_seconds as i64 {
+        return Ok(0);
+    }
+    if elapsed >= schedule.duration_seconds as i64 {
+        return Ok(amount);
+    }
+    let vested = (amount as u128)
+        .checked_mul(elapsed as u128)
+        .map(|product| product / schedule.duration_seconds as u128)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    u64::try_from(vested).map_err(|_| TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Part of `total_deposited` vested at `current_timestamp`. Each tranche vests from its own start,
+/// `queued` deposits waiting for a tranche have not vested, and deposits no longer tracked by a
+/// tranche have fully vested.
+pub fn calculate_team_vested_amount(
+    total_deposited: u64,
+    tranches: &[VestingTranche],
+    queued: u64,
+    schedule: &VestingSchedule,
+    current_timestamp: i64,
+) -> Result<u64, ProgramError> {
+    let mut unvested = queued;
+    for tranche in tranches {
+        let vested = calculate_vested_amount(
+            tranche.amount,
+            tranche.start_timestamp,
+            schedule,
+            current_timestamp,
+        )?;
+        let tranche_unvested = tranche
+            .amount
+            .checked_sub(vested)
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
+        unvested = unvested
+            .checked_add(tranche_unvested)
+            .ok_or(TestudoBondsError::NumericalOverflow)?;
+    }
+
+    total_deposited
+        .checked_sub(unvested)
+        .ok_or(TestudoBondsError::NumericalUnderflow.into())
+}
+
+/// Stream tokens a bond of `weight` earned between `from_timestamp` and `current_timestamp`,
+/// counting only the part of the interval within the stream's start and end.
+pub fn calculate_stream_reward(
+    stream: &RewardStream,
+    weight: u32,
+    from_timestamp: i64,
+    current_timestamp: i64,
+) -> Result<u64, ProgramError> {
+    let start = from_timestamp.max(stream.start_timestamp);
+    let end = current_timestamp.min(stream.end_timestamp);
+    if end <= start {
+        return Ok(0);
     }
+    let emitted = (stream.rate as u128)
+        .checked_mul((end - start) as u128)
+        .and_then(|product| u64::try_from(product).ok())
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
 
-    Ok(reward_with_penalty)
+    calculate_weighted_amount(emitted, weight)
 }
 
-pub fn calculate_token_deposit_split(token_deposit_split: [u16; 3]) -> [u64; 3] {
-    let base_amount = 10 * SHELLS_PER_TESTUDO;
-    [
-        (base_amount * token_deposit_split[0] as u64) / 10_000,
-        (base_amount * token_deposit_split[1] as u64) / 10_000,
-        (base_amount * token_deposit_split[2] as u64) / 10_000,
-    ]
+/// Protocol fee, in basis points of the sale price, taken on marketplace bond sales.
+pub fn calculate_marketplace_fee(price: u64, marketplace_fee: u16) -> Result<u64, ProgramError> {
+    calculate_basis_points(price, marketplace_fee)
 }
 
+/// Apply the first tier of the penalty schedule whose threshold has not been reached yet.
 pub fn calculate_claim_penalty(
     previous_claim_timestamp: &i64,
     current_timestamp: &i64,
-    claim_penalty: &u16,
+    penalty_schedule: &[PenaltyTier],
     reward: &u64,
 ) -> Result<u64, ProgramError> {
-    let seconds_elapsed = (current_timestamp - previous_claim_timestamp) as u64;
-    let seconds_per_day = 86400u64;
-    if seconds_elapsed < seconds_per_day * 5 {
-        reward
-            .checked_sub(reward.checked_mul(*claim_penalty as u64).unwrap() / 10_000)
-            .ok_or(TestudoBondsError::NumericalOverflow.into())
-    } else {
-        Ok(*reward)
+    let seconds_elapsed = calculate_elapsed_seconds(*previous_claim_timestamp, *current_timestamp)?;
+    let penalty_tier = penalty_schedule.iter().find(|tier| {
+        tier.max_elapsed_seconds > 0 && seconds_elapsed < tier.max_elapsed_seconds as u64
+    });
+    match penalty_tier {
+        Some(tier) => reward
+            .checked_sub(calculate_basis_points(*reward, tier.penalty_bps)?)
+            .ok_or(TestudoBondsError::NumericalUnderflow.into()),
+        None => Ok(*reward),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{MAX_LOCK_BOOST, MAX_LOYALTY_BOOST};
+    use crate::state::{SplitConfig, SplitDestination};
+    use crate::utils::fixed_point_utils::{calculate_decay_factor, DECAY_FACTOR_PRECISION};
+    use proptest::prelude::*;
+    use solana_program::pubkey::Pubkey;
+
+    const DAY: i64 = 86_400;
+
+    fn default_schedule() -> [PenaltyTier; 4] {
+        let mut schedule = [PenaltyTier::default(); 4];
+        schedule[0] = PenaltyTier {
+            max_elapsed_seconds: 5 * DAY as u32,
+            penalty_bps: 500,
+        };
+        schedule
+    }
+
+    proptest! {
+        #[test]
+        fn reward_never_exceeds_headroom(
+            elapsed in 0..100 * 365 * DAY,
+            daily_emission in 0..u64::MAX / 1_000,
+            weight in 1..1_000_000u32,
+            max_emission in any::<u64>(),
+            total_claimed in any::<u64>(),
+        ) {
+            let claim = calculate_reward(
+                &0,
+                &elapsed,
+                &0,
+                daily_emission,
+                &EmissionCurve::default(),
+                &[LoyaltyTier::default(); 4],
+                0,
+                weight,
+                0,
+                &default_schedule(),
+                max_emission,
+                total_claimed,
+            );
+            if let Ok(claim) = claim {
+                let headroom = max_emission.saturating_sub(total_claimed);
+                prop_assert!(claim.reward <= headroom);
+                prop_assert!(claim.reward + claim.penalty <= headroom);
+            }
+        }
+
+        #[test]
+        fn cap_below_total_claimed_pays_nothing(
+            elapsed in 1..10 * 365 * DAY,
+            max_emission in 0..u64::MAX / 2,
+            excess in 0..u64::MAX / 2,
+        ) {
+            let claim = calculate_reward(
+                &0,
+                &elapsed,
+                &0,
+                55_000_000,
+                &EmissionCurve::default(),
+                &[LoyaltyTier::default(); 4],
+                0,
+                STANDARD_BOND_WEIGHT,
+                0,
+                &default_schedule(),
+                max_emission,
+                max_emission + excess,
+            )
+            .unwrap();
+            prop_assert_eq!(claim.reward, 0);
+            prop_assert_eq!(claim.penalty, 0);
+        }
+
+        #[test]
+        fn clock_going_backwards_is_an_error(
+            last_claim in any::<i64>(),
+            offset in 1..i64::MAX,
+        ) {
+            let current = last_claim.saturating_sub(offset);
+            prop_assume!(current < last_claim);
+            let claim = calculate_reward(
+                &last_claim,
+                &current,
+                &0,
+                55_000_000,
+                &EmissionCurve::default(),
+                &[LoyaltyTier::default(); 4],
+                0,
+                STANDARD_BOND_WEIGHT,
+                0,
+                &default_schedule(),
+                20_000_000_000,
+                0,
+            );
+            prop_assert_eq!(claim, Err(TestudoBondsError::InvalidTimestamp.into()));
+        }
+
+        #[test]
+        fn claim_penalty_never_exceeds_reward(
+            reward in any::<u64>(),
+            penalty_bps in 0..=10_000u16,
+            elapsed in 0..DAY,
+        ) {
+            let mut schedule = [PenaltyTier::default(); 4];
+            schedule[0] = PenaltyTier {
+                max_elapsed_seconds: DAY as u32,
+                penalty_bps,
+            };
+            let reward_with_penalty =
+                calculate_claim_penalty(&0, &elapsed, &schedule, &reward).unwrap();
+            prop_assert!(reward_with_penalty <= reward);
+        }
+
+        #[test]
+        fn frequent_claims_accrue_the_same_as_one_claim(
+            intervals in prop::collection::vec(1..10 * DAY, 1..50),
+            weight in 1..100_000u32,
+        ) {
+            let mut total = 0;
+            let mut remainder = 0;
+            let mut timestamp = 0;
+            for interval in &intervals {
+                let claim = calculate_reward(
+                    &timestamp,
+                    &(timestamp + interval),
+                    &0,
+                    55_000_000,
+                    &EmissionCurve::default(),
+                    &[LoyaltyTier::default(); 4],
+                    0,
+                    weight,
+                    remainder,
+                    &[PenaltyTier::default(); 4],
+                    u64::MAX,
+                    total,
+                )
+                .unwrap();
+                total += claim.reward;
+                remainder = claim.remainder;
+                timestamp += interval;
+            }
+
+            let single = calculate_reward(
+                &0,
+                &timestamp,
+                &0,
+                55_000_000,
+                &EmissionCurve::default(),
+                &[LoyaltyTier::default(); 4],
+                0,
+                weight,
+                0,
+                &[PenaltyTier::default(); 4],
+                u64::MAX,
+                0,
+            )
+            .unwrap();
+            prop_assert_eq!(total, single.reward);
+            prop_assert_eq!(remainder, single.remainder);
+        }
+
+        #[test]
+        fn halving_curve_pays_each_period_at_its_rate(
+            daily_emission in (0..u64::MAX / 1_000_000).prop_map(|emission| emission * 4),
+            start in -DAY..DAY,
+        ) {
+            let curve = EmissionCurve {
+                start_timestamp: start,
+                period_seconds: DAY as u32,
+                decay_bps: 5_000,
+            };
+            let claim = calculate_reward(
+                &(start - DAY),
+                &(start + 3 * DAY),
+                &0,
+                daily_emission,
+                &curve,
+                &[LoyaltyTier::default(); 4],
+                0,
+                STANDARD_BOND_WEIGHT,
+                0,
+                &[PenaltyTier::default(); 4],
+                u64::MAX,
+                0,
+            )
+            .unwrap();
+            // The day before the start and the first period both run at
+            // the full rate
+            prop_assert_eq!(
+                claim.reward,
+                2 * daily_emission + daily_emission / 2 + daily_emission / 4
+            );
+            prop_assert_eq!(claim.remainder, 0);
+        }
+
+        #[test]
+        fn decaying_curve_matches_the_sum_of_its_periods(
+            rate in 0..u64::MAX / 1_000_000,
+            period in DAY as u32..30 * DAY as u32,
+            decay_bps in 1..=10_000u16,
+            periods in 0..500i64,
+        ) {
+            let curve = EmissionCurve {
+                start_timestamp: 0,
+                period_seconds: period,
+                decay_bps,
+            };
+            let emission =
+                integrate_emission(rate, &curve, 0, periods * period as i64).unwrap();
+            let summed: u128 = (0..periods as u64)
+                .map(|epoch| {
+                    rate as u128 * calculate_decay_factor(decay_bps, epoch)
+                        / DECAY_FACTOR_PRECISION
+                        * period as u128
+                })
+                .sum();
+            // Each period of the sum rounds its rate down
+            let tolerance = (periods as u128 + 1) * period as u128;
+            prop_assert!(emission.abs_diff(summed) <= tolerance);
+        }
+
+        #[test]
+        fn frequent_claims_accrue_the_same_under_decay_and_loyalty(
+            intervals in prop::collection::vec(1..10 * DAY, 1..50),
+            weight in 1..100_000u32,
+            period in DAY as u32..30 * DAY as u32,
+            decay_bps in 0..=10_000u16,
+            creation in -30 * DAY..30 * DAY,
+            boost_bps in 0..=MAX_LOYALTY_BOOST,
+        ) {
+            let curve = EmissionCurve {
+                start_timestamp: 5 * DAY,
+                period_seconds: period,
+                decay_bps,
+            };
+            let mut loyalty_schedule = [LoyaltyTier::default(); 4];
+            loyalty_schedule[0] = LoyaltyTier {
+                min_age_seconds: 30 * DAY as u32,
+                boost_bps: boost_bps / 2,
+            };
+            loyalty_schedule[1] = LoyaltyTier {
+                min_age_seconds: 90 * DAY as u32,
+                boost_bps,
+            };
+            let mut total = 0;
+            let mut remainder = 0;
+            let mut timestamp = 0;
+            for interval in &intervals {
+                let claim = calculate_reward(
+                    &timestamp,
+                    &(timestamp + interval),
+                    &creation,
+                    55_000_000,
+                    &curve,
+                    &loyalty_schedule,
+                    0,
+                    weight,
+                    remainder,
+                    &[PenaltyTier::default(); 4],
+                    u64::MAX,
+                    total,
+                )
+                .unwrap();
+                total += claim.reward;
+                remainder = claim.remainder;
+                timestamp += interval;
+            }
+
+            let single = calculate_reward(
+                &0,
+                &timestamp,
+                &creation,
+                55_000_000,
+                &curve,
+                &loyalty_schedule,
+                0,
+                weight,
+                0,
+                &[PenaltyTier::default(); 4],
+                u64::MAX,
+                0,
+            )
+            .unwrap();
+            prop_assert_eq!(total, single.reward);
+            prop_assert_eq!(remainder, single.remainder);
+        }
+
+        #[test]
+        fn loyalty_boost_applies_from_the_tier_age(
+            daily_emission in 0..u64::MAX / 1_000_000_000,
+            boost_bps in 0..=MAX_LOYALTY_BOOST,
+        ) {
+            let mut loyalty_schedule = [LoyaltyTier::default(); 4];
+            loyalty_schedule[0] = LoyaltyTier {
+                min_age_seconds: 30 * DAY as u32,
+                boost_bps,
+            };
+            let claim = calculate_reward(
+                &(10 * DAY),
+                &(60 * DAY),
+                &0,
+                daily_emission,
+                &EmissionCurve::default(),
+                &loyalty_schedule,
+                0,
+                STANDARD_BOND_WEIGHT,
+                0,
+                &[PenaltyTier::default(); 4],
+                u64::MAX,
+                0,
+            )
+            .unwrap();
+            let boosted = 30 * daily_emission as u128 * (10_000 + boost_bps as u128) / 10_000;
+            prop_assert_eq!(claim.reward as u128, 20 * daily_emission as u128 + boosted);
+        }
+
+        #[test]
+        fn merged_creation_timestamp_is_between_both_bonds(
+            target_timestamp in 0..i64::MAX / 2,
+            target_weight in 1..u32::MAX,
+            source_timestamp in 0..i64::MAX / 2,
+            source_weight in 1..u32::MAX,
+        ) {
+            let merged = calculate_merged_creation_timestamp(
+                target_timestamp,
+                target_weight,
+                source_timestamp,
+                source_weight,
+            )
+            .unwrap();
+            prop_assert!(merged >= target_timestamp.min(source_timestamp));
+            prop_assert!(merged <= target_timestamp.max(source_timestamp));
+            let same = calculate_merged_creation_timestamp(
+                target_timestamp,
+                target_weight,
+                target_timestamp,
+                source_weight,
+            )
+            .unwrap();
+            prop_assert_eq!(same, target_timestamp);
+        }
+
+        #[test]
+        fn lock_boost_stacks_with_the_loyalty_boost(
+            daily_emission in 0..u64::MAX / 1_000_000_000,
+            loyalty_boost_bps in 0..=MAX_LOYALTY_BOOST,
+            lock_boost_bps in 0..=MAX_LOCK_BOOST,
+        ) {
+            let mut loyalty_schedule = [LoyaltyTier::default(); 4];
+            loyalty_schedule[0] = LoyaltyTier {
+                min_age_seconds: 30 * DAY as u32,
+                boost_bps: loyalty_boost_bps,
+            };
+            let claim = calculate_reward(
+                &0,
+                &(60 * DAY),
+                &0,
+                daily_emission,
+                &EmissionCurve::default(),
+                &loyalty_schedule,
+                lock_boost_bps,
+                STANDARD_BOND_WEIGHT,
+                0,
+                &[PenaltyTier::default(); 4],
+                u64::MAX,
+                0,
+            )
+            .unwrap();
+            let locked = 30 * daily_emission as u128 * (10_000 + lock_boost_bps as u128);
+            let loyal = 30
+                * daily_emission as u128
+                * (10_000 + loyalty_boost_bps as u128 + lock_boost_bps as u128);
+            prop_assert_eq!(claim.reward as u128, (locked + loyal) / 10_000);
+        }
+
+        #[test]
+        fn deposit_split_never_exceeds_amount(
+            amount in any::<u64>(),
+            mut cuts in prop::collection::vec(0..=10_000u16, 0..SplitConfig::MAX_RECIPIENTS),
+        ) {
+            // Cutting 0..10000 at random points gives shares adding up to 10000
+            cuts.push(0);
+            cuts.push(10_000);
+            cuts.sort_unstable();
+            let recipients: Vec<SplitRecipient> = cuts
+                .windows(2)
+                .map(|cut| SplitRecipient {
+                    destination: SplitDestination::Account,
+                    token_account: Pubkey::new_unique(),
+                    bps: cut[1] - cut[0],
+                })
+                .collect();
+            let split = calculate_token_deposit_split(amount, &recipients).unwrap();
+            prop_assert_eq!(split.len(), recipients.len());
+            prop_assert!(split.iter().map(|share| *share as u128).sum::<u128>() <= amount as u128);
+        }
+
+        #[test]
+        fn redemption_draws_only_on_recoverable_shares(
+            refund in any::<u64>(),
+            deposit in any::<u64>(),
+            mut cuts in prop::collection::vec(0..=10_000u16, 0..SplitConfig::MAX_RECIPIENTS),
+            destinations in prop::collection::vec(0..5u8, SplitConfig::MAX_RECIPIENTS + 1),
+        ) {
+            cuts.push(0);
+            cuts.push(10_000);
+            cuts.sort_unstable();
+            let recipients: Vec<SplitRecipient> = cuts
+                .windows(2)
+                .zip(&destinations)
+                .map(|(cut, destination)| SplitRecipient {
+                    destination: match destination {
+                        0 => SplitDestination::RewardsPool,
+                        1 => SplitDestination::Treasury,
+                        2 => SplitDestination::Team,
+                        3 => SplitDestination::Burn,
+                        _ => SplitDestination::Account,
+                    },
+                    token_account: Pubkey::new_unique(),
+                    bps: cut[1] - cut[0],
+                })
+                .collect();
+            let shares = calculate_token_deposit_split(deposit, &recipients).unwrap();
+            let share_of = |destination: SplitDestination| -> u64 {
+                recipients
+                    .iter()
+                    .zip(&shares)
+                    .filter(|(recipient, _)| recipient.destination == destination)
+                    .map(|(_, share)| *share)
+                    .sum()
+            };
+
+            let (from_pool, from_treasury) =
+                calculate_redemption_sources(refund, deposit, &recipients).unwrap();
+            prop_assert!(from_pool <= share_of(SplitDestination::RewardsPool));
+            prop_assert!(from_treasury <= share_of(SplitDestination::Treasury));
+            prop_assert_eq!(
+                from_pool + from_treasury,
+                refund.min(
+                    share_of(SplitDestination::RewardsPool)
+                        + share_of(SplitDestination::Treasury)
+                )
+            );
+        }
+
+        #[test]
+        fn vesting_is_monotonic_and_bounded(
+            total in any::<u64>(),
+            cliff in 0..365 * DAY as u32,
+            extra in 1..4 * 365 * DAY as u32,
+            earlier in -DAY..5 * 365 * DAY,
+            later_by in 0..365 * DAY,
+        ) {
+            let schedule = VestingSchedule {
+                start_timestamp: 0,
+                cliff_seconds: cliff,
+                duration_seconds: cliff + extra,
+            };
+            let before = calculate_vested_amount(total, 0, &schedule, earlier).unwrap();
+            let after = calculate_vested_amount(total, 0, &schedule, earlier + later_by).unwrap();
+            prop_assert!(before <= after);
+            prop_assert!(after <= total);
+            if earlier < cliff as i64 {
+                prop_assert_eq!(before, 0);
+            }
+            if earlier >= (cliff + extra) as i64 {
+                prop_assert_eq!(before, total);
+            }
+        }
+
+        #[test]
+        fn late_tranche_vests_from_its_own_start(
+            first in 0..u32::MAX as u64,
+            second in 0..u32::MAX as u64,
+            queued in 0..u32::MAX as u64,
+            cliff in 0..365 * DAY as u32,
+            extra in 1..4 * 365 * DAY as u32,
+            deposited_after in 0..5 * 365 * DAY,
+            elapsed in 0..5 * 365 * DAY,
+        ) {
+            let schedule = VestingSchedule {
+                start_timestamp: 0,
+                cliff_seconds: cliff,
+                duration_seconds: cliff + extra,
+            };
+            let tranches = [
+                VestingTranche { start_timestamp: 0, amount: first },
+                VestingTranche { start_timestamp: deposited_after, amount: second },
+            ];
+            let now = deposited_after + elapsed;
+            let vested = calculate_team_vested_amount(
+                first + second + queued,
+                &tranches,
+                queued,
+                &schedule,
+                now,
+            )
+            .unwrap();
+            let first_vested = calculate_vested_amount(first, 0, &schedule, now).unwrap();
+            prop_assert!(vested >= first_vested);
+            prop_assert!(vested <= first + second);
+            if elapsed < cliff as i64 {
+                prop_assert_eq!(vested, first_vested);
+            }
+            if elapsed >= (cliff + extra) as i64 {
+                prop_assert_eq!(vested, first + second);
+            }
+        }
+
+        #[test]
+        fn stream_reward_is_additive_and_bounded(
+            rate in 0..1_000_000_000u64,
+            start in 0..365 * DAY,
+            duration in 1..365 * DAY,
+            from in -DAY..2 * 365 * DAY,
+            first in 0..365 * DAY,
+            second in 0..365 * DAY,
+        ) {
+            let stream = RewardStream {
+                mint: Pubkey::new_unique(),
+                pool: Pubkey::new_unique(),
+                decimals: 6,
+                rate,
+                start_timestamp: start,
+                end_timestamp: start + duration,
+            };
+            let split = from + first;
+            let to = split + second;
+            let whole = calculate_stream_reward(&stream, STANDARD_BOND_WEIGHT, from, to).unwrap();
+            let parts = calculate_stream_reward(&stream, STANDARD_BOND_WEIGHT, from, split).unwrap()
+                + calculate_stream_reward(&stream, STANDARD_BOND_WEIGHT, split, to).unwrap();
+            prop_assert_eq!(whole, parts);
+            prop_assert!(whole <= rate * duration as u64);
+        }
     }
 }
@@ -0,0 +1,183 @@
+use solana_program::program_error::ProgramError;
+
+use crate::{
+    constants::STANDARD_BOND_WEIGHT, error::TestudoBondsError,
+    state::EmissionCurve,
+};
+
+/// Denominator of accrued emission: the daily emission is spread over
+/// the seconds of a day, scaled by the bond weight and by a
+/// multiplier in basis points, so `daily_emission * seconds *
+/// multiplier_bps * weight` is exact over this value.
+pub const ACCRUAL_DENOMINATOR: u128 =
+    86_400 * STANDARD_BOND_WEIGHT as u128 * 10_000;
+
+/// Precision of the emission decay factor, where
+/// `DECAY_FACTOR_PRECISION` is no decay.
+pub const DECAY_FACTOR_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+/// Emission accrued on a bond, split into whole shells and the
+/// fraction of a shell (as a numerator over `ACCRUAL_DENOMINATOR`)
+/// carried to the next claim.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Accrual {
+    pub amount: u64,
+    pub remainder: u64,
+}
+
+/// Accrue `emission`, the daily emission per standard bond integrated
+/// over the claim interval in seconds (see `integrate_emission`) and
+/// scaled by the emission multiplier in basis points, on a bond of
+/// `weight`, starting from the `remainder` left by the previous
+/// claim. No emission is lost to truncation: whatever does not add up
+/// to a whole shell is returned as the new remainder.
+pub fn accrue_emission(
+    emission: u128,
+    weight: u32,
+    remainder: u64,
+) -> Result<Accrual, ProgramError> {
+    let numerator = emission
+        .checked_mul(weight as u128)
+        .and_then(|product| product.checked_add(remainder as u128))
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+
+    let amount = u64::try_from(numerator / ACCRUAL_DENOMINATOR)
+        .map_err(|_| TestudoBondsError::NumericalOverflow)?;
+
+    Ok(Accrual {
+        amount,
+        remainder: (numerator % ACCRUAL_DENOMINATOR) as u64,
+    })
+}
+
+/// Integral of `rate` decayed by `curve` from `from_timestamp` to
+/// `to_timestamp`, in rate units times seconds. Timestamps before the
+/// start of the curve fall in the first period, at the full rate. The
+/// integral is the difference of the cumulative emission of the curve
+/// at both ends, computed in closed form, so its cost does not depend
+/// on the number of periods in the interval and splitting an interval
+/// never changes the total.
+pub fn integrate_emission(
+    rate: u64,
+    curve: &EmissionCurve,
+    from_timestamp: i64,
+    to_timestamp: i64,
+) -> Result<u128, ProgramError> {
+    if to_timestamp < from_timestamp {
+        return Err(TestudoBondsError::InvalidTimestamp.into());
+    }
+    if curve.decay_bps == 0 || curve.period_seconds == 0 {
+        return (rate as u128)
+            .checked_mul(to_timestamp.abs_diff(from_timestamp) as u128)
+            .ok_or(TestudoBondsError::NumericalOverflow.into());
+    }
+
+    // Before the start of the curve the rate is constant
+    let before_start = (rate as u128)
+        .checked_mul(
+            to_timestamp
+                .min(curve.start_timestamp)
+                .saturating_sub(from_timestamp)
+                .max(0) as u128,
+        )
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    if to_timestamp <= curve.start_timestamp {
+        return Ok(before_start);
+    }
+
+    let from_emission = cumulative_emission(
+        rate,
+        curve,
+        from_timestamp.max(curve.start_timestamp),
+    )?;
+    let to_emission = cumulative_emission(rate, curve, to_timestamp)?;
+    to_emission
+        .checked_sub(from_emission)
+        .and_then(|emission| emission.checked_add(before_start))
+        .ok_or(TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Emission of `rate` decayed by `curve` from the start of the curve
+/// to `timestamp`, which must not be before it, in rate units times
+/// seconds. Whole periods add up to a geometric series (see
+/// `geometric_emission`), and the current period accrues its share of
+/// the series at its end linearly.
+fn cumulative_emission(
+    rate: u64,
+    curve: &EmissionCurve,
+    timestamp: i64,
+) -> Result<u128, ProgramError> {
+    let period = curve.period_seconds as i64;
+    let elapsed = timestamp
+        .checked_sub(curve.start_timestamp)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    let epoch = elapsed / period;
+    let period_elapsed = (elapsed % period) as u128;
+
+    let epoch_start = geometric_emission(rate, curve, epoch as u64)?;
+    if period_elapsed == 0 {
+        return Ok(epoch_start);
+    }
+    let epoch_end =
+        geometric_emission(rate, curve, epoch as u64 + 1)?
+            .max(epoch_start);
+    (epoch_end - epoch_start)
+        .checked_mul(period_elapsed)
+        .map(|product| product / period as u128)
+        .and_then(|partial| epoch_start.checked_add(partial))
+        .ok_or(TestudoBondsError::NumericalOverflow.into())
+}
+
+/// Emission of `rate` decayed by `curve` over its first `epochs`
+/// whole periods, rounded down. With `q = 1 - decay_bps / 10000`, the
+/// series `rate * period * (1 + q + ... + q ^ (epochs - 1))` sums to
+/// `rate * period * (1 - q ^ epochs) * 10000 / decay_bps`. The
+/// product is split around `DECAY_FACTOR_PRECISION` so it fits in 128
+/// bits.
+fn geometric_emission(
+    rate: u64,
+    curve: &EmissionCurve,
+    epochs: u64,
+) -> Result<u128, ProgramError> {
+    let decayed = DECAY_FACTOR_PRECISION
+        - calculate_decay_factor(curve.decay_bps, epochs);
+    let decay_bps = curve.decay_bps.min(10_000) as u128;
+    let scale = 10_000 * curve.period_seconds as u128;
+
+    // rate * decayed = whole * DECAY_FACTOR_PRECISION + fraction
+    let product = (rate as u128)
+        .checked_mul(decayed)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    let whole = (product / DECAY_FACTOR_PRECISION)
+        .checked_mul(scale)
+        .ok_or(TestudoBondsError::NumericalOverflow)?;
+    let fraction = (product % DECAY_FACTOR_PRECISION) * scale;
+
+    (whole % decay_bps * DECAY_FACTOR_PRECISION)
+        .checked_add(fraction)
+        .map(|remainder| {
+            remainder / (decay_bps * DECAY_FACTOR_PRECISION)
+        })
+        .and_then(|remainder| {
+            (whole / decay_bps).checked_add(remainder)
+        })
+        .ok_or(TestudoBondsError::NumericalOverflow.into())
+}
+
+/// `(1 - decay_bps / 10000) ^ epoch`, scaled by
+/// `DECAY_FACTOR_PRECISION` and rounded down at every step of the
+/// exponentiation.
+pub fn calculate_decay_factor(decay_bps: u16, epoch: u64) -> u128 {
+    let mut base = (10_000 - decay_bps.min(10_000) as u128)
+        * (DECAY_FACTOR_PRECISION / 10_000);
+    let mut factor = DECAY_FACTOR_PRECISION;
+    let mut exponent = epoch;
+    while exponent > 0 && factor > 0 {
+        if exponent & 1 == 1 {
+            factor = factor * base / DECAY_FACTOR_PRECISION;
+        }
+        base = base * base / DECAY_FACTOR_PRECISION;
+        exponent >>= 1;
+    }
+    factor
+}
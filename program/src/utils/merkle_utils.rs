@@ -0,0 +1,114 @@
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+/// Prefix of hashed leaves, so an inner node can never be passed off
+/// as a leaf.
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Prefix of hashed inner nodes.
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf of the allowlist tree for `wallet` with an `allocation` of
+/// bonds, 0 for no limit.
+pub fn allowlist_leaf(wallet: &Pubkey, allocation: u16) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, wallet.as_ref(), &allocation.to_le_bytes()])
+        .to_bytes()
+}
+
+/// Whether `proof`, the sibling hashes from `leaf` up to the root,
+/// proves `leaf` is in the tree of `root`. Each pair of siblings is
+/// hashed in sorted order, so the proof needs no positions.
+pub fn verify_merkle_proof(
+    proof: &[[u8; 32]],
+    root: &[u8; 32],
+    leaf: [u8; 32],
+) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn hash_node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[NODE_PREFIX, &left, &right]).to_bytes()
+    }
+
+    /// Root of the tree over `leaves` and the proof of the leaf at
+    /// `index`. An odd node out is carried up unchanged.
+    fn root_and_proof(
+        leaves: &[[u8; 32]],
+        mut index: usize,
+    ) -> ([u8; 32], Vec<[u8; 32]>) {
+        let mut level = leaves.to_vec();
+        let mut proof = Vec::new();
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_node(*a, *b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            index /= 2;
+        }
+        (level[0], proof)
+    }
+
+    proptest! {
+        #[test]
+        fn proofs_verify_only_their_own_leaf(
+            allocations in prop::collection::vec(0..10u16, 1..20),
+            index in any::<prop::sample::Index>(),
+        ) {
+            let wallets: Vec<Pubkey> = allocations
+                .iter()
+                .map(|_| Pubkey::new_unique())
+                .collect();
+            let leaves: Vec<[u8; 32]> = wallets
+                .iter()
+                .zip(&allocations)
+                .map(|(wallet, allocation)| {
+                    allowlist_leaf(wallet, *allocation)
+                })
+                .collect();
+            let index = index.index(leaves.len());
+            let (root, proof) = root_and_proof(&leaves, index);
+
+            prop_assert!(verify_merkle_proof(
+                &proof,
+                &root,
+                leaves[index],
+            ));
+            prop_assert!(!verify_merkle_proof(
+                &proof,
+                &root,
+                allowlist_leaf(
+                    &wallets[index],
+                    allocations[index] + 1,
+                ),
+            ));
+            prop_assert!(!verify_merkle_proof(
+                &proof,
+                &root,
+                allowlist_leaf(
+                    &Pubkey::new_unique(),
+                    allocations[index],
+                ),
+            ));
+        }
+    }
+}
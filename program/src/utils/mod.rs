@@ -1,9 +1,24 @@
 pub mod account_utils;
+pub mod bond_page_utils;
+pub mod bond_utils;
 pub mod calculation_utils;
+pub mod fixed_point_utils;
+pub mod merkle_utils;
 pub mod token_utils;
 
 pub use account_utils::{
     close_account, create_account, realloc_account, transfer_lamports, transfer_lamports_from_pdas,
 };
-pub use calculation_utils::{calculate_reward, calculate_token_deposit_split};
-pub use token_utils::{create_ata, transfer_spl_tokens};
+pub use bond_page_utils::{load_bond_page, load_open_bond_page, move_bond, remove_bond_from_page};
+pub use bond_utils::load_bond;
+pub use calculation_utils::{
+    calculate_bond_penalty_share, calculate_bond_reward, calculate_marketplace_fee,
+    calculate_penalty_per_weight, calculate_redemption_amount, calculate_reward,
+    calculate_stream_reward, calculate_token_deposit_split, calculate_vested_amount,
+    calculate_weighted_amount, ClaimReward,
+};
+pub use fixed_point_utils::{accrue_emission, integrate_emission, Accrual, ACCRUAL_DENOMINATOR};
+pub use merkle_utils::{allowlist_leaf, verify_merkle_proof};
+pub use token_utils::{
+    burn_spl_tokens, create_ata, create_mint, mint_spl_tokens, transfer_spl_tokens,
+};
@@ -1,10 +1,16 @@
+use crate::utils::account_utils::create_account;
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
+    program_pack::Pack,
+    pubkey::Pubkey,
 };
 use spl_associated_token_account::instruction::create_associated_token_account;
-use spl_token::instruction::transfer_checked;
+use spl_token::instruction::{
+    burn_checked, initialize_mint2, mint_to_checked, transfer_checked,
+};
+use spl_token::state::Mint;
 
 pub fn create_ata<'a>(
     payer: &'a AccountInfo<'a>,
@@ -51,6 +57,7 @@ pub fn create_ata<'a>(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn transfer_spl_tokens<'a>(
     token_program: &'a AccountInfo<'a>,
     source_account: &'a AccountInfo<'a>,
@@ -86,3 +93,102 @@ pub fn transfer_spl_tokens<'a>(
         invoke(&transfer_ix, accounts)
     }
 }
+
+/// Create a mint account and initialize it without a freeze authority.
+pub fn create_mint<'a>(
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    mint_account: &'a AccountInfo<'a>,
+    mint_authority: &Pubkey,
+    decimals: u8,
+    signer_seeds: Option<&[&[&[u8]]]>,
+) -> ProgramResult {
+    create_account(
+        mint_account,
+        payer,
+        system_program,
+        Mint::LEN,
+        token_program.key,
+        signer_seeds,
+    )?;
+
+    let initialize_mint_ix = initialize_mint2(
+        token_program.key,
+        mint_account.key,
+        mint_authority,
+        None,
+        decimals,
+    )?;
+
+    invoke(
+        &initialize_mint_ix,
+        &[mint_account.clone(), token_program.clone()],
+    )
+}
+
+pub fn mint_spl_tokens<'a>(
+    token_program: &'a AccountInfo<'a>,
+    mint_account: &'a AccountInfo<'a>,
+    destination_account: &'a AccountInfo<'a>,
+    authority_account: &'a AccountInfo<'a>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: Option<&[&[&[u8]]]>,
+) -> ProgramResult {
+    let mint_ix = mint_to_checked(
+        token_program.key,
+        mint_account.key,
+        destination_account.key,
+        authority_account.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+
+    let accounts = &[
+        mint_account.clone(),
+        destination_account.clone(),
+        authority_account.clone(),
+        token_program.clone(),
+    ];
+
+    if let Some(seeds) = signer_seeds {
+        invoke_signed(&mint_ix, accounts, seeds)
+    } else {
+        invoke(&mint_ix, accounts)
+    }
+}
+
+pub fn burn_spl_tokens<'a>(
+    token_program: &'a AccountInfo<'a>,
+    source_account: &'a AccountInfo<'a>,
+    mint_account: &'a AccountInfo<'a>,
+    authority_account: &'a AccountInfo<'a>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: Option<&[&[&[u8]]]>,
+) -> ProgramResult {
+    let burn_ix = burn_checked(
+        token_program.key,
+        source_account.key,
+        mint_account.key,
+        authority_account.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+
+    let accounts = &[
+        source_account.clone(),
+        mint_account.clone(),
+        authority_account.clone(),
+        token_program.clone(),
+    ];
+
+    if let Some(seeds) = signer_seeds {
+        invoke_signed(&burn_ix, accounts, seeds)
+    } else {
+        invoke(&burn_ix, accounts)
+    }
+}
@@ -0,0 +1,684 @@
+#![allow(dead_code)]
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+};
+use solana_program_test::{
+    processor, BanksClientError, ProgramTest, ProgramTestContext,
+};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::{
+    get_associated_token_address,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token::state::{Account as TokenAccount, Mint};
+use testudo_bonds::{
+    error::TestudoBondsError,
+    instruction::{TestudoBondsInstruction, UpdateAdminPayload},
+    state::{
+        Admin, Bond, GlobalState, RewardStream, RewardStreamList,
+        Serialization, UserAccount, UserBondPage,
+    },
+};
+
+pub const SHELLS_PER_TESTUDO: u64 = 1_000_000_000;
+pub const DAY: i64 = 86_400;
+
+/// The program processor takes the account slice for as long as the
+/// accounts it holds, which the program test entrypoint does not.
+fn process_instruction<'a, 'b>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts = unsafe {
+        std::mem::transmute::<
+            &'a [AccountInfo<'b>],
+            &'b [AccountInfo<'b>],
+        >(accounts)
+    };
+    testudo_bonds::processor::process_instruction(
+        program_id,
+        accounts,
+        instruction_data,
+    )
+}
+
+pub fn global_admin() -> Pubkey {
+    find_pda(&[b"global_admin"])
+}
+
+pub fn global_state() -> Pubkey {
+    find_pda(&[b"global_state"])
+}
+
+pub fn split_config() -> Pubkey {
+    find_pda(&[b"split_config"])
+}
+
+pub fn principal_vault() -> Pubkey {
+    find_pda(&[b"principal_vault"])
+}
+
+pub fn team_vesting() -> Pubkey {
+    find_pda(&[b"team_vesting"])
+}
+
+pub fn reward_streams() -> Pubkey {
+    find_pda(&[b"reward_streams"])
+}
+
+pub fn reward_stream(stream_mint: &Pubkey) -> Pubkey {
+    find_pda(&[b"reward_stream", stream_mint.as_ref()])
+}
+
+pub fn stream_settlement(stream: &Pubkey, bond: &Pubkey) -> Pubkey {
+    find_pda(&[b"stream_settlement", stream.as_ref(), bond.as_ref()])
+}
+
+pub fn user_pda(wallet: &Pubkey) -> Pubkey {
+    find_pda(&[b"user", wallet.as_ref()])
+}
+
+pub fn bond_page(user_pda: &Pubkey, page_index: u16) -> Pubkey {
+    find_pda(&[
+        b"bond_page",
+        user_pda.as_ref(),
+        &page_index.to_le_bytes(),
+    ])
+}
+
+pub fn bond(user_pda: &Pubkey, bond_index: u16) -> Pubkey {
+    find_pda(&[b"bond", user_pda.as_ref(), &bond_index.to_le_bytes()])
+}
+
+pub fn bond_mint(bond: &Pubkey) -> Pubkey {
+    find_pda(&[b"bond_mint", bond.as_ref()])
+}
+
+pub fn bond_mint_authority() -> Pubkey {
+    find_pda(&[b"bond_mint_authority"])
+}
+
+pub fn listing(bond: &Pubkey) -> Pubkey {
+    find_pda(&[b"listing", bond.as_ref()])
+}
+
+pub fn referrer_stats(referrer_wallet: &Pubkey) -> Pubkey {
+    find_pda(&[b"referrer_stats", referrer_wallet.as_ref()])
+}
+
+fn find_pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &testudo_bonds::ID).0
+}
+
+pub fn ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(owner, mint)
+}
+
+/// Optional accounts that are not passed are set to the program ID.
+pub fn none() -> AccountMeta {
+    AccountMeta::new_readonly(testudo_bonds::ID, false)
+}
+
+pub fn instruction(
+    instruction: TestudoBondsInstruction,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    Instruction {
+        program_id: testudo_bonds::ID,
+        accounts,
+        data: borsh::to_vec(&instruction).unwrap(),
+    }
+}
+
+/// Assert a transaction failed with the given program error.
+pub fn assert_error(
+    result: Result<(), BanksClientError>,
+    error: TestudoBondsError,
+) {
+    match result {
+        Err(BanksClientError::TransactionError(
+            TransactionError::InstructionError(
+                _,
+                InstructionError::Custom(code),
+            ),
+        )) => assert_eq!(code, error as u32),
+        other => panic!("expected {:?}, got {:?}", error, other),
+    }
+}
+
+/// A started program test with the global admin initialized. The
+/// payer is the admin authority and the native token mint authority.
+pub struct TestContext {
+    pub context: ProgramTestContext,
+    pub native_token_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub team: Pubkey,
+}
+
+impl TestContext {
+    pub async fn new() -> Self {
+        let program_test = ProgramTest::new(
+            "testudo_bonds",
+            testudo_bonds::ID,
+            processor!(process_instruction),
+        );
+        let context = program_test.start_with_context().await;
+        let mut test_context = Self {
+            context,
+            native_token_mint: Pubkey::default(),
+            treasury: Keypair::new().pubkey(),
+            team: Keypair::new().pubkey(),
+        };
+        test_context.native_token_mint =
+            test_context.create_mint().await;
+
+        let authority = test_context.authority();
+        let mint = test_context.native_token_mint;
+        let initialize_admin = instruction(
+            TestudoBondsInstruction::InitializeAdmin,
+            vec![
+                AccountMeta::new(global_admin(), false),
+                AccountMeta::new(authority, true),
+                AccountMeta::new(ata(&global_admin(), &mint), false),
+                AccountMeta::new_readonly(
+                    test_context.treasury,
+                    false,
+                ),
+                AccountMeta::new(
+                    ata(&test_context.treasury, &mint),
+                    false,
+                ),
+                AccountMeta::new_readonly(test_context.team, false),
+                AccountMeta::new(
+                    ata(&test_context.team, &mint),
+                    false,
+                ),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(
+                    solana_system_interface::program::ID,
+                    false,
+                ),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new_readonly(
+                    spl_associated_token_account::ID,
+                    false,
+                ),
+                AccountMeta::new(global_state(), false),
+                AccountMeta::new_readonly(principal_vault(), false),
+                AccountMeta::new(
+                    ata(&principal_vault(), &mint),
+                    false,
+                ),
+                AccountMeta::new_readonly(team_vesting(), false),
+                AccountMeta::new(ata(&team_vesting(), &mint), false),
+                AccountMeta::new(split_config(), false),
+            ],
+        );
+        test_context
+            .process(&[initialize_admin], &[])
+            .await
+            .unwrap();
+
+        test_context
+    }
+
+    pub fn authority(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    pub fn rewards_pool_ata(&self) -> Pubkey {
+        ata(&global_admin(), &self.native_token_mint)
+    }
+
+    pub fn treasury_ata(&self) -> Pubkey {
+        ata(&self.treasury, &self.native_token_mint)
+    }
+
+    pub fn team_ata(&self) -> Pubkey {
+        ata(&self.team, &self.native_token_mint)
+    }
+
+    pub fn principal_vault_ata(&self) -> Pubkey {
+        ata(&principal_vault(), &self.native_token_mint)
+    }
+
+    pub fn team_vesting_ata(&self) -> Pubkey {
+        ata(&team_vesting(), &self.native_token_mint)
+    }
+
+    /// Process a transaction paid by the payer and signed by the
+    /// given signers.
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            self.context.banks_client.get_latest_blockhash().await?,
+        );
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    /// Move the clock forward, on a new slot so the same transaction
+    /// can be sent again.
+    pub async fn warp(&mut self, seconds: i64) {
+        let mut clock = self.clock().await;
+        self.context.warp_to_slot(clock.slot + 1).unwrap();
+        clock.slot += 1;
+        clock.unix_timestamp += seconds;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn data(
+        &mut self,
+        address: &Pubkey,
+    ) -> Option<Vec<u8>> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .map(|account| account.data)
+    }
+
+    pub async fn admin(&mut self) -> Admin {
+        let data = self.data(&global_admin()).await.unwrap();
+        Admin::deserialize_account_data(&data).unwrap()
+    }
+
+    pub async fn global_state(&mut self) -> GlobalState {
+        let data = self.data(&global_state()).await.unwrap();
+        GlobalState::deserialize_account_data(&data).unwrap()
+    }
+
+    pub async fn user(&mut self, wallet: &Pubkey) -> UserAccount {
+        let data = self.data(&user_pda(wallet)).await.unwrap();
+        UserAccount::deserialize_account_data(&data).unwrap()
+    }
+
+    pub async fn bond(&mut self, bond: &Pubkey) -> Option<Bond> {
+        let data = self.data(bond).await?;
+        Some(Bond::deserialize_account_data(&data).unwrap())
+    }
+
+    pub async fn bond_page(
+        &mut self,
+        bond_page: &Pubkey,
+    ) -> UserBondPage {
+        let data = self.data(bond_page).await.unwrap();
+        UserBondPage::deserialize_account_data(&data).unwrap()
+    }
+
+    pub async fn balance(&mut self, token_account: &Pubkey) -> u64 {
+        match self.data(token_account).await {
+            Some(data) => TokenAccount::unpack(&data).unwrap().amount,
+            None => 0,
+        }
+    }
+
+    /// Replace the admin data, starting from the current data.
+    pub async fn update_admin(
+        &mut self,
+        update: impl FnOnce(&mut Admin),
+    ) {
+        let mut admin = self.admin().await;
+        update(&mut admin);
+        let mut data = vec![4];
+        UpdateAdminPayload {
+            new_admin_data: admin,
+        }
+        .serialize(&mut data)
+        .unwrap();
+        let update_admin = Instruction {
+            program_id: testudo_bonds::ID,
+            accounts: vec![
+                AccountMeta::new(global_admin(), false),
+                AccountMeta::new_readonly(self.authority(), true),
+                AccountMeta::new(global_state(), false),
+            ],
+            data,
+        };
+        self.process(&[update_admin], &[]).await.unwrap();
+    }
+
+    /// Create a mint with 9 decimals whose authority is the payer.
+    pub async fn create_mint(&mut self) -> Pubkey {
+        let mint = Keypair::new();
+        let rent =
+            self.context.banks_client.get_rent().await.unwrap();
+        let instructions = [
+            solana_system_interface::instruction::create_account(
+                &self.authority(),
+                &mint.pubkey(),
+                rent.minimum_balance(Mint::LEN),
+                Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint2(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &self.authority(),
+                None,
+                9,
+            )
+            .unwrap(),
+        ];
+        self.process(&instructions, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    /// Create the associated token account of `owner` for `mint`, if
+    /// needed, and mint `amount` to it.
+    pub async fn mint_to(
+        &mut self,
+        owner: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> Pubkey {
+        let token_account = ata(owner, mint);
+        let mut instructions =
+            vec![create_associated_token_account_idempotent(
+                &self.authority(),
+                owner,
+                mint,
+                &spl_token::ID,
+            )];
+        if amount > 0 {
+            instructions.push(
+                spl_token::instruction::mint_to(
+                    &spl_token::ID,
+                    mint,
+                    &token_account,
+                    &self.authority(),
+                    &[],
+                    amount,
+                )
+                .unwrap(),
+            );
+        }
+        self.process(&instructions, &[]).await.unwrap();
+        token_account
+    }
+
+    /// Fund a new wallet, create its user PDA and give it 100 native
+    /// tokens.
+    pub async fn create_user(&mut self) -> Keypair {
+        let wallet = Keypair::new();
+        let fund = solana_system_interface::instruction::transfer(
+            &self.authority(),
+            &wallet.pubkey(),
+            Rent::default().minimum_balance(0) + 1_000_000_000,
+        );
+        let create_user = instruction(
+            TestudoBondsInstruction::CreateUser,
+            vec![
+                AccountMeta::new(user_pda(&wallet.pubkey()), false),
+                AccountMeta::new(wallet.pubkey(), true),
+                AccountMeta::new_readonly(
+                    solana_system_interface::program::ID,
+                    false,
+                ),
+            ],
+        );
+        self.process(&[fund, create_user], &[&wallet])
+            .await
+            .unwrap();
+        let mint = self.native_token_mint;
+        self.mint_to(
+            &wallet.pubkey(),
+            &mint,
+            100 * SHELLS_PER_TESTUDO,
+        )
+        .await;
+        wallet
+    }
+
+    /// The bond page new bonds of the user go on: the current page,
+    /// or the next one once it is full.
+    pub async fn open_bond_page(
+        &mut self,
+        wallet: &Pubkey,
+    ) -> Pubkey {
+        let user_pda = user_pda(wallet);
+        let page_count = self.user(wallet).await.page_count;
+        if let Some(current) = page_count.checked_sub(1) {
+            let page = bond_page(&user_pda, current);
+            if self.bond_page(&page).await.bonds.len()
+                < UserBondPage::MAX_BONDS
+            {
+                return page;
+            }
+        }
+        bond_page(&user_pda, page_count)
+    }
+
+    /// The bond page a bond is tracked on.
+    pub async fn page_of(&mut self, bond: &Pubkey) -> Pubkey {
+        let bond_data = self.bond(bond).await.unwrap();
+        bond_page(&bond_data.owner, bond_data.page_index)
+    }
+
+    /// The accounts InitializeBond takes, with no referrer and no
+    /// tokenization.
+    pub async fn initialize_bond_accounts(
+        &mut self,
+        wallet: &Pubkey,
+    ) -> Vec<AccountMeta> {
+        let user_pda = user_pda(wallet);
+        let bond_index = self.user(wallet).await.bond_index;
+        let mint = self.native_token_mint;
+        vec![
+            AccountMeta::new(bond(&user_pda, bond_index), false),
+            AccountMeta::new(*wallet, true),
+            AccountMeta::new(user_pda, false),
+            AccountMeta::new(
+                self.open_bond_page(wallet).await,
+                false,
+            ),
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new(ata(wallet, &mint), false),
+            AccountMeta::new(self.rewards_pool_ata(), false),
+            AccountMeta::new(self.principal_vault_ata(), false),
+            AccountMeta::new(self.team_vesting_ata(), false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(
+                solana_system_interface::program::ID,
+                false,
+            ),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new(global_state(), false),
+            AccountMeta::new_readonly(split_config(), false),
+            none(),
+            none(),
+            none(),
+            none(),
+            none(),
+            none(),
+            none(),
+        ]
+    }
+
+    /// Buy a standard perpetual bond, tokenized if requested.
+    pub async fn initialize_bond(
+        &mut self,
+        wallet: &Keypair,
+        tokenize: bool,
+    ) -> Pubkey {
+        let mut accounts =
+            self.initialize_bond_accounts(&wallet.pubkey()).await;
+        let bond = accounts[0].pubkey;
+        if tokenize {
+            let bond_mint = bond_mint(&bond);
+            accounts[14] = AccountMeta::new(bond_mint, false);
+            accounts[15] = AccountMeta::new_readonly(
+                bond_mint_authority(),
+                false,
+            );
+            accounts[16] = AccountMeta::new(
+                ata(&wallet.pubkey(), &bond_mint),
+                false,
+            );
+            accounts[17] = AccountMeta::new_readonly(
+                spl_associated_token_account::ID,
+                false,
+            );
+        }
+        let initialize_bond = instruction(
+            TestudoBondsInstruction::InitializeBond {
+                tokenize,
+                lock_tier: None,
+                fixed_term: false,
+                allowlist_proof: None,
+            },
+            accounts,
+        );
+        self.process(&[initialize_bond], &[wallet]).await.unwrap();
+        bond
+    }
+
+    /// The accounts of each attached reward stream that instructions
+    /// settling `bond` take, paying `wallet`.
+    pub async fn stream_accounts(
+        &mut self,
+        wallet: &Pubkey,
+        bond: &Pubkey,
+    ) -> Vec<AccountMeta> {
+        let Some(data) = self.data(&reward_streams()).await else {
+            return Vec::new();
+        };
+        let streams =
+            RewardStreamList::deserialize_account_data(&data)
+                .unwrap()
+                .streams;
+        let mut accounts = Vec::new();
+        for stream in streams {
+            let data = self.data(&stream).await.unwrap();
+            let stream_data =
+                RewardStream::deserialize_account_data(&data)
+                    .unwrap();
+            accounts.extend([
+                AccountMeta::new_readonly(stream, false),
+                AccountMeta::new(stream_data.pool, false),
+                AccountMeta::new_readonly(stream_data.mint, false),
+                AccountMeta::new(
+                    ata(wallet, &stream_data.mint),
+                    false,
+                ),
+                AccountMeta::new(
+                    stream_settlement(&stream, bond),
+                    false,
+                ),
+            ]);
+        }
+        accounts
+    }
+
+    /// Claim a bond as `wallet`, its owner or the holder of its
+    /// token.
+    pub async fn claim(
+        &mut self,
+        wallet: &Keypair,
+        bond: &Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let bond_data = self.bond(bond).await.unwrap();
+        let owner_data = self.data(&bond_data.owner).await.unwrap();
+        let owner =
+            UserAccount::deserialize_account_data(&owner_data)
+                .unwrap()
+                .user;
+        let holder = wallet.pubkey();
+        let mint = self.native_token_mint;
+        let page = self.page_of(bond).await;
+        let mut accounts = vec![
+            AccountMeta::new(*bond, false),
+            AccountMeta::new(holder, true),
+            AccountMeta::new(bond_data.owner, false),
+            AccountMeta::new(page, false),
+            AccountMeta::new(ata(&holder, &mint), false),
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new(self.rewards_pool_ata(), false),
+            AccountMeta::new(self.treasury_ata(), false),
+            AccountMeta::new(self.principal_vault_ata(), false),
+            AccountMeta::new(self.team_vesting_ata(), false),
+            none(),
+            none(),
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(
+                spl_associated_token_account::ID,
+                false,
+            ),
+            AccountMeta::new_readonly(
+                solana_system_interface::program::ID,
+                false,
+            ),
+            AccountMeta::new(global_state(), false),
+            AccountMeta::new_readonly(split_config(), false),
+            AccountMeta::new_readonly(reward_streams(), false),
+            none(),
+            none(),
+            none(),
+            none(),
+            none(),
+            none(),
+        ];
+        if bond_data.mint != Pubkey::default() {
+            accounts[19] = AccountMeta::new_readonly(
+                ata(&holder, &bond_data.mint),
+                false,
+            );
+        }
+        let referrer = self.user(&holder).await.referrer;
+        if referrer != Pubkey::default() {
+            accounts[20] =
+                AccountMeta::new(ata(&referrer, &mint), false);
+            accounts[21] =
+                AccountMeta::new(referrer_stats(&referrer), false);
+        }
+        if holder != owner {
+            accounts[22] = AccountMeta::new(user_pda(&holder), false);
+            accounts[23] = AccountMeta::new(
+                self.open_bond_page(&holder).await,
+                false,
+            );
+            accounts[24] = AccountMeta::new(owner, false);
+        }
+        accounts.extend(self.stream_accounts(&holder, bond).await);
+        let claim = instruction(
+            TestudoBondsInstruction::ProcessClaim {
+                bond_index: bond_data.bond_index,
+                auto_compound: false,
+            },
+            accounts,
+        );
+        self.process(&[claim], &[wallet]).await
+    }
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{tokio, BanksClientError};
+use solana_sdk::signature::{Keypair, Signer};
+use testudo_bonds::{
+    error::TestudoBondsError, instruction::TestudoBondsInstruction,
+    state::FixedTermConfig,
+};
+
+const TERM: u32 = 30 * 86_400;
+const MATURITY_REWARD: u64 = SHELLS_PER_TESTUDO;
+
+/// Enable fixed-term bonds returning 80% of their principal.
+async fn enable_fixed_term(ctx: &mut TestContext) {
+    ctx.update_admin(|admin| {
+        admin.fixed_term = FixedTermConfig {
+            term_seconds: TERM,
+            reward: MATURITY_REWARD,
+            principal_return_bps: 8_000,
+        }
+    })
+    .await;
+}
+
+async fn initialize_fixed_term_bond(
+    ctx: &mut TestContext,
+    wallet: &Keypair,
+) -> Pubkey {
+    let accounts =
+        ctx.initialize_bond_accounts(&wallet.pubkey()).await;
+    let bond = accounts[0].pubkey;
+    let initialize_bond = instruction(
+        TestudoBondsInstruction::InitializeBond {
+            tokenize: false,
+            lock_tier: None,
+            fixed_term: true,
+            allowlist_proof: None,
+        },
+        accounts,
+    );
+    ctx.process(&[initialize_bond], &[wallet]).await.unwrap();
+    bond
+}
+
+async fn mature_bond(
+    ctx: &mut TestContext,
+    wallet: &Keypair,
+    bond: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let bond_data = ctx.bond(bond).await.unwrap();
+    let mint = ctx.native_token_mint;
+    let mature = instruction(
+        TestudoBondsInstruction::MatureBond {
+            bond_index: bond_data.bond_index,
+        },
+        vec![
+            AccountMeta::new(*bond, false),
+            AccountMeta::new(wallet.pubkey(), true),
+            AccountMeta::new(user_pda(&wallet.pubkey()), false),
+            AccountMeta::new(ctx.page_of(bond).await, false),
+            AccountMeta::new(ata(&wallet.pubkey(), &mint), false),
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new(ctx.rewards_pool_ata(), false),
+            AccountMeta::new(global_state(), false),
+            AccountMeta::new_readonly(principal_vault(), false),
+            AccountMeta::new(ctx.principal_vault_ata(), false),
+            AccountMeta::new(ctx.team_vesting_ata(), false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(split_config(), false),
+        ],
+    );
+    ctx.process(&[mature], &[wallet]).await
+}
+
+#[tokio::test]
+async fn fixed_term_bond_matures_with_its_reward_and_principal() {
+    let mut ctx = TestContext::new().await;
+    enable_fixed_term(&mut ctx).await;
+    // A perpetual bond funds the rewards pool
+    let funder = ctx.create_user().await;
+    ctx.initialize_bond(&funder, false).await;
+    let user = ctx.create_user().await;
+    let bond = initialize_fixed_term_bond(&mut ctx, &user).await;
+    let user_ata = ata(&user.pubkey(), &ctx.native_token_mint);
+    let principal_vault_ata = ctx.principal_vault_ata();
+    let user_balance = ctx.balance(&user_ata).await;
+    let pending_treasury = ctx.global_state().await.pending_treasury;
+    assert_eq!(
+        ctx.balance(&principal_vault_ata).await,
+        pending_treasury + 10 * SHELLS_PER_TESTUDO
+    );
+
+    // Fixed-term bonds earn no emission and cannot mature early
+    ctx.warp(DAY).await;
+    let result = ctx.claim(&user, &bond).await;
+    assert_error(result, TestudoBondsError::BondIsFixedTerm);
+    let result = mature_bond(&mut ctx, &user, &bond).await;
+    assert_error(result, TestudoBondsError::BondNotMature);
+
+    ctx.warp(TERM as i64).await;
+    mature_bond(&mut ctx, &user, &bond).await.unwrap();
+
+    // The user gets the reward and 80% of the principal, the rest is
+    // split like a deposit: 40% to the pool, 40% to the treasury and
+    // 20% to the team
+    assert!(ctx.bond(&bond).await.is_none());
+    assert_eq!(
+        ctx.balance(&user_ata).await,
+        user_balance + MATURITY_REWARD + 8 * SHELLS_PER_TESTUDO
+    );
+    let global_state = ctx.global_state().await;
+    assert_eq!(
+        global_state.pending_treasury,
+        pending_treasury + 800_000_000
+    );
+    assert_eq!(
+        ctx.balance(&principal_vault_ata).await,
+        global_state.pending_treasury
+    );
+    assert_eq!(ctx.user(&user.pubkey()).await.bond_count, 0);
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{tokio, BanksClientError};
+use solana_sdk::signature::{Keypair, Signer};
+use testudo_bonds::{
+    error::TestudoBondsError, instruction::TestudoBondsInstruction,
+    state::GrantSource,
+};
+
+/// Grant a bond to `wallet`, signed by `grant_authority` or by the
+/// payer when none is given.
+async fn grant_bond(
+    ctx: &mut TestContext,
+    grant_authority: Option<&Keypair>,
+    wallet: &Pubkey,
+    grant_source: GrantSource,
+) -> Result<Pubkey, BanksClientError> {
+    let user_pda = user_pda(wallet);
+    let (page, bond_index) = match ctx.data(&user_pda).await {
+        Some(_) => (
+            ctx.open_bond_page(wallet).await,
+            ctx.user(wallet).await.bond_index,
+        ),
+        None => (bond_page(&user_pda, 0), 0),
+    };
+    let bond = bond(&user_pda, bond_index);
+    let signer = grant_authority
+        .map(|keypair| keypair.pubkey())
+        .unwrap_or(ctx.authority());
+    let grant = instruction(
+        TestudoBondsInstruction::GrantBond { grant_source },
+        vec![
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(*wallet, false),
+            AccountMeta::new(user_pda, false),
+            AccountMeta::new(page, false),
+            AccountMeta::new(bond, false),
+            AccountMeta::new(global_state(), false),
+            AccountMeta::new_readonly(
+                solana_system_interface::program::ID,
+                false,
+            ),
+        ],
+    );
+    let signers: Vec<&Keypair> =
+        grant_authority.into_iter().collect();
+    ctx.process(&[grant], &signers).await?;
+    Ok(bond)
+}
+
+#[tokio::test]
+async fn grant_creates_the_user_and_a_bond_without_a_deposit() {
+    let mut ctx = TestContext::new().await;
+    let wallet = Keypair::new();
+    let rewards_pool_ata = ctx.rewards_pool_ata();
+    let rewards_pool = ctx.balance(&rewards_pool_ata).await;
+    let active_weight = ctx.global_state().await.total_active_weight;
+
+    let bond = grant_bond(
+        &mut ctx,
+        None,
+        &wallet.pubkey(),
+        GrantSource::Airdrop,
+    )
+    .await
+    .unwrap();
+
+    let user = ctx.user(&wallet.pubkey()).await;
+    assert_eq!(user.user, wallet.pubkey());
+    assert_eq!(user.bond_count, 1);
+    let bond_data = ctx.bond(&bond).await.unwrap();
+    assert_eq!(bond_data.owner, user_pda(&wallet.pubkey()));
+    assert_eq!(bond_data.grant_source, GrantSource::Airdrop);
+    assert_eq!(bond_data.principal, 0);
+    let page = ctx.page_of(&bond).await;
+    assert!(ctx.bond_page(&page).await.bonds.contains(&bond));
+    assert!(
+        ctx.global_state().await.total_active_weight > active_weight
+    );
+    assert_eq!(ctx.balance(&rewards_pool_ata).await, rewards_pool);
+}
+
+#[tokio::test]
+async fn granted_bond_earns_like_a_bought_bond() {
+    let mut ctx = TestContext::new().await;
+    // A bought bond funds the rewards pool
+    let funder = ctx.create_user().await;
+    ctx.initialize_bond(&funder, false).await;
+    let user = ctx.create_user().await;
+    let bond = grant_bond(
+        &mut ctx,
+        None,
+        &user.pubkey(),
+        GrantSource::Contributor,
+    )
+    .await
+    .unwrap();
+
+    ctx.warp(DAY).await;
+    ctx.claim(&user, &bond).await.unwrap();
+
+    assert!(ctx.bond(&bond).await.unwrap().total_claimed > 0);
+    assert_eq!(ctx.user(&user.pubkey()).await.bond_count, 1);
+}
+
+#[tokio::test]
+async fn grant_requires_the_grant_authority_and_a_grant_source() {
+    let mut ctx = TestContext::new().await;
+    let wallet = Keypair::new();
+    let outsider = ctx.create_user().await;
+
+    let result = grant_bond(
+        &mut ctx,
+        Some(&outsider),
+        &wallet.pubkey(),
+        GrantSource::Airdrop,
+    )
+    .await;
+    assert_error(
+        result.map(|_| ()),
+        TestudoBondsError::InvalidAccountKey,
+    );
+
+    let result = grant_bond(
+        &mut ctx,
+        None,
+        &wallet.pubkey(),
+        GrantSource::None,
+    )
+    .await;
+    assert_error(
+        result.map(|_| ()),
+        TestudoBondsError::InvalidGrantSource,
+    );
+
+    ctx.update_admin(|admin| admin.pause_bond_operations = true)
+        .await;
+    let result = grant_bond(
+        &mut ctx,
+        None,
+        &wallet.pubkey(),
+        GrantSource::Incident,
+    )
+    .await;
+    assert_error(
+        result.map(|_| ()),
+        TestudoBondsError::BondOperationsPaused,
+    );
+
+    assert!(ctx.data(&user_pda(&wallet.pubkey())).await.is_none());
+}
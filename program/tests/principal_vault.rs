@@ -0,0 +1,63 @@
+mod common;
+
+use common::*;
+use solana_program::instruction::AccountMeta;
+use solana_program_test::{tokio, BanksClientError};
+use testudo_bonds::{
+    error::TestudoBondsError, instruction::TestudoBondsInstruction,
+};
+
+const DISTRIBUTION_INTERVAL: i64 = 7 * DAY;
+
+async fn distribute_vault(
+    ctx: &mut TestContext,
+) -> Result<(), BanksClientError> {
+    let distribute = instruction(
+        TestudoBondsInstruction::DistributeVault,
+        vec![
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new(global_state(), false),
+            AccountMeta::new_readonly(principal_vault(), false),
+            AccountMeta::new(ctx.principal_vault_ata(), false),
+            AccountMeta::new(ctx.treasury_ata(), false),
+            AccountMeta::new_readonly(ctx.native_token_mint, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+    );
+    ctx.process(&[distribute], &[]).await
+}
+
+#[tokio::test]
+async fn vault_releases_the_treasury_share_once_per_interval() {
+    let mut ctx = TestContext::new().await;
+    let user = ctx.create_user().await;
+    ctx.initialize_bond(&user, false).await;
+    let treasury_ata = ctx.treasury_ata();
+    let principal_vault_ata = ctx.principal_vault_ata();
+    let treasury_balance = ctx.balance(&treasury_ata).await;
+
+    // The treasury's 40% of the deposit waits in the vault
+    let pending = 4 * SHELLS_PER_TESTUDO;
+    assert_eq!(ctx.global_state().await.pending_treasury, pending);
+    assert_eq!(ctx.balance(&principal_vault_ata).await, pending);
+    let result = distribute_vault(&mut ctx).await;
+    assert_error(result, TestudoBondsError::DistributionNotDue);
+
+    ctx.warp(DISTRIBUTION_INTERVAL).await;
+    distribute_vault(&mut ctx).await.unwrap();
+
+    let now = ctx.clock().await.unix_timestamp;
+    let global_state = ctx.global_state().await;
+    assert_eq!(global_state.pending_treasury, 0);
+    assert_eq!(global_state.last_vault_distribution, now);
+    assert_eq!(ctx.balance(&principal_vault_ata).await, 0);
+    assert_eq!(
+        ctx.balance(&treasury_ata).await,
+        treasury_balance + pending
+    );
+
+    // The next release waits for a full interval again
+    ctx.warp(DISTRIBUTION_INTERVAL - 1).await;
+    let result = distribute_vault(&mut ctx).await;
+    assert_error(result, TestudoBondsError::DistributionNotDue);
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{tokio, BanksClientError};
+use solana_sdk::signature::{Keypair, Signer};
+use testudo_bonds::{
+    error::TestudoBondsError,
+    instruction::TestudoBondsInstruction,
+    state::{ReferrerStats, Serialization},
+};
+
+/// Buy a bond referred by `referrer`.
+async fn initialize_referred_bond(
+    ctx: &mut TestContext,
+    wallet: &Keypair,
+    referrer: &Pubkey,
+) -> Result<Pubkey, BanksClientError> {
+    let mut accounts =
+        ctx.initialize_bond_accounts(&wallet.pubkey()).await;
+    let bond = accounts[0].pubkey;
+    let mint = ctx.native_token_mint;
+    accounts[18] =
+        AccountMeta::new_readonly(user_pda(referrer), false);
+    accounts[19] = AccountMeta::new(ata(referrer, &mint), false);
+    accounts[20] = AccountMeta::new(referrer_stats(referrer), false);
+    let initialize_bond = instruction(
+        TestudoBondsInstruction::InitializeBond {
+            tokenize: false,
+            lock_tier: None,
+            fixed_term: false,
+            allowlist_proof: None,
+        },
+        accounts,
+    );
+    ctx.process(&[initialize_bond], &[wallet]).await?;
+    Ok(bond)
+}
+
+async fn stats(
+    ctx: &mut TestContext,
+    referrer: &Pubkey,
+) -> ReferrerStats {
+    let data = ctx.data(&referrer_stats(referrer)).await.unwrap();
+    ReferrerStats::deserialize_account_data(&data).unwrap()
+}
+
+#[tokio::test]
+async fn referrer_earns_from_deposits_and_claims() {
+    let mut ctx = TestContext::new().await;
+    ctx.update_admin(|admin| {
+        admin.referral_deposit_bps = 500;
+        admin.referral_claim_bps = 1_000;
+    })
+    .await;
+    let referrer = ctx.create_user().await;
+    let user = ctx.create_user().await;
+    let mint = ctx.native_token_mint;
+    let referrer_ata = ata(&referrer.pubkey(), &mint);
+    let user_ata = ata(&user.pubkey(), &mint);
+    let referrer_balance = ctx.balance(&referrer_ata).await;
+
+    let bond =
+        initialize_referred_bond(&mut ctx, &user, &referrer.pubkey())
+            .await
+            .unwrap();
+
+    // The referrer is set and paid 5% of the deposit
+    let deposit_share = 500_000_000;
+    assert_eq!(
+        ctx.user(&user.pubkey()).await.referrer,
+        referrer.pubkey()
+    );
+    assert_eq!(
+        ctx.balance(&referrer_ata).await,
+        referrer_balance + deposit_share
+    );
+    let referrals = stats(&mut ctx, &referrer.pubkey()).await;
+    assert_eq!(referrals.referral_count, 1);
+    assert_eq!(referrals.total_deposit_rewards, deposit_share);
+
+    // and 10% of the referred user's claims
+    let user_balance = ctx.balance(&user_ata).await;
+    ctx.warp(10 * DAY).await;
+    ctx.claim(&user, &bond).await.unwrap();
+
+    let reward = ctx.bond(&bond).await.unwrap().total_claimed;
+    let claim_share = reward / 10;
+    assert!(claim_share > 0);
+    assert_eq!(
+        ctx.balance(&referrer_ata).await,
+        referrer_balance + deposit_share + claim_share
+    );
+    assert_eq!(
+        ctx.balance(&user_ata).await,
+        user_balance + reward - claim_share
+    );
+    let referrals = stats(&mut ctx, &referrer.pubkey()).await;
+    assert_eq!(referrals.total_claim_rewards, claim_share);
+}
+
+#[tokio::test]
+async fn users_cannot_refer_themselves() {
+    let mut ctx = TestContext::new().await;
+    let user = ctx.create_user().await;
+
+    let result =
+        initialize_referred_bond(&mut ctx, &user, &user.pubkey())
+            .await;
+
+    assert_error(
+        result.map(|_| ()),
+        TestudoBondsError::InvalidReferral,
+    );
+    assert_eq!(
+        ctx.user(&user.pubkey()).await.referrer,
+        Pubkey::default()
+    );
+}
@@ -0,0 +1,548 @@
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{tokio, BanksClientError};
+use solana_sdk::signature::{Keypair, Signer};
+use testudo_bonds::{
+    error::TestudoBondsError,
+    instruction::TestudoBondsInstruction,
+    state::{RewardStreamList, Serialization, StreamSettlement},
+};
+
+const STREAM_RATE: u64 = 1_000;
+
+/// Attach a stream of a new mint emitting `STREAM_RATE` per second
+/// for 30 days from now, and fund its pool with `funding`.
+async fn attach_stream(
+    ctx: &mut TestContext,
+    funding: u64,
+) -> Pubkey {
+    let stream_mint = ctx.create_mint().await;
+    let stream = reward_stream(&stream_mint);
+    let now = ctx.clock().await.unix_timestamp;
+    let attach = instruction(
+        TestudoBondsInstruction::AttachRewardStream {
+            rate: STREAM_RATE,
+            start_timestamp: now,
+            end_timestamp: now + 30 * DAY,
+        },
+        vec![
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new(ctx.authority(), true),
+            AccountMeta::new(reward_streams(), false),
+            AccountMeta::new(stream, false),
+            AccountMeta::new(ata(&stream, &stream_mint), false),
+            AccountMeta::new_readonly(stream_mint, false),
+            AccountMeta::new_readonly(
+                solana_system_interface::program::ID,
+                false,
+            ),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(
+                spl_associated_token_account::ID,
+                false,
+            ),
+        ],
+    );
+    ctx.process(&[attach], &[]).await.unwrap();
+    ctx.mint_to(&stream, &stream_mint, funding).await;
+    stream_mint
+}
+
+async fn detach_stream(
+    ctx: &mut TestContext,
+    stream_mint: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let stream = reward_stream(stream_mint);
+    let authority = ctx.authority();
+    let detach = instruction(
+        TestudoBondsInstruction::DetachRewardStream,
+        vec![
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new(authority, true),
+            AccountMeta::new(reward_streams(), false),
+            AccountMeta::new(stream, false),
+            AccountMeta::new(ata(&stream, stream_mint), false),
+            AccountMeta::new_readonly(*stream_mint, false),
+            AccountMeta::new(ata(&authority, stream_mint), false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+    );
+    ctx.process(&[detach], &[]).await
+}
+
+async fn transfer_bond(
+    ctx: &mut TestContext,
+    owner: &Keypair,
+    recipient: &Pubkey,
+    bond: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let bond_data = ctx.bond(bond).await.unwrap();
+    let mint = ctx.native_token_mint;
+    let mut accounts = vec![
+        AccountMeta::new(*bond, false),
+        AccountMeta::new(owner.pubkey(), true),
+        AccountMeta::new(user_pda(&owner.pubkey()), false),
+        AccountMeta::new(ctx.page_of(bond).await, false),
+        AccountMeta::new(ata(&owner.pubkey(), &mint), false),
+        AccountMeta::new(user_pda(recipient), false),
+        AccountMeta::new(ctx.open_bond_page(recipient).await, false),
+        AccountMeta::new_readonly(global_admin(), false),
+        AccountMeta::new(global_state(), false),
+        AccountMeta::new(ctx.rewards_pool_ata(), false),
+        AccountMeta::new(ctx.treasury_ata(), false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new_readonly(
+            solana_system_interface::program::ID,
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(reward_streams(), false),
+    ];
+    accounts.extend(ctx.stream_accounts(&owner.pubkey(), bond).await);
+    let transfer = instruction(
+        TestudoBondsInstruction::TransferBond {
+            bond_index: bond_data.bond_index,
+        },
+        accounts,
+    );
+    ctx.process(&[transfer], &[owner]).await
+}
+
+async fn list_bond(
+    ctx: &mut TestContext,
+    seller: &Keypair,
+    bond: &Pubkey,
+    price: u64,
+) {
+    let bond_data = ctx.bond(bond).await.unwrap();
+    let list = instruction(
+        TestudoBondsInstruction::ListBond {
+            bond_index: bond_data.bond_index,
+            price,
+        },
+        vec![
+            AccountMeta::new(*bond, false),
+            AccountMeta::new(seller.pubkey(), true),
+            AccountMeta::new_readonly(
+                user_pda(&seller.pubkey()),
+                false,
+            ),
+            AccountMeta::new_readonly(ctx.page_of(bond).await, false),
+            AccountMeta::new(listing(bond), false),
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new_readonly(
+                solana_system_interface::program::ID,
+                false,
+            ),
+        ],
+    );
+    ctx.process(&[list], &[seller]).await.unwrap();
+}
+
+async fn buy_bond(
+    ctx: &mut TestContext,
+    buyer: &Keypair,
+    seller: &Pubkey,
+    bond: &Pubkey,
+    max_price: u64,
+) -> Result<(), BanksClientError> {
+    let bond_data = ctx.bond(bond).await.unwrap();
+    let mint = ctx.native_token_mint;
+    let mut accounts = vec![
+        AccountMeta::new(*bond, false),
+        AccountMeta::new(buyer.pubkey(), true),
+        AccountMeta::new(user_pda(&buyer.pubkey()), false),
+        AccountMeta::new(
+            ctx.open_bond_page(&buyer.pubkey()).await,
+            false,
+        ),
+        AccountMeta::new(ata(&buyer.pubkey(), &mint), false),
+        AccountMeta::new(*seller, false),
+        AccountMeta::new(user_pda(seller), false),
+        AccountMeta::new(ctx.page_of(bond).await, false),
+        AccountMeta::new(ata(seller, &mint), false),
+        AccountMeta::new(listing(bond), false),
+        AccountMeta::new_readonly(global_admin(), false),
+        AccountMeta::new(ctx.treasury_ata(), false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(
+            solana_system_interface::program::ID,
+            false,
+        ),
+        AccountMeta::new(global_state(), false),
+        AccountMeta::new(ctx.rewards_pool_ata(), false),
+        AccountMeta::new_readonly(reward_streams(), false),
+    ];
+    accounts.extend(ctx.stream_accounts(seller, bond).await);
+    let buy = instruction(
+        TestudoBondsInstruction::BuyBond {
+            bond_index: bond_data.bond_index,
+            max_price,
+        },
+        accounts,
+    );
+    ctx.process(&[buy], &[buyer]).await
+}
+
+async fn merge_bonds(
+    ctx: &mut TestContext,
+    wallet: &Keypair,
+    target: &Pubkey,
+    source: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let target_data = ctx.bond(target).await.unwrap();
+    let source_data = ctx.bond(source).await.unwrap();
+    let mint = ctx.native_token_mint;
+    let mut accounts = vec![
+        AccountMeta::new(*target, false),
+        AccountMeta::new(*source, false),
+        AccountMeta::new(wallet.pubkey(), true),
+        AccountMeta::new(user_pda(&wallet.pubkey()), false),
+        AccountMeta::new_readonly(ctx.page_of(target).await, false),
+        AccountMeta::new(ctx.page_of(source).await, false),
+        AccountMeta::new(ata(&wallet.pubkey(), &mint), false),
+        AccountMeta::new_readonly(global_admin(), false),
+        AccountMeta::new(ctx.rewards_pool_ata(), false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new(ctx.treasury_ata(), false),
+        AccountMeta::new(global_state(), false),
+        AccountMeta::new_readonly(
+            solana_system_interface::program::ID,
+            false,
+        ),
+        AccountMeta::new_readonly(reward_streams(), false),
+    ];
+    let target_streams =
+        ctx.stream_accounts(&wallet.pubkey(), target).await;
+    let source_streams =
+        ctx.stream_accounts(&wallet.pubkey(), source).await;
+    for (target_stream, source_stream) in
+        target_streams.chunks(5).zip(source_streams.chunks(5))
+    {
+        accounts.extend_from_slice(target_stream);
+        accounts.push(source_stream[4].clone());
+    }
+    let merge = instruction(
+        TestudoBondsInstruction::MergeBonds {
+            target_bond_index: target_data.bond_index,
+            source_bond_index: source_data.bond_index,
+        },
+        accounts,
+    );
+    ctx.process(&[merge], &[wallet]).await
+}
+
+async fn split_bond(
+    ctx: &mut TestContext,
+    wallet: &Keypair,
+    bond: &Pubkey,
+    split_weight: u32,
+) -> Result<Pubkey, BanksClientError> {
+    let bond_data = ctx.bond(bond).await.unwrap();
+    let user_pda = user_pda(&wallet.pubkey());
+    let bond_index = ctx.user(&wallet.pubkey()).await.bond_index;
+    let new_bond = common::bond(&user_pda, bond_index);
+    let mint = ctx.native_token_mint;
+    let mut accounts = vec![
+        AccountMeta::new(*bond, false),
+        AccountMeta::new(new_bond, false),
+        AccountMeta::new(wallet.pubkey(), true),
+        AccountMeta::new(user_pda, false),
+        AccountMeta::new_readonly(ctx.page_of(bond).await, false),
+        AccountMeta::new(
+            ctx.open_bond_page(&wallet.pubkey()).await,
+            false,
+        ),
+        AccountMeta::new(ata(&wallet.pubkey(), &mint), false),
+        AccountMeta::new_readonly(global_admin(), false),
+        AccountMeta::new(ctx.rewards_pool_ata(), false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(
+            solana_system_interface::program::ID,
+            false,
+        ),
+        AccountMeta::new(ctx.treasury_ata(), false),
+        AccountMeta::new(global_state(), false),
+        AccountMeta::new_readonly(reward_streams(), false),
+    ];
+    let streams = ctx.stream_accounts(&wallet.pubkey(), bond).await;
+    for stream in streams.chunks(5) {
+        accounts.extend_from_slice(stream);
+        let settlement =
+            stream_settlement(&stream[0].pubkey, &new_bond);
+        accounts.push(AccountMeta::new(settlement, false));
+    }
+    let split = instruction(
+        TestudoBondsInstruction::SplitBond {
+            bond_index: bond_data.bond_index,
+            split_weight,
+        },
+        accounts,
+    );
+    ctx.process(&[split], &[wallet]).await?;
+    Ok(new_bond)
+}
+
+async fn settled_at(
+    ctx: &mut TestContext,
+    stream_mint: &Pubkey,
+    bond: &Pubkey,
+) -> Option<i64> {
+    let settlement =
+        stream_settlement(&reward_stream(stream_mint), bond);
+    let data = ctx.data(&settlement).await?;
+    Some(
+        StreamSettlement::deserialize_account_data(&data)
+            .unwrap()
+            .last_settled_timestamp,
+    )
+}
+
+#[tokio::test]
+async fn claim_pays_the_stream_from_the_bond_creation() {
+    let mut ctx = TestContext::new().await;
+    let user = ctx.create_user().await;
+    let bond = ctx.initialize_bond(&user, false).await;
+    let stream_mint =
+        attach_stream(&mut ctx, SHELLS_PER_TESTUDO).await;
+    ctx.mint_to(&user.pubkey(), &stream_mint, 0).await;
+    let user_stream_ata = ata(&user.pubkey(), &stream_mint);
+
+    ctx.warp(DAY).await;
+    ctx.claim(&user, &bond).await.unwrap();
+
+    let now = ctx.clock().await.unix_timestamp;
+    assert_eq!(
+        ctx.balance(&user_stream_ata).await,
+        STREAM_RATE * DAY as u64
+    );
+    assert_eq!(
+        settled_at(&mut ctx, &stream_mint, &bond).await,
+        Some(now)
+    );
+}
+
+#[tokio::test]
+async fn transfer_settles_the_owner_before_moving_the_bond() {
+    let mut ctx = TestContext::new().await;
+    let owner = ctx.create_user().await;
+    let recipient = ctx.create_user().await;
+    let bond = ctx.initialize_bond(&owner, false).await;
+    let stream_mint =
+        attach_stream(&mut ctx, SHELLS_PER_TESTUDO).await;
+    ctx.mint_to(&owner.pubkey(), &stream_mint, 0).await;
+    ctx.mint_to(&recipient.pubkey(), &stream_mint, 0).await;
+    let owner_ata = ata(&owner.pubkey(), &ctx.native_token_mint);
+    let owner_stream_ata = ata(&owner.pubkey(), &stream_mint);
+    let recipient_stream_ata = ata(&recipient.pubkey(), &stream_mint);
+    let owner_balance = ctx.balance(&owner_ata).await;
+
+    ctx.warp(10 * DAY).await;
+    transfer_bond(&mut ctx, &owner, &recipient.pubkey(), &bond)
+        .await
+        .unwrap();
+
+    // The owner was paid what the bond earned until the transfer
+    let now = ctx.clock().await.unix_timestamp;
+    let bond_data = ctx.bond(&bond).await.unwrap();
+    assert!(bond_data.total_claimed > 0);
+    assert_eq!(bond_data.last_claim_timestamp, now);
+    assert_eq!(bond_data.owner, user_pda(&recipient.pubkey()));
+    assert_eq!(
+        ctx.balance(&owner_ata).await,
+        owner_balance + bond_data.total_claimed
+    );
+    assert_eq!(
+        ctx.balance(&owner_stream_ata).await,
+        STREAM_RATE * 10 * DAY as u64
+    );
+    assert_eq!(
+        settled_at(&mut ctx, &stream_mint, &bond).await,
+        Some(now)
+    );
+    assert_eq!(ctx.user(&owner.pubkey()).await.bond_count, 0);
+    assert_eq!(ctx.user(&recipient.pubkey()).await.bond_count, 1);
+
+    // The recipient only earns from the transfer on
+    ctx.warp(DAY).await;
+    ctx.claim(&recipient, &bond).await.unwrap();
+    assert_eq!(
+        ctx.balance(&recipient_stream_ata).await,
+        STREAM_RATE * DAY as u64
+    );
+}
+
+#[tokio::test]
+async fn transfer_fails_when_a_stream_cannot_pay_the_owner() {
+    let mut ctx = TestContext::new().await;
+    let owner = ctx.create_user().await;
+    let recipient = ctx.create_user().await;
+    let bond = ctx.initialize_bond(&owner, false).await;
+    let stream_mint = attach_stream(&mut ctx, 0).await;
+    ctx.mint_to(&owner.pubkey(), &stream_mint, 0).await;
+
+    ctx.warp(DAY).await;
+    let result =
+        transfer_bond(&mut ctx, &owner, &recipient.pubkey(), &bond)
+            .await;
+
+    assert_error(result, TestudoBondsError::InsufficientRewards);
+    let bond_data = ctx.bond(&bond).await.unwrap();
+    assert_eq!(bond_data.owner, user_pda(&owner.pubkey()));
+}
+
+#[tokio::test]
+async fn buy_settles_the_seller_before_moving_the_bond() {
+    let mut ctx = TestContext::new().await;
+    let seller = ctx.create_user().await;
+    let buyer = ctx.create_user().await;
+    let bond = ctx.initialize_bond(&seller, false).await;
+    let stream_mint =
+        attach_stream(&mut ctx, SHELLS_PER_TESTUDO).await;
+    ctx.mint_to(&seller.pubkey(), &stream_mint, 0).await;
+    let seller_ata = ata(&seller.pubkey(), &ctx.native_token_mint);
+    let seller_stream_ata = ata(&seller.pubkey(), &stream_mint);
+    let price = 5 * SHELLS_PER_TESTUDO;
+    list_bond(&mut ctx, &seller, &bond, price).await;
+    let seller_balance = ctx.balance(&seller_ata).await;
+
+    ctx.warp(10 * DAY).await;
+    buy_bond(&mut ctx, &buyer, &seller.pubkey(), &bond, price)
+        .await
+        .unwrap();
+
+    // The seller was paid the price net of the marketplace fee, and
+    // what the bond earned until the sale
+    let now = ctx.clock().await.unix_timestamp;
+    let fee =
+        price * ctx.admin().await.marketplace_fee as u64 / 10_000;
+    let bond_data = ctx.bond(&bond).await.unwrap();
+    assert!(bond_data.total_claimed > 0);
+    assert_eq!(bond_data.last_claim_timestamp, now);
+    assert_eq!(bond_data.owner, user_pda(&buyer.pubkey()));
+    assert!(!bond_data.is_listed);
+    assert_eq!(
+        ctx.balance(&seller_ata).await,
+        seller_balance + price - fee + bond_data.total_claimed
+    );
+    assert_eq!(
+        ctx.balance(&seller_stream_ata).await,
+        STREAM_RATE * 10 * DAY as u64
+    );
+    assert_eq!(
+        settled_at(&mut ctx, &stream_mint, &bond).await,
+        Some(now)
+    );
+    assert!(ctx.data(&listing(&bond)).await.is_none());
+}
+
+#[tokio::test]
+async fn merge_pays_both_bonds_and_closes_the_source_settlement() {
+    let mut ctx = TestContext::new().await;
+    let user = ctx.create_user().await;
+    let target = ctx.initialize_bond(&user, false).await;
+    let source = ctx.initialize_bond(&user, false).await;
+    let stream_mint =
+        attach_stream(&mut ctx, SHELLS_PER_TESTUDO).await;
+    ctx.mint_to(&user.pubkey(), &stream_mint, 0).await;
+    let user_stream_ata = ata(&user.pubkey(), &stream_mint);
+
+    ctx.warp(DAY).await;
+    merge_bonds(&mut ctx, &user, &target, &source)
+        .await
+        .unwrap();
+
+    let now = ctx.clock().await.unix_timestamp;
+    assert_eq!(
+        ctx.balance(&user_stream_ata).await,
+        2 * STREAM_RATE * DAY as u64
+    );
+    assert_eq!(
+        settled_at(&mut ctx, &stream_mint, &target).await,
+        Some(now)
+    );
+    assert_eq!(
+        settled_at(&mut ctx, &stream_mint, &source).await,
+        None
+    );
+    assert!(ctx.bond(&source).await.is_none());
+}
+
+#[tokio::test]
+async fn split_pays_the_bond_and_starts_the_new_bond_at_the_split() {
+    let mut ctx = TestContext::new().await;
+    let user = ctx.create_user().await;
+    let bond = ctx.initialize_bond(&user, false).await;
+    let stream_mint =
+        attach_stream(&mut ctx, SHELLS_PER_TESTUDO).await;
+    ctx.mint_to(&user.pubkey(), &stream_mint, 0).await;
+    let user_stream_ata = ata(&user.pubkey(), &stream_mint);
+
+    ctx.warp(DAY).await;
+    let new_bond =
+        split_bond(&mut ctx, &user, &bond, 5_000).await.unwrap();
+
+    // The bond was paid at its full weight until the split
+    let now = ctx.clock().await.unix_timestamp;
+    assert_eq!(
+        ctx.balance(&user_stream_ata).await,
+        STREAM_RATE * DAY as u64
+    );
+    assert_eq!(
+        settled_at(&mut ctx, &stream_mint, &bond).await,
+        Some(now)
+    );
+    assert_eq!(
+        settled_at(&mut ctx, &stream_mint, &new_bond).await,
+        Some(now)
+    );
+
+    // and the new bond earns at its own weight from the split on
+    ctx.warp(DAY).await;
+    ctx.claim(&user, &new_bond).await.unwrap();
+    assert_eq!(
+        ctx.balance(&user_stream_ata).await,
+        STREAM_RATE * DAY as u64 * 3 / 2
+    );
+}
+
+#[tokio::test]
+async fn detach_removes_an_ended_stream_and_sweeps_its_pool() {
+    let mut ctx = TestContext::new().await;
+    let owner = ctx.create_user().await;
+    let recipient = ctx.create_user().await;
+    let bond = ctx.initialize_bond(&owner, false).await;
+    let stream_mint =
+        attach_stream(&mut ctx, SHELLS_PER_TESTUDO).await;
+    let authority = ctx.authority();
+    let authority_stream_ata =
+        ctx.mint_to(&authority, &stream_mint, 0).await;
+
+    // A running stream cannot be detached
+    let result = detach_stream(&mut ctx, &stream_mint).await;
+    assert_error(result, TestudoBondsError::InvalidRewardStream);
+
+    ctx.warp(31 * DAY).await;
+    detach_stream(&mut ctx, &stream_mint).await.unwrap();
+
+    let data = ctx.data(&reward_streams()).await.unwrap();
+    let streams = RewardStreamList::deserialize_account_data(&data)
+        .unwrap()
+        .streams;
+    assert!(streams.is_empty());
+    assert!(ctx.data(&reward_stream(&stream_mint)).await.is_none());
+    assert_eq!(
+        ctx.balance(&authority_stream_ata).await,
+        SHELLS_PER_TESTUDO
+    );
+
+    // Bonds no longer take the stream's accounts
+    transfer_bond(&mut ctx, &owner, &recipient.pubkey(), &bond)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::{tokio, BanksClientError};
+use solana_sdk::signature::{Keypair, Signer};
+use testudo_bonds::{
+    error::TestudoBondsError,
+    instruction::TestudoBondsInstruction,
+    state::{SplitDestination, SplitRecipient},
+};
+
+/// A recipient of `bps` of each deposit, paid to its destination.
+fn share(destination: SplitDestination, bps: u16) -> SplitRecipient {
+    SplitRecipient {
+        destination,
+        token_account: Pubkey::default(),
+        bps,
+    }
+}
+
+/// Replace the deposit split, signed by `authority` or by the payer
+/// when none is given.
+async fn update_split_config(
+    ctx: &mut TestContext,
+    authority: Option<&Keypair>,
+    recipients: Vec<SplitRecipient>,
+) -> Result<(), BanksClientError> {
+    let signer = authority
+        .map(|keypair| keypair.pubkey())
+        .unwrap_or(ctx.authority());
+    let update = instruction(
+        TestudoBondsInstruction::UpdateSplitConfig { recipients },
+        vec![
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new_readonly(signer, true),
+            AccountMeta::new(split_config(), false),
+        ],
+    );
+    let signers: Vec<&Keypair> = authority.into_iter().collect();
+    ctx.process(&[update], &signers).await
+}
+
+#[tokio::test]
+async fn deposits_follow_the_updated_split() {
+    let mut ctx = TestContext::new().await;
+    let partner = Keypair::new();
+    let mint = ctx.native_token_mint;
+    let partner_ata = ctx.mint_to(&partner.pubkey(), &mint, 0).await;
+    update_split_config(
+        &mut ctx,
+        None,
+        vec![
+            share(SplitDestination::RewardsPool, 5_000),
+            SplitRecipient {
+                token_account: partner_ata,
+                ..share(SplitDestination::Account, 3_000)
+            },
+            share(SplitDestination::Burn, 2_000),
+        ],
+    )
+    .await
+    .unwrap();
+    let user = ctx.create_user().await;
+    let rewards_pool_ata = ctx.rewards_pool_ata();
+    let rewards_pool = ctx.balance(&rewards_pool_ata).await;
+
+    let mut accounts =
+        ctx.initialize_bond_accounts(&user.pubkey()).await;
+    accounts.push(AccountMeta::new(partner_ata, false));
+    let initialize_bond = instruction(
+        TestudoBondsInstruction::InitializeBond {
+            tokenize: false,
+            lock_tier: None,
+            fixed_term: false,
+            allowlist_proof: None,
+        },
+        accounts,
+    );
+    ctx.process(&[initialize_bond], &[&user]).await.unwrap();
+
+    assert_eq!(
+        ctx.balance(&rewards_pool_ata).await,
+        rewards_pool + 5 * SHELLS_PER_TESTUDO
+    );
+    assert_eq!(
+        ctx.balance(&partner_ata).await,
+        3 * SHELLS_PER_TESTUDO
+    );
+    let global_state = ctx.global_state().await;
+    assert_eq!(global_state.total_burned, 2 * SHELLS_PER_TESTUDO);
+    assert_eq!(global_state.pending_treasury, 0);
+    assert_eq!(ctx.balance(&ctx.principal_vault_ata()).await, 0);
+}
+
+#[tokio::test]
+async fn split_updates_need_the_authority_and_a_full_split() {
+    let mut ctx = TestContext::new().await;
+    let outsider = ctx.create_user().await;
+    let pool_only =
+        vec![share(SplitDestination::RewardsPool, 10_000)];
+
+    let result =
+        update_split_config(&mut ctx, Some(&outsider), pool_only)
+            .await;
+    assert_error(result, TestudoBondsError::InvalidAccountKey);
+
+    // Shares must add up to the whole deposit
+    let result = update_split_config(
+        &mut ctx,
+        None,
+        vec![
+            share(SplitDestination::RewardsPool, 5_000),
+            share(SplitDestination::Treasury, 4_000),
+        ],
+    )
+    .await;
+    assert_error(result, TestudoBondsError::InvalidSplitConfig);
+
+    // and name each recipient once
+    let result = update_split_config(
+        &mut ctx,
+        None,
+        vec![
+            share(SplitDestination::Team, 5_000),
+            share(SplitDestination::Team, 5_000),
+        ],
+    )
+    .await;
+    assert_error(result, TestudoBondsError::InvalidSplitConfig);
+}
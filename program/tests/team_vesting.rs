@@ -0,0 +1,76 @@
+mod common;
+
+use common::*;
+use solana_program::instruction::AccountMeta;
+use solana_program_test::{tokio, BanksClientError};
+use testudo_bonds::{
+    error::TestudoBondsError, instruction::TestudoBondsInstruction,
+    state::GlobalState,
+};
+
+async fn release_vested(
+    ctx: &mut TestContext,
+) -> Result<(), BanksClientError> {
+    let release = instruction(
+        TestudoBondsInstruction::ReleaseVested,
+        vec![
+            AccountMeta::new_readonly(global_admin(), false),
+            AccountMeta::new(global_state(), false),
+            AccountMeta::new_readonly(team_vesting(), false),
+            AccountMeta::new(ctx.team_vesting_ata(), false),
+            AccountMeta::new(ctx.team_ata(), false),
+            AccountMeta::new_readonly(ctx.native_token_mint, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+    );
+    ctx.process(&[release], &[]).await
+}
+
+#[tokio::test]
+async fn team_share_vests_after_the_cliff_of_its_tranche() {
+    let mut ctx = TestContext::new().await;
+    let schedule = ctx.admin().await.team_vesting;
+    let cliff = schedule.cliff_seconds as i64;
+    let duration = schedule.duration_seconds as i64;
+    let window =
+        duration / (GlobalState::MAX_VESTING_TRANCHES as i64 - 1);
+    let user = ctx.create_user().await;
+    ctx.initialize_bond(&user, false).await;
+    let team_ata = ctx.team_ata();
+    let team_vesting_ata = ctx.team_vesting_ata();
+    let team_balance = ctx.balance(&team_ata).await;
+
+    // The team's 20% of the deposit is escrowed in a tranche starting
+    // one window after the deposit
+    let deposited = 2 * SHELLS_PER_TESTUDO;
+    assert_eq!(ctx.balance(&team_vesting_ata).await, deposited);
+    let global_state = ctx.global_state().await;
+    assert_eq!(global_state.team_vesting_deposited, deposited);
+    let result = release_vested(&mut ctx).await;
+    assert_error(result, TestudoBondsError::NothingToRelease);
+
+    ctx.warp(window + cliff - 1).await;
+    let result = release_vested(&mut ctx).await;
+    assert_error(result, TestudoBondsError::NothingToRelease);
+
+    // At the cliff the tranche has vested linearly since its start
+    ctx.warp(1).await;
+    release_vested(&mut ctx).await.unwrap();
+    let vested =
+        (deposited as u128 * cliff as u128 / duration as u128) as u64;
+    assert_eq!(ctx.balance(&team_ata).await, team_balance + vested);
+    assert_eq!(
+        ctx.global_state().await.team_vesting_released,
+        vested
+    );
+
+    ctx.warp(duration - cliff).await;
+    release_vested(&mut ctx).await.unwrap();
+    assert_eq!(
+        ctx.balance(&team_ata).await,
+        team_balance + deposited
+    );
+    assert_eq!(ctx.balance(&team_vesting_ata).await, 0);
+    let result = release_vested(&mut ctx).await;
+    assert_error(result, TestudoBondsError::NothingToRelease);
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+use testudo_bonds::error::TestudoBondsError;
+
+/// Send the token of a tokenized bond from `owner` to `recipient`.
+async fn send_bond_token(
+    ctx: &mut TestContext,
+    owner: &Keypair,
+    recipient: &Pubkey,
+    bond: &Pubkey,
+) {
+    let bond_mint = bond_mint(bond);
+    ctx.mint_to(recipient, &bond_mint, 0).await;
+    let transfer = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &ata(&owner.pubkey(), &bond_mint),
+        &ata(recipient, &bond_mint),
+        &owner.pubkey(),
+        &[],
+        1,
+    )
+    .unwrap();
+    ctx.process(&[transfer], &[owner]).await.unwrap();
+}
+
+#[tokio::test]
+async fn holder_claims_a_tokenized_bond_and_becomes_its_owner() {
+    let mut ctx = TestContext::new().await;
+    let owner = ctx.create_user().await;
+    let holder = ctx.create_user().await;
+    let bond = ctx.initialize_bond(&owner, true).await;
+    send_bond_token(&mut ctx, &owner, &holder.pubkey(), &bond).await;
+
+    // The owner no longer holds the token, so cannot claim
+    ctx.warp(DAY).await;
+    let result = ctx.claim(&owner, &bond).await;
+    assert_error(result, TestudoBondsError::NotBondHolder);
+
+    ctx.claim(&holder, &bond).await.unwrap();
+
+    let bond_data = ctx.bond(&bond).await.unwrap();
+    let holder_pda = user_pda(&holder.pubkey());
+    assert_eq!(bond_data.owner, holder_pda);
+    assert!(bond_data.total_claimed > 0);
+    let page = ctx.page_of(&bond).await;
+    assert!(ctx.bond_page(&page).await.bonds.contains(&bond));
+    assert_eq!(ctx.user(&owner.pubkey()).await.bond_count, 0);
+    assert_eq!(ctx.user(&holder.pubkey()).await.bond_count, 1);
+}
+
+#[tokio::test]
+async fn holder_claim_respects_the_holder_bond_limit() {
+    let mut ctx = TestContext::new().await;
+    let owner = ctx.create_user().await;
+    let holder = ctx.create_user().await;
+    let bond = ctx.initialize_bond(&owner, true).await;
+    ctx.initialize_bond(&holder, false).await;
+    send_bond_token(&mut ctx, &owner, &holder.pubkey(), &bond).await;
+    ctx.update_admin(|admin| admin.max_bonds_per_wallet = 1)
+        .await;
+
+    // Moving the bond to the holder would exceed the holder's limit
+    ctx.warp(DAY).await;
+    let result = ctx.claim(&holder, &bond).await;
+    assert_error(result, TestudoBondsError::MaxBondsReached);
+    let bond_data = ctx.bond(&bond).await.unwrap();
+    assert_eq!(bond_data.owner, user_pda(&owner.pubkey()));
+    assert_eq!(bond_data.total_claimed, 0);
+
+    ctx.update_admin(|admin| admin.max_bonds_per_wallet = 2)
+        .await;
+    ctx.claim(&holder, &bond).await.unwrap();
+
+    let bond_data = ctx.bond(&bond).await.unwrap();
+    assert_eq!(bond_data.owner, user_pda(&holder.pubkey()));
+    assert_eq!(ctx.user(&owner.pubkey()).await.bond_count, 0);
+    assert_eq!(ctx.user(&holder.pubkey()).await.bond_count, 2);
+}
@@ -50,7 +50,7 @@ codama.update(
         ),
         c.variablePdaSeedNode(
           'bondIndex',
-          c.numberTypeNode('u8'),
+          c.numberTypeNode('u16'),
           'The bond index'
         ),
       ],